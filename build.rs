@@ -62,6 +62,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("cargo:version={}", 0x00_01_00_00_u32 | ((enabled_version as u32) << 8));
     println!(r#"cargo:rustc-cfg=k8s_openapi_enabled_version="1.{enabled_version}""#);
 
+    {
+        let mut out_file: std::path::PathBuf = std::env::var_os("OUT_DIR").ok_or("OUT_DIR not set")?.into();
+        out_file.push("enabled_minor_version.rs");
+        std::fs::write(out_file, enabled_version.to_string())?;
+    }
+
     let mut f = {
         let mut out_file: std::path::PathBuf = std::env::var_os("OUT_DIR").ok_or("OUT_DIR not set")?.into();
         out_file.push("conditional_compilation_macros.rs");
@@ -75,8 +75,11 @@ impl_from_for_error! {
 /// reuse types from the k8s-openapi crate would map `com.github.openshift.` to `crate::` and `io.k8s.` to `k8s_openapi::` instead.
 ///
 /// The implementation should return `None` for paths that it does not recognize.
+///
+/// The returned components can borrow from the path as well as from the implementation itself,
+/// so an implementation that is configured at runtime can return components that it owns.
 pub trait MapNamespace {
-	fn map_namespace<'a>(&self, path_parts: &[&'a str]) -> Option<Vec<&'a str>>;
+	fn map_namespace<'a>(&'a self, path_parts: &[&'a str]) -> Option<Vec<&'a str>>;
 }
 
 /// Used to create an impl of `std::io::Write` for each type that the type's generated code will be written to.
@@ -112,6 +115,25 @@ pub trait RunState {
 	/// This function is invoked when `k8s_openapi_codegen_common::run` is done with the writer and completes successfully.
 	/// The implementation can do any cleanup that it wants here.
 	fn finish(&mut self, writer: Self::Writer);
+
+	/// Whether the definition of a struct type should be emitted, in addition to its impls.
+	///
+	/// Code generators that are emitting impls for a struct that is already defined, such as a custom derive on the struct itself,
+	/// can return `false` here. The existing struct must then have the same fields as the ones that would've been generated.
+	fn emit_struct_definition(&self) -> bool {
+		true
+	}
+
+	/// This function is invoked with the fields of each struct type generated from a definition with properties,
+	/// as pairs of the Rust field name and the Rust field type. The `apiVersion` and `kind` properties of resource types are not included
+	/// since they are not emitted as fields.
+	///
+	/// Code generators can use this to collect the shapes of the generated types, such as to emit code that works with the types
+	/// of multiple versions of a spec.
+	fn handle_struct_fields(&mut self, fields: &[(&str, &str)]) -> std::io::Result<()> {
+		let _ = fields;
+		Ok(())
+	}
 }
 
 impl<T> RunState for &'_ mut T where T: RunState {
@@ -136,8 +158,93 @@ impl<T> RunState for &'_ mut T where T: RunState {
 	fn finish(&mut self, writer: Self::Writer) {
 		(*self).finish(writer);
 	}
+
+	fn emit_struct_definition(&self) -> bool {
+		(**self).emit_struct_definition()
+	}
+
+	fn handle_struct_fields(&mut self, fields: &[(&str, &str)]) -> std::io::Result<()> {
+		(**self).handle_struct_fields(fields)
+	}
 }
 
+/// Lets code generators add to the code that [`run`] and [`write_operation`] emit, without having to post-process the generated code.
+///
+/// For example, a code generator can use this to add a `Hash` derive to some types, or to add extra trait impls or documentation.
+///
+/// All functions have default implementations that add nothing, and `()` implements this trait with these defaults.
+///
+/// Note that the generated types implement `serde::Deserialize` and `serde::Serialize` manually rather than deriving them,
+/// so `#[serde]` attributes added by this trait have no effect.
+pub trait Hooks {
+	/// Returns the names of additional traits that the type generated for the given definition should derive, such as `Hash`.
+	///
+	/// These are emitted in a separate `#[derive]` attribute after the one of the generated code.
+	fn derives(&self, definition_path: &swagger20::DefinitionPath) -> Vec<String> {
+		let _ = definition_path;
+		vec![]
+	}
+
+	/// Returns additional attributes, such as `#[non_exhaustive]`, that should be emitted on the type generated for the given definition.
+	fn type_attributes(&self, definition_path: &swagger20::DefinitionPath) -> Vec<String> {
+		let _ = definition_path;
+		vec![]
+	}
+
+	/// Returns additional documentation that should be appended as a separate paragraph to the doc comment of the type generated for the given definition.
+	///
+	/// The text is emitted as-is, so it can contain Markdown such as intra-doc links.
+	fn type_doc(&self, definition_path: &swagger20::DefinitionPath) -> Option<String> {
+		let _ = definition_path;
+		None
+	}
+
+	/// Returns additional attributes that should be emitted on the field generated for the given property of the given definition.
+	fn property_attributes(&self, definition_path: &swagger20::DefinitionPath, property_name: &str) -> Vec<String> {
+		let _ = (definition_path, property_name);
+		vec![]
+	}
+
+	/// Returns additional documentation that should be appended as a separate paragraph to the doc comment of the field generated for
+	/// the given property of the given definition.
+	///
+	/// The text is emitted as-is, so it can contain Markdown such as intra-doc links.
+	fn property_doc(&self, definition_path: &swagger20::DefinitionPath, property_name: &str) -> Option<String> {
+		let _ = (definition_path, property_name);
+		None
+	}
+
+	/// Returns additional attributes that should be emitted on the API function generated for the given operation.
+	fn operation_attributes(&self, operation: &swagger20::Operation) -> Vec<String> {
+		let _ = operation;
+		vec![]
+	}
+
+	/// Returns additional documentation that should be appended as a separate paragraph to the doc comment of the API function generated for
+	/// the given operation.
+	///
+	/// The text is emitted as-is, so it can contain Markdown such as intra-doc links.
+	fn operation_doc(&self, operation: &swagger20::Operation) -> Option<String> {
+		let _ = operation;
+		None
+	}
+
+	/// Writes additional items, such as trait impls, after the generated code of the type for the given definition.
+	///
+	/// `type_name` is the name of the generated type. The items are written to the same writer as the type, so they can refer to the type by this name.
+	fn write_type_items(
+		&self,
+		definition_path: &swagger20::DefinitionPath,
+		type_name: &str,
+		writer: &mut dyn std::io::Write,
+	) -> std::io::Result<()> {
+		let _ = (definition_path, type_name, writer);
+		Ok(())
+	}
+}
+
+impl Hooks for () {}
+
 /// Whether [`run`] should generate an impl of `schemars::JsonSchema` for the type or not.
 #[derive(Clone, Copy, Debug)]
 pub enum GenerateSchema<'a> {
@@ -149,6 +256,43 @@ pub enum GenerateSchema<'a> {
 	No,
 }
 
+/// Whether [`run`] should derive `Eq`, `Hash`, `Ord` and `PartialOrd` for the types whose fields all implement these traits.
+///
+/// Regardless of this setting, these traits are derived for some types that always implement them, like string enums.
+#[derive(Clone, Copy, Debug)]
+pub enum DeriveEqHashOrd<'a> {
+	Yes {
+		/// An optional feature that the derives will be `cfg_attr`-gated by.
+		feature: Option<&'a str>,
+	},
+
+	No,
+}
+
+/// Additional options for [`run_with_options`] and [`write_operation_with_options`].
+///
+/// The `Default` impl returns the options that [`run`] and [`write_operation`] use,
+/// so callers can set only the options they need with `Options { derive_eq_hash_ord: ..., ..Default::default() }`.
+#[derive(Clone, Copy)]
+pub struct Options<'a> {
+	/// An instance of the [`Hooks`] trait that can add derives, attributes, documentation and items to the generated code.
+	/// Defaults to `&()`, which does not add anything.
+	pub hooks: &'a dyn Hooks,
+
+	/// Whether to derive `Eq`, `Hash`, `Ord` and `PartialOrd` for the types whose fields all implement these traits.
+	/// Defaults to [`DeriveEqHashOrd::No`]. This is ignored by [`write_operation_with_options`].
+	pub derive_eq_hash_ord: DeriveEqHashOrd<'a>,
+}
+
+impl Default for Options<'_> {
+	fn default() -> Self {
+		Options {
+			hooks: &(),
+			derive_eq_hash_ord: DeriveEqHashOrd::No,
+		}
+	}
+}
+
 /// Each invocation of this function generates a single type specified by the `definition_path` parameter along with its associated API operation functions.
 ///
 /// # Parameters
@@ -177,10 +321,37 @@ pub fn run(
 	vis: &str,
 	generate_schema: GenerateSchema<'_>,
 	operation_feature: Option<&str>,
+	state: impl RunState,
+) -> Result<RunResult, Error> {
+	run_with_options(
+		definitions,
+		operations,
+		definition_path,
+		map_namespace,
+		vis,
+		generate_schema,
+		operation_feature,
+		&Options::default(),
+		state,
+	)
+}
+
+/// Like [`run`], but with additional [`Options`].
+pub fn run_with_options(
+	definitions: &std::collections::BTreeMap<swagger20::DefinitionPath, swagger20::Schema>,
+	operations: &mut Vec<swagger20::Operation>,
+	definition_path: &swagger20::DefinitionPath,
+	map_namespace: &impl MapNamespace,
+	vis: &str,
+	generate_schema: GenerateSchema<'_>,
+	operation_feature: Option<&str>,
+	options: &Options<'_>,
 	mut state: impl RunState,
 ) -> Result<RunResult, Error> {
 	use std::io::Write;
 
+	let Options { hooks, derive_eq_hash_ord } = *options;
+
 	let definition = definitions.get(definition_path).ok_or_else(|| format!("definition for {definition_path} does not exist in spec"))?;
 
 	let local = map_namespace_local_to_string(map_namespace)?;
@@ -205,6 +376,7 @@ pub fn run(
 			swagger20::Type::PatchOptional(_) |
 			swagger20::Type::ReplaceOptional(_) |
 			swagger20::Type::WatchOptional(_) |
+			swagger20::Type::StringEnum(_) |
 			swagger20::Type::CreateResponse |
 			swagger20::Type::DeleteResponse |
 			swagger20::Type::ListResponse |
@@ -222,16 +394,21 @@ pub fn run(
 
 	let type_name = path_parts.last().ok_or_else(|| format!("path for {definition_path} has no parts"))?;
 
-	let derives = get_derives(&definition.kind, definitions, map_namespace)?;
+	let derives = get_derives(&definition.kind, definitions, map_namespace, derive_eq_hash_ord)?;
 
-	templates::type_header::generate(
-		&mut out,
-		definition_path,
-		definition.description.as_deref(),
-		type_feature,
-		derives,
-		vis,
-	)?;
+	let emit_struct_definition = !matches!(&definition.kind, swagger20::SchemaKind::Properties(_)) || state.emit_struct_definition();
+
+	if emit_struct_definition {
+		templates::type_header::generate(
+			&mut out,
+			definition_path,
+			definition.description.as_deref(),
+			type_feature,
+			derives,
+			hooks,
+			vis,
+		)?;
+	}
 
 	match &definition.kind {
 		swagger20::SchemaKind::Properties(properties) => {
@@ -350,13 +527,22 @@ pub fn run(
 				(result, resource_metadata, metadata_ty)
 			};
 
-			templates::r#struct::generate(
-				&mut out,
-				vis,
-				type_name,
-				Default::default(),
-				&template_properties,
-			)?;
+			if emit_struct_definition {
+				templates::r#struct::generate(
+					&mut out,
+					vis,
+					type_name,
+					Default::default(),
+					&template_properties,
+					definition_path,
+					hooks,
+				)?;
+			}
+
+			{
+				let fields: Vec<_> = template_properties.iter().map(|property| (&*property.field_name, &*property.field_type_name)).collect();
+				state.handle_struct_fields(&fields)?;
+			}
 
 			let mut namespace_or_cluster_scoped_url_path_segment_and_scope = vec![];
 			let mut subresource_url_path_segment_and_scope = vec![];
@@ -383,13 +569,14 @@ pub fn run(
 
 						for operation in operations {
 							let (operation_optional_parameters_name, operation_result_name) =
-								write_operation(
+								write_operation_with_options(
 									&mut out,
 									&operation,
 									map_namespace,
 									vis,
 									Some(type_name),
-									operation_feature)?;
+									operation_feature,
+									options)?;
 							state.handle_operation_types(operation_optional_parameters_name.as_deref(), operation_result_name.as_deref())?;
 							run_result.num_generated_apis += 1;
 
@@ -464,7 +651,9 @@ pub fn run(
 				"io.k8s.apimachinery.pkg.apis.meta.v1.APIGroup" |
 				"io.k8s.apimachinery.pkg.apis.meta.v1.APIGroupList" |
 				"io.k8s.apimachinery.pkg.apis.meta.v1.APIResourceList" |
-				"io.k8s.apimachinery.pkg.apis.meta.v1.APIVersions" =>
+				"io.k8s.apimachinery.pkg.apis.meta.v1.APIVersions" |
+				"io.k8s.apimachinery.pkg.apis.meta.v1.PartialObjectMetadata" |
+				"io.k8s.apimachinery.pkg.apis.meta.v1.Table" =>
 					namespace_or_cluster_scoped_url_path_segment_and_scope.push((r#""""#.to_owned(), format!("{local}ClusterResourceScope"))),
 				"io.k8s.apimachinery.pkg.apis.meta.v1.Status" =>
 					subresource_url_path_segment_and_scope.push((r#""status""#.to_owned(), format!("{local}SubResourceScope"))),
@@ -702,6 +891,8 @@ pub fn run(
 				type_name,
 				template_generics,
 				&template_properties,
+				definition_path,
+				hooks,
 			)?;
 
 			templates::impl_resource::generate(
@@ -825,6 +1016,8 @@ pub fn run(
 				type_name,
 				template_generics,
 				&template_properties,
+				definition_path,
+				hooks,
 			)?;
 
 			match ty {
@@ -871,6 +1064,19 @@ pub fn run(
 			run_result.num_generated_structs += 1;
 		},
 
+		swagger20::SchemaKind::Ty(swagger20::Type::StringEnum(variants)) => {
+			templates::string_enum::generate(
+				&mut out,
+				type_name,
+				vis,
+				variants,
+				operation_feature,
+				map_namespace,
+			)?;
+
+			run_result.num_generated_structs += 1;
+		},
+
 		swagger20::SchemaKind::Ty(swagger20::Type::CreateResponse) => {
 			templates::operation_response_common::generate(
 				&mut out,
@@ -1029,6 +1235,8 @@ pub fn run(
 		}
 	}
 
+	hooks.write_type_items(definition_path, type_name, &mut out)?;
+
 	state.finish(out);
 
 	Ok(run_result)
@@ -1045,11 +1253,12 @@ fn map_namespace_local_to_string(map_namespace: &impl MapNamespace) -> Result<St
 	Ok(result)
 }
 
-fn get_derives(
+fn get_derives<'a>(
 	kind: &swagger20::SchemaKind,
 	definitions: &std::collections::BTreeMap<swagger20::DefinitionPath, swagger20::Schema>,
 	map_namespace: &impl MapNamespace,
-) -> Result<Option<templates::type_header::Derives>, Error> {
+	derive_eq_hash_ord: DeriveEqHashOrd<'a>,
+) -> Result<Option<templates::type_header::Derives<'a>>, Error> {
 	if matches!(kind, swagger20::SchemaKind::Ty(swagger20::Type::ListRef { .. })) {
 		// ListRef is emitted as a type alias.
 		return Ok(None);
@@ -1083,7 +1292,8 @@ fn get_derives(
 				swagger20::Type::ListOptional(_) |
 				swagger20::Type::PatchOptional(_) |
 				swagger20::Type::ReplaceOptional(_) |
-				swagger20::Type::WatchOptional(_)
+				swagger20::Type::WatchOptional(_) |
+				swagger20::Type::StringEnum(_)
 			))))?;
 
 	#[allow(clippy::match_same_arms)]
@@ -1110,6 +1320,7 @@ fn get_derives(
 			swagger20::Type::JsonSchemaPropsOr(_, _) |
 			swagger20::Type::Patch |
 			swagger20::Type::WatchEvent(_) |
+			swagger20::Type::StringEnum(_) |
 			swagger20::Type::CreateResponse |
 			swagger20::Type::DeleteResponse |
 			swagger20::Type::ListResponse |
@@ -1150,24 +1361,100 @@ fn get_derives(
 		derive_partial_eq &&
 		matches!(kind, swagger20::SchemaKind::Ty(
 			swagger20::Type::IntOrString |
-			swagger20::Type::String { format: Some(swagger20::StringFormat::DateTime) }
+			swagger20::Type::String { format: Some(swagger20::StringFormat::DateTime) } |
+			swagger20::Type::StringEnum(_)
 		));
 
 	let derive_partial_ord =
 		derive_partial_eq &&
-		matches!(kind, swagger20::SchemaKind::Ty(swagger20::Type::String { format: Some(swagger20::StringFormat::DateTime) }));
+		matches!(kind, swagger20::SchemaKind::Ty(
+			swagger20::Type::String { format: Some(swagger20::StringFormat::DateTime) } |
+			swagger20::Type::StringEnum(_)
+		));
 
 	let derive_ord = derive_partial_ord && derive_eq;
 
-	Ok(Some(templates::type_header::Derives {
+	let mut derives = templates::type_header::Derives {
 		clone: derive_clone,
 		copy: derive_copy,
 		default: derive_default,
 		eq: derive_eq,
+		// Fieldless enums can always derive Hash consistently with their derived Eq.
+		hash: derive_eq && matches!(kind, swagger20::SchemaKind::Ty(swagger20::Type::StringEnum(_))),
 		ord: derive_ord,
 		partial_eq: derive_partial_eq,
 		partial_ord: derive_partial_ord,
-	}))
+		eq_hash_ord_feature: None,
+	};
+
+	if let DeriveEqHashOrd::Yes { feature } = derive_eq_hash_ord {
+		if derive_partial_eq && is_eq_hash_ord(kind, definitions, map_namespace)? {
+			if feature.is_some() {
+				derives.eq_hash_ord_feature = feature;
+			}
+			else {
+				derives.eq = true;
+				derives.hash = true;
+				derives.ord = true;
+				derives.partial_ord = true;
+			}
+		}
+	}
+
+	Ok(Some(derives))
+}
+
+/// Whether the type of the given kind implements `Eq`, `Hash` and `Ord`, assuming that the generated types of its fields derive them.
+fn is_eq_hash_ord(
+	kind: &swagger20::SchemaKind,
+	definitions: &std::collections::BTreeMap<swagger20::DefinitionPath, swagger20::Schema>,
+	map_namespace: &impl MapNamespace,
+) -> Result<bool, Error> {
+	#[allow(clippy::match_same_arms)]
+	evaluate_trait_bound(kind, true, definitions, map_namespace, |kind, _| match kind {
+		// Types from outside the spec, like the k8s-openapi types used by custom resources, might not implement these traits.
+		swagger20::SchemaKind::Ref(_) => Ok(false),
+
+		// serde_json::Value and f64 don't implement these traits.
+		swagger20::SchemaKind::Ty(
+			swagger20::Type::Any |
+			swagger20::Type::Number { .. } |
+			swagger20::Type::Patch |
+			swagger20::Type::WatchEvent(_)
+		) => Ok(false),
+
+		swagger20::SchemaKind::Ty(swagger20::Type::CustomResourceSubresources(_)) => Ok(false),
+
+		swagger20::SchemaKind::Ty(
+			swagger20::Type::CreateResponse |
+			swagger20::Type::DeleteResponse |
+			swagger20::Type::ListResponse |
+			swagger20::Type::PatchResponse |
+			swagger20::Type::ReplaceResponse |
+			swagger20::Type::WatchResponse
+		) => Ok(false),
+
+		swagger20::SchemaKind::Ty(swagger20::Type::ListDef { metadata }) => is_eq_hash_ord(metadata, definitions, map_namespace),
+
+		swagger20::SchemaKind::Ty(
+			swagger20::Type::CreateOptional(properties) |
+			swagger20::Type::DeleteOptional(properties) |
+			swagger20::Type::ListOptional(properties) |
+			swagger20::Type::PatchOptional(properties) |
+			swagger20::Type::ReplaceOptional(properties) |
+			swagger20::Type::WatchOptional(properties)
+		) => {
+			for schema in properties.values() {
+				if !is_eq_hash_ord(&schema.kind, definitions, map_namespace)? {
+					return Ok(false);
+				}
+			}
+
+			Ok(true)
+		},
+
+		_ => Ok(true),
+	})
 }
 
 fn is_default(
@@ -1199,6 +1486,7 @@ fn is_default(
 			swagger20::Type::JsonSchemaPropsOr(_, _) |
 			swagger20::Type::Patch |
 			swagger20::Type::WatchEvent(_) |
+			swagger20::Type::StringEnum(_) |
 			swagger20::Type::CreateResponse |
 			swagger20::Type::DeleteResponse |
 			swagger20::Type::ListResponse |
@@ -1286,6 +1574,18 @@ fn evaluate_trait_bound(
 					f,
 				),
 
+			// Maps follow their values the same way as arrays follow their elements.
+			swagger20::SchemaKind::Ty(swagger20::Type::Object { additional_properties }) if array_follows_elements =>
+				evaluate_trait_bound_inner(
+					&std::borrow::Cow::Owned(additional_properties.kind.clone()),
+					required,
+					array_follows_elements,
+					definitions,
+					map_namespace,
+					visited,
+					f,
+				),
+
 			swagger20::SchemaKind::Ty(swagger20::Type::JsonSchemaPropsOr(namespace, _)) => {
 				let json_schema_props_ref_path = swagger20::RefPath {
 					path: format!("io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.{namespace}.JSONSchemaProps"),
@@ -1342,6 +1642,11 @@ fn evaluate_trait_bound(
 	)
 }
 
+/// Returns the lines of the doc comment for documentation text from [`Hooks`], which is emitted as-is.
+fn get_hook_doc_lines(text: &str) -> impl Iterator<Item = String> + '_ {
+	text.lines().map(|line| if line.is_empty() { "///".to_owned() } else { format!("/// {line}") })
+}
+
 fn get_comment_text<'a>(s: &'a str, indent: &'a str) -> impl Iterator<Item = std::borrow::Cow<'static, str>> + 'a {
 	s.lines().scan(true, move |previous_line_was_empty, line|
 		if line.is_empty() {
@@ -1478,6 +1783,13 @@ fn get_rust_borrow_type(
 		swagger20::SchemaKind::Ref(swagger20::RefPath { path, .. }) if path == "io.k8s.WatchOptional" =>
 			Ok(format!("{local}WatchOptional<'_>").into()),
 
+		// The parameter enums are Copy, so they are passed by value.
+		swagger20::SchemaKind::Ref(swagger20::RefPath { path, .. }) if matches!(
+			&**path,
+			"io.k8s.DryRun" | "io.k8s.FieldValidation" | "io.k8s.PropagationPolicy" | "io.k8s.ResourceVersionMatch",
+		) =>
+			Ok(format!("{local}{}", &path["io.k8s.".len()..]).into()),
+
 		swagger20::SchemaKind::Ref(ref_path) =>
 			Ok(format!("&{}", get_fully_qualified_type_name(ref_path, map_namespace)).into()),
 
@@ -1521,6 +1833,8 @@ fn get_rust_borrow_type(
 		swagger20::SchemaKind::Ty(swagger20::Type::ReplaceOptional(_)) => Err("ReplaceOptional type not supported".into()),
 		swagger20::SchemaKind::Ty(swagger20::Type::WatchOptional(_)) => Err("WatchOptional type not supported".into()),
 
+		swagger20::SchemaKind::Ty(swagger20::Type::StringEnum(_)) => Err("StringEnum type not supported".into()),
+
 		swagger20::SchemaKind::Ty(swagger20::Type::CreateResponse) => Err("CreateResponse type not supported".into()),
 		swagger20::SchemaKind::Ty(swagger20::Type::DeleteResponse) => Err("DeleteResponse type not supported".into()),
 		swagger20::SchemaKind::Ty(swagger20::Type::ListResponse) => Err("ListResponse type not supported".into()),
@@ -1596,6 +1910,8 @@ fn get_rust_type(
 		swagger20::SchemaKind::Ty(swagger20::Type::ReplaceOptional(_)) => Err("ReplaceOptional type not supported".into()),
 		swagger20::SchemaKind::Ty(swagger20::Type::WatchOptional(_)) => Err("WatchOptional type not supported".into()),
 
+		swagger20::SchemaKind::Ty(swagger20::Type::StringEnum(_)) => Err("StringEnum type not supported".into()),
+
 		swagger20::SchemaKind::Ty(swagger20::Type::CreateResponse) => Err("CreateResponse type not supported".into()),
 		swagger20::SchemaKind::Ty(swagger20::Type::DeleteResponse) => Err("DeleteResponse type not supported".into()),
 		swagger20::SchemaKind::Ty(swagger20::Type::ListResponse) => Err("ListResponse type not supported".into()),
@@ -1628,13 +1944,28 @@ fn get_rust_type(
 /// 1. The name of the optional parameters type associated with the operation, if any.
 /// 1. The name of the response type associated with the operation, if any.
 pub fn write_operation(
+	out: impl std::io::Write,
+	operation: &swagger20::Operation,
+	map_namespace: &impl MapNamespace,
+	vis: &str,
+	type_name: Option<&str>,
+	operation_feature: Option<&str>,
+) -> Result<(Option<String>, Option<String>), Error> {
+	write_operation_with_options(out, operation, map_namespace, vis, type_name, operation_feature, &Options::default())
+}
+
+/// Like [`write_operation`], but with additional [`Options`].
+pub fn write_operation_with_options(
 	mut out: impl std::io::Write,
 	operation: &swagger20::Operation,
 	map_namespace: &impl MapNamespace,
 	vis: &str,
 	type_name: Option<&str>,
 	operation_feature: Option<&str>,
+	options: &Options<'_>,
 ) -> Result<(Option<String>, Option<String>), Error> {
+	let hooks = options.hooks;
+
 	let local = map_namespace_local_to_string(map_namespace)?;
 
 	writeln!(out)?;
@@ -1813,12 +2144,27 @@ pub fn write_operation(
 			writeln!(out, "{indent}///")?;
 			writeln!(out, "{indent}///     Optional parameters. Use `Default::default()` to not pass any.")?;
 		}
+		need_empty_line = true;
+	}
+
+	if let Some(operation_doc) = hooks.operation_doc(operation) {
+		if need_empty_line {
+			writeln!(out, "{indent}///")?;
+		}
+
+		for line in get_hook_doc_lines(&operation_doc) {
+			writeln!(out, "{indent}{line}")?;
+		}
 	}
 
 	if let Some(operation_feature) = operation_feature {
 		writeln!(out, r#"{indent}#[cfg(feature = {operation_feature:?})]"#)?;
 	}
 
+	for attribute in hooks.operation_attributes(operation) {
+		writeln!(out, "{indent}{attribute}")?;
+	}
+
 	writeln!(out, "{indent}{vis}fn {operation_fn_name}(")?;
 	for (parameter_name, parameter_type, _) in &required_parameters {
 		writeln!(out, "{indent}    {parameter_name}: {parameter_type},")?;
@@ -2332,9 +2678,240 @@ fn get_operation_names(
 
 #[cfg(test)]
 mod test {
+	struct MapNamespace;
+
+	impl super::MapNamespace for MapNamespace {
+		fn map_namespace<'a>(&self, path_parts: &[&'a str]) -> Option<Vec<&'a str>> {
+			Some(std::iter::once("crate").chain(path_parts.iter().copied()).collect())
+		}
+	}
+
+	struct RunState(Vec<u8>);
+
+	impl super::RunState for RunState {
+		type Writer = Vec<u8>;
+
+		fn make_writer(&mut self, _parts: &[&str], _type_feature: Option<&str>) -> std::io::Result<Self::Writer> {
+			Ok(vec![])
+		}
+
+		fn handle_operation_types(&mut self, _: Option<&str>, _: Option<&str>) -> std::io::Result<()> {
+			Ok(())
+		}
+
+		fn finish(&mut self, writer: Self::Writer) {
+			self.0 = writer;
+		}
+	}
+
+	fn schema(kind: super::swagger20::SchemaKind) -> super::swagger20::Schema {
+		super::swagger20::Schema {
+			description: None,
+			kind,
+			kubernetes_group_kind_versions: vec![],
+			default: None,
+			nullable: false,
+			list_kind: None,
+			impl_deep_merge: true,
+		}
+	}
+
 	#[test]
 	fn test_get_rust_ident() {
 		assert_eq!(super::get_rust_ident("as"), "as_");
 		assert_eq!(super::get_rust_ident("foo.bar"), "foo_bar");
 	}
+
+	#[test]
+	fn test_hooks() {
+		struct Hooks;
+
+		impl super::Hooks for Hooks {
+			fn derives(&self, _definition_path: &super::swagger20::DefinitionPath) -> Vec<String> {
+				vec!["Eq".to_owned(), "Hash".to_owned()]
+			}
+
+			fn type_attributes(&self, _definition_path: &super::swagger20::DefinitionPath) -> Vec<String> {
+				vec!["#[non_exhaustive]".to_owned()]
+			}
+
+			fn type_doc(&self, definition_path: &super::swagger20::DefinitionPath) -> Option<String> {
+				Some(format!("Extra documentation for `{definition_path}`."))
+			}
+
+			fn property_attributes(&self, _definition_path: &super::swagger20::DefinitionPath, property_name: &str) -> Vec<String> {
+				if property_name == "fooBar" { vec!["#[doc(alias = \"foo-bar\")]".to_owned()] } else { vec![] }
+			}
+
+			fn property_doc(&self, _definition_path: &super::swagger20::DefinitionPath, property_name: &str) -> Option<String> {
+				Some(format!("Extra documentation for `{property_name}`."))
+			}
+
+			fn write_type_items(
+				&self,
+				_definition_path: &super::swagger20::DefinitionPath,
+				type_name: &str,
+				writer: &mut dyn std::io::Write,
+			) -> std::io::Result<()> {
+				writeln!(writer, "impl Marker for {type_name} {{}}")
+			}
+		}
+
+		let definition_path = super::swagger20::DefinitionPath("Foo".to_owned());
+		let definitions = [(
+			definition_path.clone(),
+			super::swagger20::Schema {
+				description: Some("A foo.".to_owned()),
+				..schema(super::swagger20::SchemaKind::Properties([(
+					super::swagger20::PropertyName("fooBar".to_owned()),
+					(
+						super::swagger20::Schema {
+							description: Some("The bar of the foo.".to_owned()),
+							..schema(super::swagger20::SchemaKind::Ty(super::swagger20::Type::String { format: None }))
+						},
+						true,
+					),
+				)].into_iter().collect()))
+			},
+		)].into_iter().collect();
+
+		let mut run_state = RunState(vec![]);
+		super::run_with_options(
+			&definitions,
+			&mut vec![],
+			&definition_path,
+			&MapNamespace,
+			"pub ",
+			super::GenerateSchema::No,
+			None,
+			&super::Options { hooks: &Hooks, ..Default::default() },
+			&mut run_state,
+		).unwrap();
+		let output = String::from_utf8(run_state.0).unwrap();
+
+		assert!(output.starts_with("\
+// Generated from definition Foo
+
+/// A foo.
+///
+/// Extra documentation for `Foo`.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Eq, Hash)]
+#[non_exhaustive]
+pub struct Foo {
+    /// The bar of the foo.
+    ///
+    /// Extra documentation for `fooBar`.
+    #[doc(alias = \"foo-bar\")]
+    pub foo_bar: String,
+}
+"), "{output}");
+		assert!(output.ends_with("impl Marker for Foo {}\n"), "{output}");
+	}
+
+	#[test]
+	fn test_derive_eq_hash_ord() {
+		fn properties(properties: &[(&str, super::swagger20::SchemaKind)]) -> super::swagger20::Schema {
+			schema(super::swagger20::SchemaKind::Properties(
+				properties.iter()
+				.map(|(name, kind)| (super::swagger20::PropertyName((*name).to_owned()), (schema(kind.clone()), true)))
+				.collect()))
+		}
+
+		fn reference(path: &str) -> super::swagger20::SchemaKind {
+			super::swagger20::SchemaKind::Ref(super::swagger20::RefPath { path: path.to_owned(), can_be_default: None })
+		}
+
+		let string = super::swagger20::SchemaKind::Ty(super::swagger20::Type::String { format: None });
+		let definitions: std::collections::BTreeMap<_, _> = [
+			("Hashable", properties(&[
+				("names", super::swagger20::SchemaKind::Ty(super::swagger20::Type::Array { items: Box::new(schema(string.clone())) })),
+				("nested", reference("Nested")),
+			])),
+			("Nested", properties(&[
+				("labels", super::swagger20::SchemaKind::Ty(super::swagger20::Type::Object { additional_properties: Box::new(schema(string.clone())) })),
+			])),
+			("NotHashable", properties(&[
+				("name", string.clone()),
+				("values", super::swagger20::SchemaKind::Ty(super::swagger20::Type::Object {
+					additional_properties: Box::new(schema(super::swagger20::SchemaKind::Ty(super::swagger20::Type::Number {
+						format: super::swagger20::NumberFormat::Double,
+					}))),
+				})),
+			])),
+			("Time", schema(super::swagger20::SchemaKind::Ty(super::swagger20::Type::String { format: Some(super::swagger20::StringFormat::DateTime) }))),
+		].into_iter().map(|(path, schema)| (super::swagger20::DefinitionPath(path.to_owned()), schema)).collect();
+
+		for (derive_eq_hash_ord, definition_path, expected) in [
+			(super::DeriveEqHashOrd::No, "Hashable", "#[derive(Clone, Debug, Default, PartialEq)]\npub struct Hashable {"),
+			(
+				super::DeriveEqHashOrd::Yes { feature: None },
+				"Hashable",
+				"#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]\npub struct Hashable {",
+			),
+			(
+				super::DeriveEqHashOrd::Yes { feature: Some("eq_hash_ord") },
+				"Hashable",
+				"#[derive(Clone, Debug, Default, PartialEq)]\n#[cfg_attr(feature = \"eq_hash_ord\", derive(Eq, Hash, Ord, PartialOrd))]\npub struct Hashable {",
+			),
+			(super::DeriveEqHashOrd::Yes { feature: Some("eq_hash_ord") }, "NotHashable", "#[derive(Clone, Debug, Default, PartialEq)]\npub struct NotHashable {"),
+			(
+				super::DeriveEqHashOrd::Yes { feature: Some("eq_hash_ord") },
+				"Time",
+				"#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]\n#[cfg_attr(feature = \"eq_hash_ord\", derive(Hash))]\npub struct Time(",
+			),
+		] {
+			let mut run_state = RunState(vec![]);
+			super::run_with_options(
+				&definitions,
+				&mut vec![],
+				&super::swagger20::DefinitionPath(definition_path.to_owned()),
+				&MapNamespace,
+				"pub ",
+				super::GenerateSchema::No,
+				None,
+				&super::Options { derive_eq_hash_ord, ..Default::default() },
+				&mut run_state,
+			).unwrap();
+			let output = String::from_utf8(run_state.0).unwrap();
+			assert!(output.contains(expected), "{derive_eq_hash_ord:?} {definition_path}\n{output}");
+		}
+	}
+
+	#[test]
+	fn test_string_enum() {
+		let definition_path = super::swagger20::DefinitionPath("Mode".to_owned());
+		let definitions = [(
+			definition_path.clone(),
+			schema(super::swagger20::SchemaKind::Ty(super::swagger20::Type::StringEnum(vec![
+				("Fast".to_owned(), "Go fast.".to_owned()),
+				("Slow".to_owned(), "Go slow.".to_owned()),
+			]))),
+		)].into();
+
+		let mut run_state = RunState(vec![]);
+		super::run_with_options(
+			&definitions,
+			&mut vec![],
+			&definition_path,
+			&MapNamespace,
+			"pub ",
+			super::GenerateSchema::No,
+			None,
+			&super::Options { derive_eq_hash_ord: super::DeriveEqHashOrd::Yes { feature: Some("eq_hash_ord") }, ..Default::default() },
+			&mut run_state,
+		).unwrap();
+		let output = String::from_utf8(run_state.0).unwrap();
+		assert!(output.contains("\
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Mode {
+    /// Go fast.
+    Fast,
+
+    /// Go slow.
+    Slow,
+}
+"), "{output}");
+		assert!(output.ends_with("serializer.serialize_str(self.as_str())\n    }\n}\n"), "{output}");
+	}
 }
@@ -60,21 +60,17 @@ pub struct RefPath {
 impl<'de> serde::Deserialize<'de> for RefPath {
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
 		let path: String = serde::Deserialize::deserialize(deserializer)?;
-		let mut parts = path.split('/');
 
-		if parts.next() != Some("#") {
-			return Err(serde::de::Error::invalid_value(serde::de::Unexpected::Str(&path), &"path like `#/definitions/$definitionName`"));
-		}
-
-		if parts.next() != Some("definitions") {
-			return Err(serde::de::Error::invalid_value(serde::de::Unexpected::Str(&path), &"path like `#/definitions/$definitionName`"));
-		}
-
-		let ref_path = parts.next().ok_or_else(|| serde::de::Error::invalid_value(serde::de::Unexpected::Str(&path), &"path like `#/definitions/$definitionName`"))?;
-
-		if parts.next().is_some() {
-			return Err(serde::de::Error::invalid_value(serde::de::Unexpected::Str(&path), &"path like `#/definitions/$definitionName`"));
-		}
+		// Swagger 2.0 specs refer to definitions as `#/definitions/$definitionName`,
+		// and OpenAPI v3 documents refer to them as `#/components/schemas/$definitionName`.
+		let ref_path =
+			path.strip_prefix("#/definitions/")
+			.or_else(|| path.strip_prefix("#/components/schemas/"))
+			.filter(|ref_path| !ref_path.is_empty() && !ref_path.contains('/'))
+			.ok_or_else(|| serde::de::Error::invalid_value(
+				serde::de::Unexpected::Str(&path),
+				&"path like `#/definitions/$definitionName` or `#/components/schemas/$definitionName`",
+			))?;
 
 		Ok(RefPath {
 			path: ref_path.to_string(),
@@ -97,6 +93,12 @@ pub struct Schema {
 	pub kind: SchemaKind,
 	pub kubernetes_group_kind_versions: Vec<super::KubernetesGroupKindVersion>,
 
+	/// The JSON text of the `"default"` value of the schema, if any.
+	pub default: Option<String>,
+
+	/// Whether the schema is `"nullable"`, ie the value can be `null`. This is only specified by OpenAPI v3 documents.
+	pub nullable: bool,
+
 	/// Used to store the definition path of the corresponding list type, if any.
 	pub list_kind: Option<String>,
 
@@ -113,8 +115,16 @@ impl<'de> serde::Deserialize<'de> for Schema {
 			#[serde(rename = "additionalProperties")]
 			additional_properties: Option<Box<Schema>>,
 
+			#[serde(rename = "allOf")]
+			all_of: Option<Vec<Schema>>,
+
+			default: Option<serde_json::Value>,
+
 			description: Option<String>,
 
+			#[serde(rename = "enum")]
+			enum_values: Option<Vec<serde_json::Value>>,
+
 			format: Option<String>,
 
 			items: Option<Box<Schema>>,
@@ -122,6 +132,12 @@ impl<'de> serde::Deserialize<'de> for Schema {
 			#[serde(default, rename = "x-kubernetes-group-version-kind")]
 			kubernetes_group_kind_versions: Vec<super::KubernetesGroupKindVersion>,
 
+			#[serde(default)]
+			nullable: bool,
+
+			#[serde(rename = "oneOf")]
+			one_of: Option<Vec<Schema>>,
+
 			properties: Option<std::collections::BTreeMap<PropertyName, Schema>>,
 
 			#[serde(rename = "$ref")]
@@ -140,6 +156,26 @@ impl<'de> serde::Deserialize<'de> for Schema {
 			if let Some(ref_path) = value.ref_path {
 				SchemaKind::Ref(ref_path)
 			}
+			else if let Some(all_of) = value.all_of.take() {
+				// OpenAPI v3 documents wrap references in a single-element `allOf` so that they can have a description.
+				let Ok([schema]) = <[Schema; 1]>::try_from(all_of) else {
+					return Err(serde::de::Error::custom("schema has allOf with more than one element"));
+				};
+				schema.kind
+			}
+			else if let Some(one_of) = value.one_of.take() {
+				// A value that can be either an integer or a string is an IntOrString. Any other combination of schemas can only be represented as arbitrary JSON.
+				let is_int_or_string =
+					one_of.len() == 2 &&
+					one_of.iter().any(|schema| matches!(schema.kind, SchemaKind::Ty(Type::Integer { .. }))) &&
+					one_of.iter().any(|schema| matches!(schema.kind, SchemaKind::Ty(Type::String { .. })));
+				if is_int_or_string {
+					SchemaKind::Ty(Type::IntOrString)
+				}
+				else {
+					SchemaKind::Ty(Type::Any)
+				}
+			}
 			else if let Some(properties) = value.properties.take() {
 				if value.ty.as_deref() != Some("object") {
 					return Err(serde::de::Error::custom(format!("schema has properties but not type=object {value:?}")));
@@ -151,6 +187,17 @@ impl<'de> serde::Deserialize<'de> for Schema {
 					(name, (schema, required))
 				}).collect())
 			}
+			else if let (Some("string"), Some(enum_values)) = (value.ty.as_deref(), value.enum_values.take()) {
+				// The values of the enum do not have descriptions of their own.
+				let variants =
+					enum_values.into_iter()
+					.map(|enum_value| match enum_value {
+						serde_json::Value::String(enum_value) => Ok((enum_value, String::new())),
+						enum_value => Err(serde::de::Error::custom(format!("schema has type=string but enum has non-string value {enum_value}"))),
+					})
+					.collect::<Result<_, _>>()?;
+				SchemaKind::Ty(Type::StringEnum(variants))
+			}
 			else if let Some(ty) = value.ty {
 				SchemaKind::Ty(Type::parse::<D>(
 					&ty,
@@ -167,6 +214,8 @@ impl<'de> serde::Deserialize<'de> for Schema {
 			description: value.description,
 			kind,
 			kubernetes_group_kind_versions: value.kubernetes_group_kind_versions,
+			default: value.default.map(|default| default.to_string()),
+			nullable: value.nullable,
 			list_kind: None,
 			impl_deep_merge: true,
 		})
@@ -221,6 +270,10 @@ pub enum Type {
 	ReplaceOptional(std::collections::BTreeMap<PropertyName, Schema>),
 	WatchOptional(std::collections::BTreeMap<PropertyName, Schema>),
 
+	// Special type for the string parameters of some API operations that only accept a fixed set of values,
+	// and for string schemas with an `enum`. Each variant is a pair of the value and its description.
+	StringEnum(Vec<(String, String)>),
+
 	// Special types for responses of some API operations
 	CreateResponse,
 	DeleteResponse,
@@ -6,9 +6,15 @@ pub use self::definitions::*;
 mod info;
 pub use self::info::*;
 
+mod openapi_v3;
+pub use self::openapi_v3::*;
+
 mod paths;
 pub use self::paths::*;
 
+mod resource_operations;
+pub use self::resource_operations::*;
+
 /// The value of an `x-kubernetes-group-version-kind` annotation on a type or an operation.
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize))]
@@ -33,6 +39,8 @@ impl std::cmp::PartialOrd for KubernetesGroupKindVersion {
 }
 
 /// The whole `Spec` object. An OpenAPI spec JSON file can be deserialized into this type.
+///
+/// A `Spec` can also be created from a set of OpenAPI v3 documents with [`Spec::from_openapi_v3`].
 #[derive(Debug)]
 pub struct Spec {
 	pub info: Info,
@@ -49,128 +57,141 @@ impl<'de> serde::Deserialize<'de> for Spec {
 			swagger: String,
 			info: Info,
 			definitions: std::collections::BTreeMap<DefinitionPath, Schema>,
-			paths: std::collections::BTreeMap<Path, InnerPathItem>,
+			paths: std::collections::BTreeMap<Path, InnerPathItem<InnerOperation>>,
 		}
 
-		#[derive(Debug, serde::Deserialize)]
-		struct InnerPathItem {
-			delete: Option<InnerOperation>,
-			get: Option<InnerOperation>,
-			patch: Option<InnerOperation>,
-			post: Option<InnerOperation>,
-			put: Option<InnerOperation>,
-			#[serde(default)]
-			parameters: Vec<std::sync::Arc<Parameter>>,
-		}
+		let result: InnerSpec = serde::Deserialize::deserialize(deserializer)?;
 
-		#[derive(Debug, serde::Deserialize)]
-		struct InnerOperation {
-			description: Option<String>,
-			#[serde(rename = "operationId")]
-			id: String,
-			#[serde(rename = "x-kubernetes-action")]
-			kubernetes_action: Option<KubernetesAction>,
-			#[serde(rename = "x-kubernetes-group-version-kind")]
-			kubernetes_group_kind_version: Option<KubernetesGroupKindVersion>,
-			#[serde(default)]
-			parameters: Vec<std::sync::Arc<Parameter>>,
-			responses: std::collections::BTreeMap<String, InnerResponse>,
-			tags: Option<(String,)>,
+		if result.swagger != "2.0" {
+			return Err(serde::de::Error::invalid_value(serde::de::Unexpected::Str(&result.swagger), &"2.0"));
 		}
 
-		#[derive(Debug, serde::Deserialize)]
-		struct InnerResponse {
-			schema: Option<Schema>,
+		let operations = parse_paths::<D>(result.paths)?;
+
+		let mut operation_ids: std::collections::BTreeSet<_> = Default::default();
+		for operation in &operations {
+			assert!(operation_ids.insert(&operation.id));
 		}
 
-		fn parse_operation<'de, D>(
-			value: InnerOperation,
-			method: Method,
-			path: Path,
-			mut parameters: Vec<std::sync::Arc<Parameter>>,
-		) -> Result<Operation, D::Error> where D: serde::Deserializer<'de> {
-			let responses: Result<_, _> =
-				value.responses.into_iter()
-				.filter_map(|(status_code_str, response)| {
-					let Ok(status_code) = status_code_str.parse() else {
-						return Some(Err(serde::de::Error::invalid_value(
-							serde::de::Unexpected::Str(&status_code_str),
-							&"string representation of an HTTP status code")));
-					};
-					let schema = response.schema?;
-					Some(Ok((status_code, schema)))
-				})
-				.collect();
-
-			for parameter in value.parameters {
-				if let Some(p) = parameters.iter_mut().find(|p| p.name == parameter.name) {
-					*p = parameter;
-				}
-				else {
-					parameters.push(parameter);
-				}
-			}
+		Ok(Spec {
+			info: result.info,
+			definitions: result.definitions,
+			operations,
+		})
+	}
+}
 
-			if method == Method::Get {
-				for parameter in &parameters {
-					if parameter.location == ParameterLocation::Body {
-						return Err(serde::de::Error::custom(format!("Operation {} has method GET but has a body parameter {}", value.id, parameter.name)));
-					}
-				}
-			}
+#[cfg(feature = "serde")]
+#[derive(Debug, serde::Deserialize)]
+struct InnerPathItem<O> {
+	delete: Option<O>,
+	get: Option<O>,
+	patch: Option<O>,
+	post: Option<O>,
+	put: Option<O>,
+	#[serde(default)]
+	parameters: Vec<std::sync::Arc<Parameter>>,
+}
 
-			Ok(Operation {
-				description: value.description,
-				id: value.id,
-				path,
-				kubernetes_action: value.kubernetes_action,
-				kubernetes_group_kind_version: value.kubernetes_group_kind_version,
-				method,
-				parameters,
-				responses: OperationResponses::Map(responses?),
-				tag: value.tags.map(|t|t.0),
-			})
-		}
+#[cfg(feature = "serde")]
+#[derive(Debug, serde::Deserialize)]
+struct InnerOperation {
+	description: Option<String>,
+	#[serde(rename = "operationId")]
+	id: String,
+	#[serde(rename = "x-kubernetes-action")]
+	kubernetes_action: Option<KubernetesAction>,
+	#[serde(rename = "x-kubernetes-group-version-kind")]
+	kubernetes_group_kind_version: Option<KubernetesGroupKindVersion>,
+	#[serde(default)]
+	parameters: Vec<std::sync::Arc<Parameter>>,
+	responses: std::collections::BTreeMap<String, InnerResponse>,
+	tags: Option<(String,)>,
+}
 
-		let result: InnerSpec = serde::Deserialize::deserialize(deserializer)?;
+#[cfg(feature = "serde")]
+#[derive(Debug, serde::Deserialize)]
+struct InnerResponse {
+	schema: Option<Schema>,
+}
 
-		if result.swagger != "2.0" {
-			return Err(serde::de::Error::invalid_value(serde::de::Unexpected::Str(&result.swagger), &"2.0"));
+#[cfg(feature = "serde")]
+fn parse_paths<'de, D>(
+	paths: std::collections::BTreeMap<Path, InnerPathItem<InnerOperation>>,
+) -> Result<Vec<Operation>, D::Error> where D: serde::Deserializer<'de> {
+	let mut operations = vec![];
+
+	for (path, path_item) in paths {
+		if let Some(delete) = path_item.delete {
+			operations.push(parse_operation::<D>(delete, Method::Delete, path.clone(), path_item.parameters.clone())?);
 		}
 
-		let mut operations = vec![];
+		if let Some(get) = path_item.get {
+			operations.push(parse_operation::<D>(get, Method::Get, path.clone(), path_item.parameters.clone())?);
+		}
 
-		for (path, path_item) in result.paths {
-			if let Some(delete) = path_item.delete {
-				operations.push(parse_operation::<D>(delete, Method::Delete, path.clone(), path_item.parameters.clone())?);
-			}
+		if let Some(patch) = path_item.patch {
+			operations.push(parse_operation::<D>(patch, Method::Patch, path.clone(), path_item.parameters.clone())?);
+		}
 
-			if let Some(get) = path_item.get {
-				operations.push(parse_operation::<D>(get, Method::Get, path.clone(), path_item.parameters.clone())?);
-			}
+		if let Some(post) = path_item.post {
+			operations.push(parse_operation::<D>(post, Method::Post, path.clone(), path_item.parameters.clone())?);
+		}
 
-			if let Some(patch) = path_item.patch {
-				operations.push(parse_operation::<D>(patch, Method::Patch, path.clone(), path_item.parameters.clone())?);
-			}
+		if let Some(put) = path_item.put {
+			operations.push(parse_operation::<D>(put, Method::Put, path, path_item.parameters)?);
+		}
+	}
 
-			if let Some(post) = path_item.post {
-				operations.push(parse_operation::<D>(post, Method::Post, path.clone(), path_item.parameters.clone())?);
-			}
+	Ok(operations)
+}
 
-			if let Some(put) = path_item.put {
-				operations.push(parse_operation::<D>(put, Method::Put, path, path_item.parameters)?);
-			}
-		}
+#[cfg(feature = "serde")]
+fn parse_operation<'de, D>(
+	value: InnerOperation,
+	method: Method,
+	path: Path,
+	mut parameters: Vec<std::sync::Arc<Parameter>>,
+) -> Result<Operation, D::Error> where D: serde::Deserializer<'de> {
+	let responses: Result<_, _> =
+		value.responses.into_iter()
+		.filter_map(|(status_code_str, response)| {
+			let Ok(status_code) = status_code_str.parse() else {
+				return Some(Err(serde::de::Error::invalid_value(
+					serde::de::Unexpected::Str(&status_code_str),
+					&"string representation of an HTTP status code")));
+			};
+			let schema = response.schema?;
+			Some(Ok((status_code, schema)))
+		})
+		.collect();
 
-		let mut operation_ids: std::collections::BTreeSet<_> = Default::default();
-		for operation in &operations {
-			assert!(operation_ids.insert(&operation.id));
+	for parameter in value.parameters {
+		if let Some(p) = parameters.iter_mut().find(|p| p.name == parameter.name) {
+			*p = parameter;
+		}
+		else {
+			parameters.push(parameter);
 		}
+	}
 
-		Ok(Spec {
-			info: result.info,
-			definitions: result.definitions,
-			operations,
-		})
+	if method == Method::Get {
+		for parameter in &parameters {
+			if parameter.location == ParameterLocation::Body {
+				return Err(serde::de::Error::custom(format!("Operation {} has method GET but has a body parameter {}", value.id, parameter.name)));
+			}
+		}
 	}
+
+	Ok(Operation {
+		description: value.description,
+		id: value.id,
+		path,
+		kubernetes_action: value.kubernetes_action,
+		kubernetes_group_kind_version: value.kubernetes_group_kind_version,
+		method,
+		parameters,
+		responses: OperationResponses::Map(responses?),
+		tag: value.tags.map(|t|t.0),
+	})
 }
@@ -0,0 +1,398 @@
+/// An OpenAPI v3 document, like the ones that the API server serves at `/openapi/v3/api/v1` and `/openapi/v3/apis/$group/$version`.
+///
+/// Each document contains the operations of one group version, and the definitions used by them. Deserialize each document into this type,
+/// then combine them into a single [`Spec`](super::Spec) with [`Spec::from_openapi_v3`](super::Spec::from_openapi_v3).
+///
+/// The schemas and operations of the document are parsed into the same model as that of the Swagger 2.0 spec:
+///
+/// - References of the form `#/components/schemas/$definitionName` are parsed like `#/definitions/$definitionName`.
+///   A reference wrapped in a single-element `allOf`, which the v3 documents use to attach a description to the reference, is parsed as the reference itself.
+///
+/// - The schema of the `requestBody` of an operation is parsed as a body parameter named `body`.
+///
+/// - The schema of each response of an operation is taken from its `application/json` content if present,
+///   otherwise its `*/*` content, otherwise its first content.
+///
+/// - A string schema with an `enum` is parsed as a [`Type::StringEnum`](super::Type::StringEnum), and the `default` and `nullable` of a schema
+///   are parsed into [`Schema::default`](super::Schema::default) and [`Schema::nullable`](super::Schema::nullable).
+///   A `oneOf` of an integer and a string schema is parsed as a [`Type::IntOrString`](super::Type::IntOrString).
+#[derive(Debug)]
+pub struct OpenApiV3Document {
+	pub info: super::Info,
+	pub definitions: std::collections::BTreeMap<super::DefinitionPath, super::Schema>,
+	pub operations: Vec<super::Operation>,
+}
+
+#[cfg(feature = "serde")]
+#[allow(clippy::use_self)]
+impl<'de> serde::Deserialize<'de> for OpenApiV3Document {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+		#[derive(Debug, serde::Deserialize)]
+		struct InnerDocument {
+			openapi: String,
+			info: super::Info,
+			#[serde(default)]
+			components: InnerComponents,
+			#[serde(default)]
+			paths: std::collections::BTreeMap<super::Path, super::InnerPathItem<InnerOperation>>,
+		}
+
+		#[derive(Debug, Default, serde::Deserialize)]
+		struct InnerComponents {
+			#[serde(default)]
+			schemas: std::collections::BTreeMap<super::DefinitionPath, super::Schema>,
+		}
+
+		#[derive(Debug, serde::Deserialize)]
+		struct InnerOperation {
+			description: Option<String>,
+			#[serde(rename = "operationId")]
+			id: String,
+			#[serde(rename = "x-kubernetes-action")]
+			kubernetes_action: Option<super::KubernetesAction>,
+			#[serde(rename = "x-kubernetes-group-version-kind")]
+			kubernetes_group_kind_version: Option<super::KubernetesGroupKindVersion>,
+			#[serde(default)]
+			parameters: Vec<std::sync::Arc<super::Parameter>>,
+			#[serde(rename = "requestBody")]
+			request_body: Option<InnerRequestBody>,
+			responses: std::collections::BTreeMap<String, InnerResponse>,
+			tags: Option<(String,)>,
+		}
+
+		#[derive(Debug, serde::Deserialize)]
+		struct InnerRequestBody {
+			#[serde(default)]
+			content: std::collections::BTreeMap<String, InnerMediaType>,
+			description: Option<String>,
+			#[serde(default)]
+			required: bool,
+		}
+
+		#[derive(Debug, serde::Deserialize)]
+		struct InnerResponse {
+			#[serde(default)]
+			content: std::collections::BTreeMap<String, InnerMediaType>,
+		}
+
+		#[derive(Debug, serde::Deserialize)]
+		struct InnerMediaType {
+			schema: Option<super::Schema>,
+		}
+
+		fn content_schema(mut content: std::collections::BTreeMap<String, InnerMediaType>) -> Option<super::Schema> {
+			let media_type =
+				content.remove("application/json")
+				.or_else(|| content.remove("*/*"))
+				.or_else(|| content.into_values().next())?;
+			media_type.schema
+		}
+
+		fn convert_operation(value: InnerOperation) -> super::InnerOperation {
+			let mut parameters = value.parameters;
+			if let Some(request_body) = value.request_body {
+				if let Some(schema) = content_schema(request_body.content) {
+					parameters.insert(0, std::sync::Arc::new(super::Parameter {
+						location: super::ParameterLocation::Body,
+						name: "body".to_owned(),
+						required: request_body.required,
+						schema: super::Schema {
+							description: request_body.description,
+							..schema
+						},
+					}));
+				}
+			}
+
+			super::InnerOperation {
+				description: value.description,
+				id: value.id,
+				kubernetes_action: value.kubernetes_action,
+				kubernetes_group_kind_version: value.kubernetes_group_kind_version,
+				parameters,
+				responses:
+					value.responses.into_iter()
+					.map(|(status_code, response)| (status_code, super::InnerResponse { schema: content_schema(response.content) }))
+					.collect(),
+				tags: value.tags,
+			}
+		}
+
+		let result: InnerDocument = serde::Deserialize::deserialize(deserializer)?;
+
+		if !result.openapi.starts_with("3.") {
+			return Err(serde::de::Error::invalid_value(serde::de::Unexpected::Str(&result.openapi), &"3.x"));
+		}
+
+		let paths =
+			result.paths.into_iter()
+			.map(|(path, path_item)| (path, super::InnerPathItem {
+				delete: path_item.delete.map(convert_operation),
+				get: path_item.get.map(convert_operation),
+				patch: path_item.patch.map(convert_operation),
+				post: path_item.post.map(convert_operation),
+				put: path_item.put.map(convert_operation),
+				parameters: path_item.parameters,
+			}))
+			.collect();
+		let operations = super::parse_paths::<D>(paths)?;
+
+		Ok(OpenApiV3Document {
+			info: result.info,
+			definitions: result.components.schemas,
+			operations,
+		})
+	}
+}
+
+impl super::Spec {
+	/// Combines a set of OpenAPI v3 documents into a single spec.
+	///
+	/// The definitions that are present in more than one document must be identical in all of them.
+	/// The operations must have unique IDs across all documents. The `info` of the spec is taken from the first document.
+	pub fn from_openapi_v3(documents: impl IntoIterator<Item = OpenApiV3Document>) -> Result<Self, crate::Error> {
+		let mut info = None;
+		let mut definitions: std::collections::BTreeMap<super::DefinitionPath, super::Schema> = Default::default();
+		let mut operations = vec![];
+		let mut operation_ids: std::collections::BTreeSet<String> = Default::default();
+
+		for document in documents {
+			info.get_or_insert(document.info);
+
+			for (definition_path, schema) in document.definitions {
+				match definitions.entry(definition_path) {
+					std::collections::btree_map::Entry::Occupied(entry) =>
+						if *entry.get() != schema {
+							return Err(format!("definition {} differs between OpenAPI v3 documents", entry.key()).into());
+						},
+
+					std::collections::btree_map::Entry::Vacant(entry) => {
+						entry.insert(schema);
+					},
+				}
+			}
+
+			for operation in document.operations {
+				if !operation_ids.insert(operation.id.clone()) {
+					return Err(format!("operation {} is present in more than one OpenAPI v3 document", operation.id).into());
+				}
+
+				operations.push(operation);
+			}
+		}
+
+		let info = info.ok_or("no OpenAPI v3 documents")?;
+
+		Ok(super::Spec {
+			info,
+			definitions,
+			operations,
+		})
+	}
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+	#[test]
+	fn from_openapi_v3() {
+		let core_v1: super::OpenApiV3Document = serde_json::from_str(r##"{
+			"openapi": "3.0.0",
+			"info": { "title": "Kubernetes", "version": "v1.27.0" },
+			"components": {
+				"schemas": {
+					"io.k8s.api.core.v1.Pod": {
+						"description": "Pod is a collection of containers that can run on a host.",
+						"type": "object",
+						"properties": {
+							"metadata": {
+								"allOf": [{ "$ref": "#/components/schemas/io.k8s.apimachinery.pkg.apis.meta.v1.ObjectMeta" }],
+								"default": {},
+								"description": "Standard object's metadata."
+							},
+							"restartPolicy": {
+								"description": "Restart policy for all containers within the pod.",
+								"enum": ["Always", "Never", "OnFailure"],
+								"type": "string"
+							},
+							"terminationGracePeriodSeconds": {
+								"description": "Optional duration in seconds the pod needs to terminate gracefully.",
+								"format": "int64",
+								"nullable": true,
+								"type": "integer"
+							}
+						},
+						"x-kubernetes-group-version-kind": [{ "group": "", "kind": "Pod", "version": "v1" }]
+					},
+					"io.k8s.apimachinery.pkg.apis.meta.v1.ObjectMeta": {
+						"description": "ObjectMeta is metadata that all persisted resources must have.",
+						"type": "object",
+						"properties": {
+							"name": { "description": "Name must be unique within a namespace.", "type": "string" }
+						}
+					}
+				}
+			},
+			"paths": {
+				"/api/v1/namespaces/{namespace}/pods": {
+					"parameters": [
+						{ "description": "object name and auth scope", "in": "path", "name": "namespace", "required": true, "schema": { "type": "string", "uniqueItems": true } }
+					],
+					"get": {
+						"description": "list or watch objects of kind Pod",
+						"operationId": "listCoreV1NamespacedPod",
+						"parameters": [
+							{ "description": "limit", "in": "query", "name": "limit", "schema": { "type": "integer", "uniqueItems": true } }
+						],
+						"responses": {
+							"200": {
+								"content": {
+									"application/json;stream=watch": { "schema": { "type": "string" } },
+									"application/json": { "schema": { "$ref": "#/components/schemas/io.k8s.api.core.v1.Pod" } }
+								},
+								"description": "OK"
+							},
+							"401": { "description": "Unauthorized" }
+						},
+						"tags": ["core_v1"],
+						"x-kubernetes-action": "list",
+						"x-kubernetes-group-version-kind": { "group": "", "kind": "Pod", "version": "v1" }
+					},
+					"post": {
+						"description": "create a Pod",
+						"operationId": "createCoreV1NamespacedPod",
+						"requestBody": {
+							"content": { "*/*": { "schema": { "$ref": "#/components/schemas/io.k8s.api.core.v1.Pod" } } },
+							"required": true
+						},
+						"responses": {
+							"201": { "content": { "application/json": { "schema": { "$ref": "#/components/schemas/io.k8s.api.core.v1.Pod" } } }, "description": "Created" }
+						},
+						"tags": ["core_v1"],
+						"x-kubernetes-action": "post",
+						"x-kubernetes-group-version-kind": { "group": "", "kind": "Pod", "version": "v1" }
+					}
+				}
+			}
+		}"##).unwrap();
+
+		let version: super::OpenApiV3Document = serde_json::from_str(r#"{
+			"openapi": "3.0.0",
+			"info": { "title": "Kubernetes", "version": "v1.27.0" },
+			"components": {
+				"schemas": {
+					"io.k8s.apimachinery.pkg.apis.meta.v1.ObjectMeta": {
+						"description": "ObjectMeta is metadata that all persisted resources must have.",
+						"type": "object",
+						"properties": {
+							"name": { "description": "Name must be unique within a namespace.", "type": "string" }
+						}
+					}
+				}
+			},
+			"paths": {
+				"/version/": {
+					"get": {
+						"description": "get the code version",
+						"operationId": "getCodeVersion",
+						"responses": {
+							"200": { "content": { "application/json": { "schema": { "type": "object" } } }, "description": "OK" }
+						},
+						"tags": ["version"]
+					}
+				}
+			}
+		}"#).unwrap();
+
+		let spec = crate::swagger20::Spec::from_openapi_v3([core_v1, version]).unwrap();
+
+		assert_eq!(spec.info.version, "v1.27.0");
+		assert_eq!(
+			spec.definitions.keys().map(|definition_path| &**definition_path).collect::<Vec<_>>(),
+			["io.k8s.api.core.v1.Pod", "io.k8s.apimachinery.pkg.apis.meta.v1.ObjectMeta"],
+		);
+
+		let crate::swagger20::SchemaKind::Properties(properties) = &spec.definitions[&crate::swagger20::DefinitionPath("io.k8s.api.core.v1.Pod".to_owned())].kind else {
+			panic!("Pod is not a Properties schema");
+		};
+		let (metadata, metadata_required) = &properties[&crate::swagger20::PropertyName("metadata".to_owned())];
+		assert_eq!(metadata.description.as_deref(), Some("Standard object's metadata."));
+		assert!(matches!(
+			&metadata.kind,
+			crate::swagger20::SchemaKind::Ref(crate::swagger20::RefPath { path, .. }) if path == "io.k8s.apimachinery.pkg.apis.meta.v1.ObjectMeta"
+		));
+		assert!(!metadata_required);
+		assert_eq!(metadata.default.as_deref(), Some("{}"));
+		assert!(!metadata.nullable);
+
+		let (restart_policy, _) = &properties[&crate::swagger20::PropertyName("restartPolicy".to_owned())];
+		let crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::StringEnum(restart_policy_values)) = &restart_policy.kind else {
+			panic!("restartPolicy is not a StringEnum schema");
+		};
+		assert_eq!(
+			restart_policy_values.iter().map(|(value, _)| &**value).collect::<Vec<_>>(),
+			["Always", "Never", "OnFailure"],
+		);
+		assert_eq!(restart_policy.default, None);
+
+		let (termination_grace_period_seconds, _) = &properties[&crate::swagger20::PropertyName("terminationGracePeriodSeconds".to_owned())];
+		assert!(termination_grace_period_seconds.nullable);
+
+		assert_eq!(
+			spec.operations.iter().map(|operation| (&*operation.id, operation.method)).collect::<Vec<_>>(),
+			[
+				("listCoreV1NamespacedPod", crate::swagger20::Method::Get),
+				("createCoreV1NamespacedPod", crate::swagger20::Method::Post),
+				("getCodeVersion", crate::swagger20::Method::Get),
+			],
+		);
+
+		let list = &spec.operations[0];
+		assert_eq!(
+			list.parameters.iter().map(|parameter| (&*parameter.name, parameter.location, parameter.required)).collect::<Vec<_>>(),
+			[
+				("namespace", crate::swagger20::ParameterLocation::Path, true),
+				("limit", crate::swagger20::ParameterLocation::Query, false),
+			],
+		);
+		assert!(matches!(
+			list.parameters[1].schema.kind,
+			crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::Integer { format: crate::swagger20::IntegerFormat::Int64 }),
+		));
+		let crate::swagger20::OperationResponses::Map(responses) = &list.responses else {
+			panic!("operation responses are not a map");
+		};
+		assert_eq!(responses.keys().copied().collect::<Vec<_>>(), [http::StatusCode::OK]);
+		assert!(matches!(
+			&responses[&http::StatusCode::OK].kind,
+			crate::swagger20::SchemaKind::Ref(crate::swagger20::RefPath { path, .. }) if path == "io.k8s.api.core.v1.Pod"
+		));
+
+		let create = &spec.operations[1];
+		assert_eq!(
+			create.parameters.iter().map(|parameter| (&*parameter.name, parameter.location, parameter.required)).collect::<Vec<_>>(),
+			[
+				("namespace", crate::swagger20::ParameterLocation::Path, true),
+				("body", crate::swagger20::ParameterLocation::Body, true),
+			],
+		);
+	}
+
+	#[test]
+	fn from_openapi_v3_conflicting_definitions() {
+		fn document(description: &str) -> super::OpenApiV3Document {
+			serde_json::from_value(serde_json::json!({
+				"openapi": "3.0.0",
+				"info": { "title": "Kubernetes", "version": "v1.27.0" },
+				"components": {
+					"schemas": {
+						"io.k8s.apimachinery.pkg.apis.meta.v1.Time": { "description": description, "format": "date-time", "type": "string" },
+					},
+				},
+			})).unwrap()
+		}
+
+		assert!(crate::swagger20::Spec::from_openapi_v3([document("Time"), document("Time")]).is_ok());
+		assert!(crate::swagger20::Spec::from_openapi_v3([document("Time"), document("Other")]).is_err());
+		assert!(crate::swagger20::Spec::from_openapi_v3([]).is_err());
+	}
+}
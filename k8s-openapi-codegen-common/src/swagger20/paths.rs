@@ -119,12 +119,31 @@ impl<'de> serde::Deserialize<'de> for Parameter {
 				},
 			),
 
+			// OpenAPI v3 documents specify the type of path and query parameters with a schema.
+			("path", None, Some(schema)) => (
+				ParameterLocation::Path,
+				super::Schema {
+					description: value.description,
+					..schema
+				},
+			),
+
+			("query", None, Some(schema)) => (
+				ParameterLocation::Query,
+				super::Schema {
+					description: value.description,
+					..schema
+				},
+			),
+
 			("path", Some(ty), None) => (
 				ParameterLocation::Path,
 				super::Schema {
 					description: value.description,
 					kind: super::SchemaKind::Ty(super::Type::parse::<D>(&ty, None, None, None)?),
 					kubernetes_group_kind_versions: vec![],
+					default: None,
+					nullable: false,
 					list_kind: None,
 					impl_deep_merge: true,
 				},
@@ -136,6 +155,8 @@ impl<'de> serde::Deserialize<'de> for Parameter {
 					description: value.description,
 					kind: super::SchemaKind::Ty(super::Type::parse::<D>(&ty, None, None, None)?),
 					kubernetes_group_kind_versions: vec![],
+					default: None,
+					nullable: false,
 					list_kind: None,
 					impl_deep_merge: true,
 				},
@@ -0,0 +1,323 @@
+/// A resource type that is served by the API server with the standard verbs, like a custom resource.
+///
+/// [`ResourceOperations::operations`] constructs the operations of this resource type in the form that the operations of built-in resource types
+/// have after `k8s-openapi-codegen` has applied its fixups to the spec, so [`crate::run`] generates the same functions for both.
+#[derive(Clone, Copy, Debug)]
+pub struct ResourceOperations<'a> {
+	/// The API group of the resource, or empty for the core group.
+	pub group: &'a str,
+
+	/// The API version of the resource.
+	pub version: &'a str,
+
+	/// The kind of the resource.
+	pub kind: &'a str,
+
+	/// The plural name of the resource, used in the URLs of its operations.
+	pub plural: &'a str,
+
+	/// The definition path of the resource type. This is used to construct the operation IDs, so it must be a valid identifier.
+	pub type_name: &'a str,
+
+	/// Whether the resource is namespaced or cluster-scoped.
+	pub namespaced: bool,
+}
+
+impl ResourceOperations<'_> {
+	/// Constructs the operations of this resource type.
+	///
+	/// This includes the operations on the status subresource, and for namespaced resources,
+	/// the operations to list and watch the resources in all namespaces.
+	pub fn operations(&self) -> Vec<super::Operation> {
+		let ResourceOperations { group, version, kind, plural, type_name, namespaced } = *self;
+
+		let kubernetes_group_kind_version = super::KubernetesGroupKindVersion {
+			group: group.to_owned(),
+			kind: kind.to_owned(),
+			version: version.to_owned(),
+		};
+
+		let api_path = if group.is_empty() { format!("/api/{version}") } else { format!("/apis/{group}/{version}") };
+
+		let (namespace_operation_id_component, namespace_parameter, namespace_path_component) =
+			if namespaced {
+				("Namespaced", Some(parameter(
+					super::ParameterLocation::Path,
+					"namespace",
+					Some("object name and auth scope, such as for teams and projects"),
+					super::SchemaKind::Ty(super::Type::String { format: None }),
+				)), "/namespaces/{namespace}")
+			}
+			else {
+				("", None, "")
+			};
+
+		let body_parameter = parameter(
+			super::ParameterLocation::Body,
+			"body",
+			None,
+			super::SchemaKind::Ref(super::RefPath { path: type_name.to_owned(), can_be_default: None }),
+		);
+
+		let name_parameter = std::sync::Arc::new(super::Parameter {
+			location: super::ParameterLocation::Path,
+			name: "name".to_owned(),
+			required: true,
+			schema: schema(Some(format!("name of the `{type_name}`")), super::SchemaKind::Ty(super::Type::String { format: None })),
+		});
+
+		let patch_body_parameter = parameter(
+			super::ParameterLocation::Body,
+			"body",
+			None,
+			super::SchemaKind::Ref(super::RefPath { path: "io.k8s.apimachinery.pkg.apis.meta.v1.Patch".to_owned(), can_be_default: None }),
+		);
+
+		let optional_parameter = |location, name, description, path: &str| parameter(
+			location,
+			name,
+			description,
+			super::SchemaKind::Ref(super::RefPath { path: path.to_owned(), can_be_default: None }),
+		);
+		let create_optional_parameter = optional_parameter(
+			super::ParameterLocation::Query, "optional", Some("Optional parameters. Use `Default::default()` to not pass any."), "io.k8s.CreateOptional");
+		let delete_optional_parameter = optional_parameter(
+			super::ParameterLocation::Query, "optional", Some("Optional parameters. Use `Default::default()` to not pass any."), "io.k8s.DeleteOptional");
+		let list_optional_parameter = optional_parameter(
+			super::ParameterLocation::Query, "optional", Some("Optional parameters. Use `Default::default()` to not pass any."), "io.k8s.ListOptional");
+		let patch_optional_parameter = optional_parameter(super::ParameterLocation::Body, "optional", None, "io.k8s.PatchOptional");
+		let replace_optional_parameter = optional_parameter(super::ParameterLocation::Body, "optional", None, "io.k8s.ReplaceOptional");
+		let watch_optional_parameter = optional_parameter(
+			super::ParameterLocation::Query, "optional", Some("Optional parameters. Use `Default::default()` to not pass any."), "io.k8s.WatchOptional");
+
+		let read_responses = || super::OperationResponses::Map([
+			(http::StatusCode::OK, schema(
+				Some("OK".to_owned()),
+				super::SchemaKind::Ref(super::RefPath { path: type_name.to_owned(), can_be_default: None }),
+			)),
+		].into());
+
+		let operation = |
+			description: String,
+			id: String,
+			method,
+			kubernetes_action,
+			parameters: Vec<Option<std::sync::Arc<super::Parameter>>>,
+			path: String,
+			responses,
+		| super::Operation {
+			description: Some(description),
+			id,
+			method,
+			kubernetes_action: Some(kubernetes_action),
+			kubernetes_group_kind_version: Some(kubernetes_group_kind_version.clone()),
+			parameters: parameters.into_iter().flatten().collect(),
+			path: super::Path(path),
+			responses,
+			tag: None,
+		};
+
+		let mut result = vec![
+			operation(
+				format!("Create a `{type_name}`"),
+				format!("create{namespace_operation_id_component}{type_name}"),
+				super::Method::Post,
+				super::KubernetesAction::Post,
+				vec![
+					Some(body_parameter.clone()),
+					namespace_parameter.clone(),
+					Some(create_optional_parameter),
+				],
+				format!("{api_path}{namespace_path_component}/{plural}"),
+				super::OperationResponses::Common(super::Type::CreateResponse),
+			),
+
+			operation(
+				format!("Delete a `{type_name}`"),
+				format!("delete{namespace_operation_id_component}{type_name}"),
+				super::Method::Delete,
+				super::KubernetesAction::Delete,
+				vec![
+					Some(name_parameter.clone()),
+					namespace_parameter.clone(),
+					Some(delete_optional_parameter),
+				],
+				format!("{api_path}{namespace_path_component}/{plural}/{{name}}"),
+				super::OperationResponses::Common(super::Type::DeleteResponse),
+			),
+
+			operation(
+				format!("Delete a collection of objects of kind `{type_name}`"),
+				format!("deleteCollection{namespace_operation_id_component}{type_name}"),
+				super::Method::Delete,
+				super::KubernetesAction::DeleteCollection,
+				vec![
+					namespace_parameter.clone(),
+					Some(optional_parameter(
+						super::ParameterLocation::Query, "deleteOptional", Some("Delete options. Use `Default::default()` to not pass any."), "io.k8s.DeleteOptional")),
+					Some(optional_parameter(
+						super::ParameterLocation::Query, "listOptional", Some("List options. Use `Default::default()` to not pass any."), "io.k8s.ListOptional")),
+				],
+				format!("{api_path}{namespace_path_component}/{plural}"),
+				super::OperationResponses::Common(super::Type::DeleteResponse),
+			),
+
+			operation(
+				format!("List objects of kind `{type_name}`"),
+				format!("list{namespace_operation_id_component}{type_name}"),
+				super::Method::Get,
+				super::KubernetesAction::List,
+				vec![
+					namespace_parameter.clone(),
+					Some(list_optional_parameter.clone()),
+				],
+				format!("{api_path}{namespace_path_component}/{plural}"),
+				super::OperationResponses::Common(super::Type::ListResponse),
+			),
+
+			operation(
+				format!("Partially update the specified `{type_name}`"),
+				format!("patch{namespace_operation_id_component}{type_name}"),
+				super::Method::Patch,
+				super::KubernetesAction::Patch,
+				vec![
+					Some(patch_body_parameter.clone()),
+					Some(name_parameter.clone()),
+					namespace_parameter.clone(),
+					Some(patch_optional_parameter.clone()),
+				],
+				format!("{api_path}{namespace_path_component}/{plural}/{{name}}"),
+				super::OperationResponses::Common(super::Type::PatchResponse),
+			),
+
+			operation(
+				format!("Partially update the state of the specified `{type_name}`"),
+				format!("patch{namespace_operation_id_component}{type_name}Status"),
+				super::Method::Patch,
+				super::KubernetesAction::Patch,
+				vec![
+					Some(patch_body_parameter),
+					Some(name_parameter.clone()),
+					namespace_parameter.clone(),
+					Some(patch_optional_parameter),
+				],
+				format!("{api_path}{namespace_path_component}/{plural}/{{name}}/status"),
+				super::OperationResponses::Common(super::Type::PatchResponse),
+			),
+
+			operation(
+				format!("Read the specified `{type_name}`"),
+				format!("read{namespace_operation_id_component}{type_name}"),
+				super::Method::Get,
+				super::KubernetesAction::Get,
+				vec![
+					Some(name_parameter.clone()),
+					namespace_parameter.clone(),
+				],
+				format!("{api_path}{namespace_path_component}/{plural}/{{name}}"),
+				read_responses(),
+			),
+
+			operation(
+				format!("Read status of the specified `{type_name}`"),
+				format!("read{namespace_operation_id_component}{type_name}Status"),
+				super::Method::Get,
+				super::KubernetesAction::Get,
+				vec![
+					Some(name_parameter.clone()),
+					namespace_parameter.clone(),
+				],
+				format!("{api_path}{namespace_path_component}/{plural}/{{name}}/status"),
+				read_responses(),
+			),
+
+			operation(
+				format!("Replace the specified `{type_name}`"),
+				format!("replace{namespace_operation_id_component}{type_name}"),
+				super::Method::Put,
+				super::KubernetesAction::Put,
+				vec![
+					Some(body_parameter.clone()),
+					Some(name_parameter.clone()),
+					namespace_parameter.clone(),
+					Some(replace_optional_parameter.clone()),
+				],
+				format!("{api_path}{namespace_path_component}/{plural}/{{name}}"),
+				super::OperationResponses::Common(super::Type::ReplaceResponse),
+			),
+
+			operation(
+				format!("Replace status of the specified `{type_name}`"),
+				format!("replace{namespace_operation_id_component}{type_name}Status"),
+				super::Method::Put,
+				super::KubernetesAction::Put,
+				vec![
+					Some(body_parameter),
+					Some(name_parameter),
+					namespace_parameter.clone(),
+					Some(replace_optional_parameter),
+				],
+				format!("{api_path}{namespace_path_component}/{plural}/{{name}}/status"),
+				super::OperationResponses::Common(super::Type::ReplaceResponse),
+			),
+
+			operation(
+				format!("Watch objects of kind `{type_name}`"),
+				format!("watch{namespace_operation_id_component}{type_name}"),
+				super::Method::Get,
+				super::KubernetesAction::Watch,
+				vec![
+					namespace_parameter,
+					Some(watch_optional_parameter.clone()),
+				],
+				format!("{api_path}{namespace_path_component}/{plural}"),
+				super::OperationResponses::Common(super::Type::WatchResponse),
+			),
+		];
+
+		if namespaced {
+			result.push(operation(
+				format!("List objects of kind `{type_name}` across all namespaces"),
+				format!("list{type_name}ForAllNamespaces"),
+				super::Method::Get,
+				super::KubernetesAction::List,
+				vec![Some(list_optional_parameter)],
+				format!("{api_path}/{plural}"),
+				super::OperationResponses::Common(super::Type::ListResponse),
+			));
+
+			result.push(operation(
+				format!("Watch objects of kind `{type_name}` across all namespaces"),
+				format!("watch{type_name}ForAllNamespaces"),
+				super::Method::Get,
+				super::KubernetesAction::Watch,
+				vec![Some(watch_optional_parameter)],
+				format!("{api_path}/{plural}"),
+				super::OperationResponses::Common(super::Type::WatchResponse),
+			));
+		}
+
+		result
+	}
+}
+
+fn parameter(location: super::ParameterLocation, name: &str, description: Option<&str>, kind: super::SchemaKind) -> std::sync::Arc<super::Parameter> {
+	std::sync::Arc::new(super::Parameter {
+		location,
+		name: name.to_owned(),
+		required: true,
+		schema: schema(description.map(ToOwned::to_owned), kind),
+	})
+}
+
+fn schema(description: Option<String>, kind: super::SchemaKind) -> super::Schema {
+	super::Schema {
+		description,
+		kind,
+		kubernetes_group_kind_versions: vec![],
+		default: None,
+		nullable: false,
+		list_kind: None,
+		impl_deep_merge: true,
+	}
+}
@@ -22,6 +22,8 @@ pub(crate) mod patch;
 
 pub(crate) mod query_string_optional;
 
+pub(crate) mod string_enum;
+
 pub(crate) mod r#struct;
 
 pub(crate) mod struct_deep_merge;
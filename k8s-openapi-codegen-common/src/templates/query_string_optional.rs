@@ -26,6 +26,11 @@ pub(crate) fn generate(
 		match &**field_type_name {
 			"Option<&'a str>" => writeln!(fields_append_pair, r#"            __query_pairs.append_pair({name:?}, value);"#)?,
 			"Option<bool>" => writeln!(fields_append_pair, r#"            __query_pairs.append_pair({name:?}, if value {{ "true" }} else {{ "false" }});"#)?,
+			field_type_name if field_type_name.starts_with("Option<&'a [") => {
+				writeln!(fields_append_pair, "            for value in value {{")?;
+				writeln!(fields_append_pair, "                __query_pairs.append_pair({name:?}, &value.to_string());")?;
+				writeln!(fields_append_pair, "            }}")?;
+			},
 			_ => writeln!(fields_append_pair, r#"            __query_pairs.append_pair({name:?}, &value.to_string());"#)?,
 		}
 		writeln!(fields_append_pair, "        }}")?;
@@ -0,0 +1,43 @@
+pub(crate) fn generate(
+	mut writer: impl std::io::Write,
+	type_name: &str,
+	vis: &str,
+	variants: &[(String, String)],
+	operation_feature: Option<&str>,
+	map_namespace: &impl crate::MapNamespace,
+) -> Result<(), crate::Error> {
+	let local = crate::map_namespace_local_to_string(map_namespace)?;
+
+	let operation_feature_attribute: std::borrow::Cow<'static, str> =
+		operation_feature.map_or("".into(), |operation_feature| format!("#[cfg(feature = {operation_feature:?})]\n").into());
+
+	let mut variants_definition = String::new();
+	let mut as_str_arms = String::new();
+
+	for (i, (value, comment)) in variants.iter().enumerate() {
+		use std::fmt::Write;
+
+		if i > 0 {
+			writeln!(variants_definition)?;
+		}
+		for line in crate::get_comment_text(comment, "") {
+			writeln!(variants_definition, "    ///{line}")?;
+		}
+		writeln!(variants_definition, "    {value},")?;
+
+		writeln!(as_str_arms, "            {type_name}::{value} => {value:?},")?;
+	}
+
+	writeln!(
+		writer,
+		include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/string_enum.rs")),
+		local = local,
+		type_name = type_name,
+		vis = vis,
+		operation_feature_attribute = operation_feature_attribute,
+		variants = variants_definition,
+		as_str_arms = as_str_arms,
+	)?;
+
+	Ok(())
+}
@@ -4,6 +4,8 @@ pub(crate) fn generate(
 	type_name: &str,
 	generics: super::Generics<'_>,
 	fields: &[super::Property<'_>],
+	definition_path: &crate::swagger20::DefinitionPath,
+	hooks: &dyn crate::Hooks,
 ) -> Result<(), crate::Error> {
 	use std::fmt::Write;
 
@@ -11,7 +13,7 @@ pub(crate) fn generate(
 	let type_generics_where = generics.where_part.map(|part| format!(" where {part}")).unwrap_or_default();
 
 	let mut fields_string = String::new();
-	for super::Property { comment, field_name, field_type_name, .. } in fields {
+	for super::Property { name, comment, field_name, field_type_name, .. } in fields {
 		if !fields_string.is_empty() {
 			writeln!(fields_string)?;
 		}
@@ -22,6 +24,19 @@ pub(crate) fn generate(
 			}
 		}
 
+		if let Some(property_doc) = hooks.property_doc(definition_path, name) {
+			if comment.is_some() {
+				writeln!(fields_string, "    ///")?;
+			}
+			for line in crate::get_hook_doc_lines(&property_doc) {
+				writeln!(fields_string, "    {line}")?;
+			}
+		}
+
+		for attribute in hooks.property_attributes(definition_path, name) {
+			writeln!(fields_string, "    {attribute}")?;
+		}
+
 		writeln!(
 			fields_string,
 			"    {vis}{field_name}: {field_type_name},",
@@ -3,32 +3,63 @@ pub(crate) fn generate(
 	definition_path: &crate::swagger20::DefinitionPath,
 	type_comment: Option<&str>,
 	type_feature: Option<&str>,
-	derives: Option<Derives>,
+	derives: Option<Derives<'_>>,
+	hooks: &dyn crate::Hooks,
 	vis: &str,
 ) -> Result<(), crate::Error> {
-	let type_comment: String =
+	use std::fmt::Write;
+
+	let mut type_comment: String =
 		type_comment
 		.map(|type_comment| crate::get_comment_text(type_comment, "").map(|line| format!("///{line}\n")).collect())
 		.unwrap_or_default();
+	if let Some(type_doc) = hooks.type_doc(definition_path) {
+		if !type_comment.is_empty() {
+			writeln!(type_comment, "///")?;
+		}
+		for line in crate::get_hook_doc_lines(&type_doc) {
+			writeln!(type_comment, "{line}")?;
+		}
+	}
 
 	let type_feature_attribute =
 		type_feature
 		.map(|type_feature| format!("#[cfg(feature = {type_feature:?})]\n"))
 		.unwrap_or_default();
 
-	let derives =
-		derives
-		.map(|Derives { clone, copy, default, eq, ord, partial_eq, partial_ord }| format!(
-			"#[derive({clone}{copy}Debug{default}{eq}{ord}{partial_eq}{partial_ord})]\n",
+	let mut derives_string = String::new();
+	if let Some(Derives { clone, copy, default, eq, hash, ord, partial_eq, partial_ord, eq_hash_ord_feature }) = derives {
+		writeln!(
+			derives_string,
+			"#[derive({clone}{copy}Debug{default}{eq}{hash}{ord}{partial_eq}{partial_ord})]",
 			clone = if clone { "Clone, " } else { "" },
 			copy = if copy { "Copy, " } else { "" },
 			default = if default { ", Default" } else { "" },
 			eq = if eq { ", Eq" } else { "" },
+			hash = if hash { ", Hash" } else { "" },
 			ord = if ord { ", Ord" } else { "" },
 			partial_eq = if partial_eq { ", PartialEq" } else { "" },
 			partial_ord = if partial_ord { ", PartialOrd" } else { "" },
-		))
-		.unwrap_or_default();
+		)?;
+
+		if let Some(eq_hash_ord_feature) = eq_hash_ord_feature {
+			let feature_derives: Vec<_> =
+				[(eq, "Eq"), (hash, "Hash"), (ord, "Ord"), (partial_ord, "PartialOrd")].into_iter()
+				.filter_map(|(derived, name)| (!derived).then_some(name))
+				.collect();
+			if !feature_derives.is_empty() {
+				writeln!(derives_string, "#[cfg_attr(feature = {eq_hash_ord_feature:?}, derive({}))]", feature_derives.join(", "))?;
+			}
+		}
+	}
+
+	let extra_derives = hooks.derives(definition_path);
+	if !extra_derives.is_empty() {
+		writeln!(derives_string, "#[derive({})]", extra_derives.join(", "))?;
+	}
+	for attribute in hooks.type_attributes(definition_path) {
+		writeln!(derives_string, "{attribute}")?;
+	}
 
 	write!(
 		writer,
@@ -36,7 +67,7 @@ pub(crate) fn generate(
 		definition_path = definition_path,
 		type_comment = type_comment,
 		type_feature_attribute = type_feature_attribute,
-		derives = derives,
+		derives = derives_string,
 		vis = vis,
 	)?;
 
@@ -46,12 +77,16 @@ pub(crate) fn generate(
 // Clippy thinks a struct with lots of bool fields is trying to emulate an enum. This struct is not doing that.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Clone, Copy, Debug)]
-pub(crate) struct Derives {
+pub(crate) struct Derives<'a> {
 	pub(crate) clone: bool,
 	pub(crate) copy: bool,
 	pub(crate) default: bool,
 	pub(crate) eq: bool,
+	pub(crate) hash: bool,
 	pub(crate) ord: bool,
 	pub(crate) partial_eq: bool,
 	pub(crate) partial_ord: bool,
+
+	/// If set, `Eq`, `Hash`, `Ord` and `PartialOrd` are also derived if this feature is enabled, for the ones that aren't already derived.
+	pub(crate) eq_hash_ord_feature: Option<&'a str>,
 }
@@ -0,0 +1,22 @@
+enum {type_name} {{
+{variants}}}
+
+{operation_feature_attribute}impl {type_name} {{
+    /// The value of this parameter as it is sent to the API server.
+    {vis}fn as_str(self) -> &'static str {{
+        match self {{
+{as_str_arms}        }}
+    }}
+}}
+
+{operation_feature_attribute}impl std::fmt::Display for {type_name} {{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+        f.write_str(self.as_str())
+    }}
+}}
+
+{operation_feature_attribute}impl {local}serde::Serialize for {type_name} {{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: {local}serde::Serializer {{
+        serializer.serialize_str(self.as_str())
+    }}
+}}
\ No newline at end of file
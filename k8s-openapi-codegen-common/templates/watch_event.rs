@@ -2,11 +2,29 @@ enum {type_name}<T> {{
     Added(T),
     Deleted(T),
     Modified(T),
-    Bookmark {{ resource_version: String }},
+    /// A bookmark event. It only contains the resource version and the annotations of the watched resource type's metadata, not a whole object.
+    ///
+    /// Note that the `annotations` field was added to this variant to support [`{type_name}::is_initial_events_end`].
+    /// Patterns that match this variant exhaustively, like `Bookmark {{ resource_version }}`, need to match it as `Bookmark {{ resource_version, .. }}` instead.
+    Bookmark {{ annotations: std::collections::BTreeMap<String, String>, resource_version: String }},
     ErrorStatus({error_status_rust_type}),
     ErrorOther({error_other_rust_type}),
 }}
 
+impl<T> {type_name}<T> {{
+    /// Whether this is the bookmark that the API server sends after the initial events of a watch that was requested with `sendInitialEvents` set to `true`,
+    /// ie a bookmark with the `k8s.io/initial-events-end` annotation set to `"true"`.
+    ///
+    /// The events before this bookmark describe the state of the watched objects as of the resource version of the bookmark,
+    /// so such a watch can be used in place of a list followed by a watch.
+    pub fn is_initial_events_end(&self) -> bool {{
+        matches!(
+            self,
+            {type_name}::Bookmark {{ annotations, .. }} if annotations.get("k8s.io/initial-events-end").map(String::as_str) == Some("true"),
+        )
+    }}
+}}
+
 impl<'de, T> {local}serde::Deserialize<'de> for {type_name}<T> where T: {local}serde::Deserialize<'de> {{
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: {local}serde::Deserializer<'de> {{
         #[allow(non_camel_case_types)]
@@ -119,6 +137,7 @@ impl<'de, T> {local}serde::Deserialize<'de> for {type_name}<T> where T: {local}s
                         let value_object = {local}serde_value::ValueDeserializer::new(value_object);
                         let value: BookmarkObject<'static> = {local}serde::Deserialize::deserialize(value_object)?;
                         {type_name}::Bookmark {{
+                            annotations: value.metadata.annotations.into_owned(),
                             resource_version: value.metadata.resource_version.into_owned(),
                         }}
                     }},
@@ -172,10 +191,11 @@ impl<T> {local}serde::Serialize for {type_name}<T> where T: {local}serde::Serial
                 {local}serde::ser::SerializeStruct::serialize_field(&mut state, "type", "MODIFIED")?;
                 {local}serde::ser::SerializeStruct::serialize_field(&mut state, "object", &object)?;
             }},
-            {type_name}::Bookmark {{ resource_version }} => {{
+            {type_name}::Bookmark {{ annotations, resource_version }} => {{
                 {local}serde::ser::SerializeStruct::serialize_field(&mut state, "type", "BOOKMARK")?;
                 let object = BookmarkObject {{
                     metadata: BookmarkObjectMeta {{
+                        annotations: std::borrow::Cow::Borrowed(annotations),
                         resource_version: std::borrow::Cow::Borrowed(&**resource_version),
                     }},
                 }};
@@ -201,6 +221,7 @@ struct BookmarkObject<'a> {{
 
 #[derive(Debug, PartialEq)]
 struct BookmarkObjectMeta<'a> {{
+    annotations: std::borrow::Cow<'a, std::collections::BTreeMap<String, String>>,
     resource_version: std::borrow::Cow<'a, str>,
 }}
 
@@ -274,6 +295,7 @@ impl<'de> {local}serde::Deserialize<'de> for BookmarkObjectMeta<'static> {{
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: {local}serde::Deserializer<'de> {{
         #[allow(non_camel_case_types)]
         enum Field {{
+            Key_annotations,
             Key_resource_version,
             Other,
         }}
@@ -291,6 +313,7 @@ impl<'de> {local}serde::Deserialize<'de> for BookmarkObjectMeta<'static> {{
 
                     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: {local}serde::de::Error {{
                         Ok(match v {{
+                            "annotations" => Field::Key_annotations,
                             "resourceVersion" => Field::Key_resource_version,
                             _ => Field::Other,
                         }})
@@ -311,16 +334,19 @@ impl<'de> {local}serde::Deserialize<'de> for BookmarkObjectMeta<'static> {{
             }}
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: {local}serde::de::MapAccess<'de> {{
+                let mut value_annotations: Option<std::collections::BTreeMap<String, String>> = None;
                 let mut value_resource_version: Option<String> = None;
 
                 while let Some(key) = {local}serde::de::MapAccess::next_key::<Field>(&mut map)? {{
                     match key {{
+                        Field::Key_annotations => value_annotations = {local}serde::de::MapAccess::next_value(&mut map)?,
                         Field::Key_resource_version => value_resource_version = {local}serde::de::MapAccess::next_value(&mut map)?,
                         Field::Other => {{ let _: {local}serde::de::IgnoredAny = {local}serde::de::MapAccess::next_value(&mut map)?; }},
                     }}
                 }}
 
                 Ok(BookmarkObjectMeta {{
+                    annotations: std::borrow::Cow::Owned(value_annotations.unwrap_or_default()),
                     resource_version: std::borrow::Cow::Owned(value_resource_version.ok_or_else(|| {local}serde::de::Error::missing_field("resourceVersion"))?),
                 }})
             }}
@@ -329,6 +355,7 @@ impl<'de> {local}serde::Deserialize<'de> for BookmarkObjectMeta<'static> {{
         deserializer.deserialize_struct(
             "ObjectMeta",
             &[
+                "annotations",
                 "resourceVersion",
             ],
             Visitor,
@@ -351,8 +378,12 @@ impl<'a> {local}serde::Serialize for BookmarkObjectMeta<'a> {{
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: {local}serde::Serializer {{
         let mut state = serializer.serialize_struct(
             "ObjectMeta",
-            1,
+            1 +
+            usize::from(!self.annotations.is_empty()),
         )?;
+        if !self.annotations.is_empty() {{
+            {local}serde::ser::SerializeStruct::serialize_field(&mut state, "annotations", &self.annotations)?;
+        }}
         {local}serde::ser::SerializeStruct::serialize_field(&mut state, "resourceVersion", &self.resource_version)?;
         {local}serde::ser::SerializeStruct::end(state)
     }}
@@ -0,0 +1,471 @@
+//! Generates a standalone crate with the Rust types and API functions of an aggregated API server, like the `metrics.k8s.io` API of the metrics server,
+//! from the spec of the server.
+//!
+//! The spec of an aggregated API server also contains the definitions of the types of the Kubernetes API that the server's types refer to, like `ObjectMeta`.
+//! Only the definitions that the namespace mappings map into `crate` are generated. All other definitions under `io.k8s.` are referred to
+//! via the `k8s_openapi` crate, so that the generated types can be used with the rest of it. Their definitions in the spec are still used to determine
+//! which traits the generated types can derive.
+
+use k8s_openapi_codegen_common::swagger20;
+
+/// Maps the definitions whose paths start with a prefix to a Rust module, such as `io.k8s.metrics.pkg.apis=crate`
+/// or `io.k8s.metrics.pkg.apis.custom_metrics=crate::custom_metrics`.
+#[derive(Clone, Debug)]
+pub(crate) struct NamespaceMapping {
+	prefix: Vec<String>,
+	path: Vec<String>,
+}
+
+impl std::str::FromStr for NamespaceMapping {
+	type Err = crate::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (prefix, path) = s.split_once('=').ok_or_else(|| format!("namespace mapping {s:?} is not of the form PREFIX=PATH"))?;
+
+		let prefix: Vec<_> = prefix.split('.').map(ToOwned::to_owned).collect();
+		let path: Vec<_> = path.split("::").map(ToOwned::to_owned).collect();
+		if prefix.iter().any(String::is_empty) || path.iter().any(String::is_empty) {
+			return Err(format!("namespace mapping {s:?} has an empty component").into());
+		}
+
+		Ok(NamespaceMapping {
+			prefix,
+			path,
+		})
+	}
+}
+
+pub(crate) async fn run(
+	spec_url: &str,
+	namespace_mappings: &[NamespaceMapping],
+	crate_name: &str,
+	out_dir: &std::path::Path,
+	fixups: &[crate::fixups::declarative::Fixup],
+	client: &reqwest::Client,
+) -> Result<(), crate::Error> {
+	if let Some(fixup) = fixups.iter().find(|fixup| !fixup.applies_to_all_versions()) {
+		return Err(format!("fixup {fixup} applies to specific versions of Kubernetes, which is not supported for aggregated API servers").into());
+	}
+
+	let spec = crate::load_spec_from_url(spec_url, client).await?;
+
+	write_crate(spec, spec_url, namespace_mappings, crate_name, out_dir, fixups)
+}
+
+/// Writes the crate for the given spec to `<OUT_DIR>/Cargo.toml` and `<OUT_DIR>/src/`.
+fn write_crate(
+	mut spec: swagger20::Spec,
+	spec_url: &str,
+	namespace_mappings: &[NamespaceMapping],
+	crate_name: &str,
+	out_dir: &std::path::Path,
+	fixups: &[crate::fixups::declarative::Fixup],
+) -> Result<(), crate::Error> {
+	let map_namespace = MapNamespace { namespace_mappings };
+
+	log::info!("Applying fixups...");
+	crate::fixups::special::string_enums(&mut spec);
+	for fixup in fixups {
+		fixup.apply(&mut spec)?;
+	}
+	remove_unrelated_operations(&mut spec, &map_namespace);
+	special_fixups(&mut spec)?;
+
+	let src_dir = out_dir.join("src");
+
+	log::info!("Removing output directory {} ...", src_dir.display());
+	match std::fs::remove_dir_all(&src_dir) {
+		Ok(()) => log::trace!("OK"),
+		Err(err) if err.kind() == std::io::ErrorKind::NotFound => log::trace!("OK. Directory doesn't exist"),
+		Err(err) => return Err(err.into()),
+	}
+
+	log::info!("Creating output directory {} ...", src_dir.display());
+	std::fs::create_dir_all(&src_dir)?;
+
+	log::info!("Generating types...");
+
+	let mut num_generated_types = 0_usize;
+	let mut types = Default::default();
+
+	for definition_path in spec.definitions.keys() {
+		if !map_namespace.is_generated(definition_path) {
+			log::trace!("Skipping {definition_path} because it is not mapped into the crate");
+			continue;
+		}
+
+		log::trace!("Working on {definition_path} ...");
+
+		let run_state = crate::RunState {
+			out_dir: &src_dir,
+			parent_mod_rs_file_and_mod_name: None,
+			types: &mut types,
+			current_type_path: None,
+		};
+
+		let run_result = k8s_openapi_codegen_common::run_with_options(
+			&spec.definitions,
+			&mut spec.operations,
+			definition_path,
+			&map_namespace,
+			"pub ",
+			k8s_openapi_codegen_common::GenerateSchema::Yes { feature: Some("schemars") },
+			Some("api"),
+			&k8s_openapi_codegen_common::Options {
+				derive_eq_hash_ord: k8s_openapi_codegen_common::DeriveEqHashOrd::Yes { feature: Some("eq_hash_ord") },
+				..Default::default()
+			},
+			run_state,
+		)?;
+
+		num_generated_types += run_result.num_generated_structs + run_result.num_generated_type_aliases;
+	}
+
+	if num_generated_types == 0 {
+		return Err("none of the definitions in the spec are mapped into the crate".into());
+	}
+
+	// Top-level operations
+	{
+		let mut mod_root_file = std::io::BufWriter::new(std::fs::OpenOptions::new().append(true).open(src_dir.join("mod.rs"))?);
+
+		spec.operations.sort_by(|o1, o2| o1.id.cmp(&o2.id));
+		for operation in spec.operations {
+			if let Some(swagger20::KubernetesGroupKindVersion { group, kind, version }) = operation.kubernetes_group_kind_version {
+				return Err(format!(
+					"Operation {} is associated with {group}/{version}/{kind} but did not get emitted with that definition",
+					operation.id).into());
+			}
+
+			k8s_openapi_codegen_common::write_operation(
+				&mut mod_root_file,
+				&operation,
+				&map_namespace,
+				"pub ",
+				None,
+				Some("api"),
+			)?;
+		}
+
+		std::io::Write::flush(&mut mod_root_file)?;
+	}
+
+	// The root module is written to `mod.rs` like the root module of each version of k8s-openapi, so turn it into the crate root.
+	let lib_rs = format!("// Generated from the OpenAPI spec at {spec_url}\n\n{}", std::fs::read_to_string(src_dir.join("mod.rs"))?);
+	std::fs::write(src_dir.join("lib.rs"), lib_rs)?;
+	std::fs::remove_file(src_dir.join("mod.rs"))?;
+
+	log::info!("Writing {} ...", out_dir.join("Cargo.toml").display());
+	std::fs::write(out_dir.join("Cargo.toml"), cargo_toml(crate_name)?)?;
+
+	log::info!("OK. Generated {num_generated_types} types");
+
+	Ok(())
+}
+
+/// Removes the operations that do not belong to the generated definitions, such as those of the Kubernetes API itself
+/// when the spec is the spec of the whole cluster.
+///
+/// Operations that are not associated with any definition, like `getMetricsV1beta1APIResources`, are kept if they're under the path of
+/// the API group of a generated definition.
+fn remove_unrelated_operations(spec: &mut swagger20::Spec, map_namespace: &MapNamespace<'_>) {
+	let kubernetes_group_kind_versions: Vec<_> =
+		spec.definitions.iter()
+		.filter(|(definition_path, _)| map_namespace.is_generated(definition_path))
+		.flat_map(|(_, definition)| &definition.kubernetes_group_kind_versions)
+		.collect();
+
+	let group_paths: std::collections::BTreeSet<_> =
+		kubernetes_group_kind_versions.iter()
+		.map(|kubernetes_group_kind_version| format!("/apis/{}/", kubernetes_group_kind_version.group))
+		.collect();
+
+	let num_operations = spec.operations.len();
+
+	spec.operations.retain(|operation| match &operation.kubernetes_group_kind_version {
+		Some(kubernetes_group_kind_version) => kubernetes_group_kind_versions.contains(&kubernetes_group_kind_version),
+		None => group_paths.iter().any(|group_path| operation.path.starts_with(&**group_path)),
+	});
+
+	log::info!("Skipping {} operations that do not belong to the generated definitions", num_operations - spec.operations.len());
+}
+
+/// Applies the special fixups that concern the operations and resource types of an aggregated API server.
+///
+/// The other special fixups only concern the types of the Kubernetes API itself, which the generated crate uses from `k8s_openapi`.
+/// An aggregated API server usually does not serve every kind of operation, so the fixups for one kind of operation are only applied
+/// if the spec contains operations of that kind.
+fn special_fixups(spec: &mut swagger20::Spec) -> Result<(), crate::Error> {
+	#[allow(clippy::type_complexity)]
+	let special_fixups: &[(Option<swagger20::KubernetesAction>, fn(&mut swagger20::Spec) -> Result<(), crate::Error>)] = &[
+		(None, crate::fixups::special::create_optionals_partial),
+		(Some(swagger20::KubernetesAction::DeleteCollection), crate::fixups::special::remove_delete_collection_operations_query_parameters),
+		(Some(swagger20::KubernetesAction::Delete), crate::fixups::special::remove_delete_operations_query_parameters),
+		(Some(swagger20::KubernetesAction::Get), crate::fixups::special::remove_read_operations_query_parameters),
+		(Some(swagger20::KubernetesAction::List), crate::fixups::special::separate_watch_from_list_operations),
+		(Some(swagger20::KubernetesAction::List), crate::fixups::special::list), // Must run after separate_watch_from_list_operations
+		(None, crate::fixups::special::response_types_partial),
+		(None, crate::fixups::special::resource_metadata_not_optional_partial),
+	];
+
+	for &(kubernetes_action, fixup) in special_fixups {
+		if let Some(kubernetes_action) = kubernetes_action {
+			if spec.operations.iter().all(|o| o.kubernetes_action != Some(kubernetes_action)) {
+				continue;
+			}
+		}
+
+		fixup(spec)?;
+	}
+
+	Ok(())
+}
+
+/// Returns the contents of the `Cargo.toml` of the generated crate.
+fn cargo_toml(crate_name: &str) -> Result<String, crate::Error> {
+	// The generated crate depends on the version of k8s-openapi that this code generator belongs to.
+	let k8s_openapi_version =
+		include_str!("../../Cargo.toml").lines()
+		.find_map(|line| line.strip_prefix("version = \"")?.strip_suffix('"'))
+		.ok_or("could not find the version of k8s-openapi")?;
+
+	Ok(format!(r#"[package]
+name = "{crate_name}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+# The crate that uses this crate must enable one of the `v1_*` features of k8s-openapi to select the version of Kubernetes.
+k8s-openapi = {{ version = "{k8s_openapi_version}", default-features = false }}
+
+[features]
+default = ["api"]
+
+api = ["k8s-openapi/api"] # Enables API operation functions and response types. If disabled, only the resource types will be exported.
+
+eq_hash_ord = ["k8s-openapi/eq_hash_ord"] # Derives `Eq`, `Hash`, `Ord` and `PartialOrd` for the types whose fields all implement these traits.
+
+schemars = ["k8s-openapi/schemars"] # Implements `schemars::JsonSchema` for the types.
+"#))
+}
+
+/// Definitions are mapped according to the namespace mappings, with the longest matching prefix taking precedence.
+/// All other definitions under `io.k8s.` are referred to via the `k8s_openapi` crate.
+struct MapNamespace<'a> {
+	namespace_mappings: &'a [NamespaceMapping],
+}
+
+impl MapNamespace<'_> {
+	/// Whether the definition is generated in the crate, as opposed to being referred to via another crate like `k8s_openapi`.
+	fn is_generated(&self, definition_path: &swagger20::DefinitionPath) -> bool {
+		let path_parts: Vec<_> = definition_path.split('.').collect();
+		matches!(k8s_openapi_codegen_common::MapNamespace::map_namespace(self, &path_parts).as_deref(), Some(["crate", ..]))
+	}
+}
+
+impl k8s_openapi_codegen_common::MapNamespace for MapNamespace<'_> {
+	fn map_namespace<'a>(&'a self, path_parts: &[&'a str]) -> Option<Vec<&'a str>> {
+		let namespace_mapping =
+			self.namespace_mappings.iter()
+			.filter(|namespace_mapping|
+				namespace_mapping.prefix.len() <= path_parts.len() &&
+				namespace_mapping.prefix.iter().zip(path_parts).all(|(prefix_part, path_part)| prefix_part == path_part))
+			.max_by_key(|namespace_mapping| namespace_mapping.prefix.len());
+
+		match (namespace_mapping, path_parts) {
+			(Some(namespace_mapping), path_parts) =>
+				Some(namespace_mapping.path.iter().map(String::as_str).chain(path_parts[namespace_mapping.prefix.len()..].iter().copied()).collect()),
+			(None, ["io", "k8s", rest @ ..]) => Some(std::iter::once("k8s_openapi").chain(rest.iter().copied()).collect()),
+			(None, _) => None,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn map_namespace() {
+		let namespace_mappings: Vec<super::NamespaceMapping> = vec![
+			"io.k8s.metrics.pkg.apis=crate".parse().unwrap(),
+			"io.k8s.metrics.pkg.apis.custom_metrics=crate::custom::metrics".parse().unwrap(),
+		];
+		let map_namespace = super::MapNamespace { namespace_mappings: &namespace_mappings };
+
+		for (path, expected) in [
+			("io.k8s.metrics.pkg.apis.metrics.v1beta1.NodeMetrics", Some("crate::metrics::v1beta1::NodeMetrics")),
+			("io.k8s.metrics.pkg.apis.custom_metrics.v1beta2.MetricValue", Some("crate::custom::metrics::v1beta2::MetricValue")),
+			("io.k8s.apimachinery.pkg.apis.meta.v1.ObjectMeta", Some("k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta")),
+			("io.k8s.ListOptional", Some("k8s_openapi::ListOptional")),
+			("io.k8s", Some("k8s_openapi")),
+			("com.example.v1.Widget", None),
+		] {
+			let path_parts: Vec<_> = path.split('.').collect();
+			let actual = k8s_openapi_codegen_common::MapNamespace::map_namespace(&map_namespace, &path_parts).map(|parts| parts.join("::"));
+			assert_eq!(actual.as_deref(), expected, "{path}");
+		}
+
+		assert!("io.k8s.metrics".parse::<super::NamespaceMapping>().is_err());
+		assert!("io.k8s.metrics=crate::".parse::<super::NamespaceMapping>().is_err());
+	}
+
+	#[test]
+	fn write_crate() {
+		let spec: super::swagger20::Spec = serde_json::from_str(r##"{
+			"definitions": {
+				"io.k8s.apimachinery.pkg.api.resource.Quantity": { "type": "string" },
+				"io.k8s.apimachinery.pkg.apis.meta.v1.ListMeta": {
+					"type": "object",
+					"properties": {
+						"continue": { "type": "string" },
+						"resourceVersion": { "type": "string" }
+					}
+				},
+				"io.k8s.apimachinery.pkg.apis.meta.v1.FieldsV1": { "type": "object" },
+				"io.k8s.apimachinery.pkg.apis.meta.v1.ManagedFieldsEntry": {
+					"type": "object",
+					"properties": {
+						"fieldsV1": { "$ref": "#/definitions/io.k8s.apimachinery.pkg.apis.meta.v1.FieldsV1" },
+						"manager": { "type": "string" }
+					}
+				},
+				"io.k8s.apimachinery.pkg.apis.meta.v1.ObjectMeta": {
+					"type": "object",
+					"properties": {
+						"managedFields": { "type": "array", "items": { "$ref": "#/definitions/io.k8s.apimachinery.pkg.apis.meta.v1.ManagedFieldsEntry" } },
+						"name": { "type": "string" },
+						"namespace": { "type": "string" }
+					}
+				},
+				"io.k8s.apimachinery.pkg.apis.meta.v1.Time": { "type": "string", "format": "date-time" },
+				"io.k8s.metrics.pkg.apis.metrics.v1beta1.NodeMetrics": {
+					"description": "NodeMetrics sets resource usage metrics of a node.",
+					"type": "object",
+					"required": ["timestamp", "window", "usage"],
+					"properties": {
+						"apiVersion": { "type": "string" },
+						"kind": { "type": "string" },
+						"metadata": { "$ref": "#/definitions/io.k8s.apimachinery.pkg.apis.meta.v1.ObjectMeta" },
+						"timestamp": { "$ref": "#/definitions/io.k8s.apimachinery.pkg.apis.meta.v1.Time" },
+						"usage": { "type": "object", "additionalProperties": { "$ref": "#/definitions/io.k8s.apimachinery.pkg.api.resource.Quantity" } },
+						"window": { "type": "string" }
+					},
+					"x-kubernetes-group-version-kind": [{ "group": "metrics.k8s.io", "kind": "NodeMetrics", "version": "v1beta1" }]
+				},
+				"io.k8s.metrics.pkg.apis.metrics.v1beta1.NodeMetricsList": {
+					"description": "NodeMetricsList is a list of NodeMetrics.",
+					"type": "object",
+					"required": ["items"],
+					"properties": {
+						"apiVersion": { "type": "string" },
+						"items": { "type": "array", "items": { "$ref": "#/definitions/io.k8s.metrics.pkg.apis.metrics.v1beta1.NodeMetrics" } },
+						"kind": { "type": "string" },
+						"metadata": { "$ref": "#/definitions/io.k8s.apimachinery.pkg.apis.meta.v1.ListMeta" }
+					},
+					"x-kubernetes-group-version-kind": [{ "group": "metrics.k8s.io", "kind": "NodeMetricsList", "version": "v1beta1" }]
+				}
+			},
+			"info": { "title": "metrics-server", "version": "v0.6.0" },
+			"paths": {
+				"/api/": {
+					"get": {
+						"operationId": "getCoreAPIVersions",
+						"responses": { "200": { "description": "OK", "schema": { "type": "string" } } }
+					}
+				},
+				"/apis/metrics.k8s.io/v1beta1/": {
+					"get": {
+						"operationId": "getMetricsV1beta1APIResources",
+						"responses": { "200": { "description": "OK", "schema": { "type": "string" } } }
+					}
+				},
+				"/apis/metrics.k8s.io/v1beta1/nodes": {
+					"get": {
+						"operationId": "listMetricsV1beta1NodeMetrics",
+						"parameters": [
+							{ "in": "query", "name": "continue", "type": "string", "uniqueItems": true },
+							{ "in": "query", "name": "fieldSelector", "type": "string", "uniqueItems": true },
+							{ "in": "query", "name": "labelSelector", "type": "string", "uniqueItems": true },
+							{ "in": "query", "name": "limit", "type": "integer", "uniqueItems": true },
+							{ "in": "query", "name": "pretty", "type": "string", "uniqueItems": true },
+							{ "in": "query", "name": "resourceVersion", "type": "string", "uniqueItems": true },
+							{ "in": "query", "name": "timeoutSeconds", "type": "integer", "uniqueItems": true },
+							{ "in": "query", "name": "watch", "type": "boolean", "uniqueItems": true }
+						],
+						"responses": { "200": { "description": "OK", "schema": { "$ref": "#/definitions/io.k8s.metrics.pkg.apis.metrics.v1beta1.NodeMetricsList" } } },
+						"tags": ["metrics_v1beta1"],
+						"x-kubernetes-action": "list",
+						"x-kubernetes-group-version-kind": { "group": "metrics.k8s.io", "kind": "NodeMetrics", "version": "v1beta1" }
+					}
+				},
+				"/apis/metrics.k8s.io/v1beta1/nodes/{name}": {
+					"get": {
+						"operationId": "readMetricsV1beta1NodeMetrics",
+						"parameters": [
+							{ "in": "path", "name": "name", "required": true, "type": "string", "uniqueItems": true },
+							{ "in": "query", "name": "pretty", "type": "string", "uniqueItems": true }
+						],
+						"responses": { "200": { "description": "OK", "schema": { "$ref": "#/definitions/io.k8s.metrics.pkg.apis.metrics.v1beta1.NodeMetrics" } } },
+						"tags": ["metrics_v1beta1"],
+						"x-kubernetes-action": "get",
+						"x-kubernetes-group-version-kind": { "group": "metrics.k8s.io", "kind": "NodeMetrics", "version": "v1beta1" }
+					}
+				}
+			},
+			"swagger": "2.0"
+		}"##).unwrap();
+
+		let namespace_mappings = vec!["io.k8s.metrics.pkg.apis=crate".parse().unwrap()];
+
+		let out_dir = std::env::temp_dir().join(format!("k8s-openapi-codegen-aggregated-{}", std::process::id()));
+		let _ = std::fs::remove_dir_all(&out_dir);
+
+		super::write_crate(spec, "file:///metrics.json", &namespace_mappings, "metrics-k8s-io", &out_dir, &[]).unwrap();
+
+		let cargo_toml = std::fs::read_to_string(out_dir.join("Cargo.toml")).unwrap();
+		assert!(cargo_toml.contains(r#"name = "metrics-k8s-io""#));
+		let k8s_openapi_dependency = cargo_toml.lines().find(|line| line.starts_with("k8s-openapi = ")).unwrap();
+		assert!(k8s_openapi_dependency.starts_with(r#"k8s-openapi = { version = "0."#), "{k8s_openapi_dependency}");
+		assert!(k8s_openapi_dependency.ends_with(", default-features = false }"), "{k8s_openapi_dependency}");
+
+		let lib_rs = std::fs::read_to_string(out_dir.join("src").join("lib.rs")).unwrap();
+		assert!(lib_rs.starts_with("// Generated from the OpenAPI spec at file:///metrics.json\n"));
+		assert!(lib_rs.contains("pub mod metrics;"));
+		assert!(lib_rs.contains("pub fn get_metrics_v1beta1_api_resources("));
+		assert!(!lib_rs.contains("get_core_api_versions"));
+		assert!(!out_dir.join("src").join("mod.rs").exists());
+		assert!(!out_dir.join("src").join("apimachinery").exists());
+
+		let node_metrics = std::fs::read_to_string(out_dir.join("src").join("metrics").join("v1beta1").join("node_metrics.rs")).unwrap();
+		assert!(node_metrics.contains("pub struct NodeMetrics {"));
+		assert!(node_metrics.contains("pub metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta,"));
+		assert!(node_metrics.contains("pub usage: std::collections::BTreeMap<String, k8s_openapi::apimachinery::pkg::api::resource::Quantity>,"));
+		assert!(node_metrics.contains("pub fn list(\n        optional: k8s_openapi::ListOptional<'_>,"));
+		assert!(node_metrics.contains("pub fn read(\n        name: &str,"));
+		assert!(node_metrics.contains("pub fn watch(\n        optional: k8s_openapi::WatchOptional<'_>,"));
+		assert!(node_metrics.contains("k8s_openapi::ResponseBody<k8s_openapi::ListResponse<Self>>"));
+		assert!(node_metrics.contains("k8s_openapi::ResponseBody<k8s_openapi::WatchResponse<Self>>"));
+		assert!(node_metrics.contains("impl k8s_openapi::ListableResource for NodeMetrics {"));
+
+		// The generated crate uses the public API of k8s_openapi, including its `__serialize` helpers,
+		// so check that it compiles against the k8s-openapi crate in this workspace.
+		//
+		// The workspace's Cargo.lock is copied so that the dependencies resolve to the same versions, which are available offline.
+		let workspace_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+		std::fs::copy(workspace_dir.join("Cargo.lock"), out_dir.join("Cargo.lock")).unwrap();
+		std::fs::write(out_dir.join("Cargo.toml"), format!(
+			"{cargo_toml}\n[patch.crates-io]\nk8s-openapi = {{ path = {:?} }}\n\n[workspace]\n",
+			workspace_dir.display().to_string(),
+		)).unwrap();
+		let output =
+			std::process::Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()))
+			.args(["check", "--offline", "--features", "eq_hash_ord,schemars"])
+			.current_dir(&out_dir)
+			.env("CARGO_TARGET_DIR", out_dir.join("target"))
+			.env("K8S_OPENAPI_ENABLED_VERSION", "1.26")
+			.output()
+			.unwrap();
+		assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+		std::fs::remove_dir_all(&out_dir).unwrap();
+	}
+}
@@ -0,0 +1,275 @@
+//! Generates the `compat` module, which lets code work with the types of a range of versions without conditional compilation.
+//!
+//! For every supported version except the latest, the module contains a `v1_<>` submodule with the types that are present in that version
+//! and all later versions. Each type gets a `<Type>Compat` trait with accessors for the fields that have the same name and type in all those versions,
+//! implemented for the type of the enabled version.
+//!
+//! The trait also has a `with_<field>` builder method for each of these fields. Since a struct literal has to name all the fields of the type
+//! of the enabled version, values of the type can instead be constructed with `Default::default()` followed by these builder methods.
+
+/// The fields of the struct types generated for one version, keyed by the path of the type relative to the version's module root,
+/// such as `api::core::v1::Pod`.
+pub(crate) type Types = std::collections::BTreeMap<String, Vec<(String, String)>>;
+
+/// The types generated for one version.
+pub(crate) struct Version<'a> {
+	pub(crate) name: &'a str,
+	pub(crate) mod_root: &'a str,
+	pub(crate) types: &'a Types,
+}
+
+/// Writes the `compat` module to the given directory, replacing its previous contents.
+///
+/// `versions` must be sorted from the oldest to the latest version.
+pub(crate) fn write(out_dir: &std::path::Path, versions: &[Version<'_>]) -> std::io::Result<()> {
+	use std::io::Write;
+
+	let Some((latest, _)) = versions.split_last() else { return Ok(()); };
+
+	match std::fs::remove_dir_all(out_dir) {
+		Ok(()) => (),
+		Err(err) if err.kind() == std::io::ErrorKind::NotFound => (),
+		Err(err) => return Err(err),
+	}
+	std::fs::create_dir(out_dir)?;
+
+	let mut mod_rs = std::io::BufWriter::new(std::fs::File::create(out_dir.join("mod.rs"))?);
+	writeln!(mod_rs, "// Generated from the types of Kubernetes {} through {}", versions[0].name, latest.name)?;
+
+	for (i, version) in versions.iter().enumerate().take(versions.len() - 1) {
+		let range = &versions[i..];
+		let cfg =
+			range.iter()
+			.map(|version| format!("k8s_openapi_enabled_version={:?}", version.name))
+			.collect::<Vec<_>>()
+			.join(", ");
+
+		writeln!(mod_rs)?;
+		writeln!(mod_rs, "/// The fields of the types that are common to Kubernetes {} through {}.", version.name, latest.name)?;
+		writeln!(mod_rs, "///")?;
+		writeln!(mod_rs, "/// This module is only available if the enabled version is {} or later.", version.name)?;
+		writeln!(mod_rs, "#[cfg(any({cfg}))]")?;
+		writeln!(mod_rs, "pub mod {};", version.mod_root)?;
+
+		let mut file = std::io::BufWriter::new(std::fs::File::create(out_dir.join(version.mod_root).with_extension("rs"))?);
+		write_range(&mut file, range)?;
+		file.flush()?;
+	}
+
+	mod_rs.flush()?;
+
+	Ok(())
+}
+
+/// Writes the traits for the types that are common to all the given versions.
+fn write_range(mut writer: impl std::io::Write, range: &[Version<'_>]) -> std::io::Result<()> {
+	let (first, rest) = range.split_first().expect("range is non-empty");
+	let last = range.last().expect("range is non-empty");
+
+	writeln!(writer, "// Generated from the types of Kubernetes {} through {}", first.name, last.name)?;
+
+	let mut current_mods: Vec<&str> = vec![];
+	let mut first_in_mod = false;
+
+	for (type_path, fields) in first.types {
+		let Some(later_fields) = rest.iter().map(|version| version.types.get(type_path)).collect::<Option<Vec<_>>>() else { continue; };
+
+		let common_fields: Vec<_> =
+			fields.iter()
+			.filter(|field| later_fields.iter().all(|later_fields| later_fields.contains(field)))
+			.collect();
+		if common_fields.is_empty() {
+			continue;
+		}
+
+		let mut parts: Vec<&str> = type_path.split("::").collect();
+		let type_name = parts.pop().expect("split always yields at least one part");
+
+		let num_common_mods = current_mods.iter().zip(&parts).take_while(|(a, b)| a == b).count();
+		while current_mods.len() > num_common_mods {
+			current_mods.pop();
+			writeln!(writer, "{}}}", Indent(current_mods.len()))?;
+			first_in_mod = false;
+		}
+		for &part in &parts[num_common_mods..] {
+			if !first_in_mod {
+				writeln!(writer)?;
+			}
+			writeln!(writer, "{}pub mod {part} {{", Indent(current_mods.len()))?;
+			current_mods.push(part);
+			first_in_mod = true;
+		}
+
+		let indent = Indent(current_mods.len());
+
+		if !first_in_mod {
+			writeln!(writer)?;
+		}
+		first_in_mod = false;
+		writeln!(
+			writer,
+			"{indent}/// The fields of [`crate::{type_path}`] that are common to Kubernetes {} through {}.",
+			first.name, last.name,
+		)?;
+		// Accessors are named after the fields, so a field like `from` gets a `from_mut` accessor that clippy expects to be a constructor.
+		if common_fields.iter().any(|(field_name, _)| field_name.starts_with("from_") || mut_accessor_name(field_name).starts_with("from_")) {
+			writeln!(writer, "{indent}#[allow(clippy::wrong_self_convention)]")?;
+		}
+		writeln!(writer, "{indent}pub trait {type_name}Compat {{")?;
+		for (i, (field_name, field_type_name)) in common_fields.iter().enumerate() {
+			if i > 0 {
+				writeln!(writer)?;
+			}
+			let mut_name = mut_accessor_name(field_name);
+			writeln!(writer, "{indent}    /// Returns a reference to [`crate::{type_path}::{field_name}`].")?;
+			writeln!(writer, "{indent}    fn {field_name}(&self) -> &{field_type_name};")?;
+			writeln!(writer)?;
+			writeln!(writer, "{indent}    /// Returns a mutable reference to [`crate::{type_path}::{field_name}`].")?;
+			writeln!(writer, "{indent}    fn {mut_name}(&mut self) -> &mut {field_type_name};")?;
+			writeln!(writer)?;
+			writeln!(writer, "{indent}    /// Sets [`crate::{type_path}::{field_name}`] and returns `self`.")?;
+			writeln!(writer, "{indent}    #[must_use]")?;
+			writeln!(writer, "{indent}    fn {}(mut self, value: {field_type_name}) -> Self where Self: Sized {{", with_name(field_name))?;
+			writeln!(writer, "{indent}        *self.{mut_name}() = value;")?;
+			writeln!(writer, "{indent}        self")?;
+			writeln!(writer, "{indent}    }}")?;
+		}
+		writeln!(writer, "{indent}}}")?;
+
+		writeln!(writer)?;
+		writeln!(writer, "{indent}impl {type_name}Compat for crate::{type_path} {{")?;
+		for (i, (field_name, field_type_name)) in common_fields.iter().enumerate() {
+			if i > 0 {
+				writeln!(writer)?;
+			}
+			let mut_name = mut_accessor_name(field_name);
+			writeln!(writer, "{indent}    fn {field_name}(&self) -> &{field_type_name} {{")?;
+			writeln!(writer, "{indent}        &self.{field_name}")?;
+			writeln!(writer, "{indent}    }}")?;
+			writeln!(writer)?;
+			writeln!(writer, "{indent}    fn {mut_name}(&mut self) -> &mut {field_type_name} {{")?;
+			writeln!(writer, "{indent}        &mut self.{field_name}")?;
+			writeln!(writer, "{indent}    }}")?;
+		}
+		writeln!(writer, "{indent}}}")?;
+	}
+
+	while !current_mods.is_empty() {
+		current_mods.pop();
+		writeln!(writer, "{}}}", Indent(current_mods.len()))?;
+	}
+
+	Ok(())
+}
+
+/// Field names that are Rust keywords have a trailing `_`, so strip it to avoid accessors like `type__mut`.
+fn mut_accessor_name(field_name: &str) -> String {
+	format!("{}_mut", field_name.strip_suffix('_').unwrap_or(field_name))
+}
+
+/// Like [`mut_accessor_name`], the trailing `_` of field names that are Rust keywords is stripped to avoid builder methods like `with_type_`.
+fn with_name(field_name: &str) -> String {
+	format!("with_{}", field_name.strip_suffix('_').unwrap_or(field_name))
+}
+
+struct Indent(usize);
+
+impl std::fmt::Display for Indent {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		for _ in 0..self.0 {
+			f.write_str("    ")?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn write_range() {
+		let old: super::Types = [
+			("api::core::v1::Pod".to_owned(), vec![
+				("metadata".to_owned(), "crate::ObjectMeta".to_owned()),
+				("spec".to_owned(), "Option<crate::api::core::v1::PodSpec>".to_owned()),
+				("type_".to_owned(), "Option<String>".to_owned()),
+			]),
+			("api::core::v1::Removed".to_owned(), vec![
+				("value".to_owned(), "String".to_owned()),
+			]),
+		].into_iter().collect();
+
+		let new: super::Types = [
+			("api::core::v1::Pod".to_owned(), vec![
+				("metadata".to_owned(), "crate::ObjectMeta".to_owned()),
+				("spec".to_owned(), "crate::api::core::v1::PodSpec".to_owned()),
+				("type_".to_owned(), "Option<String>".to_owned()),
+			]),
+			("api::core::v2::Added".to_owned(), vec![
+				("value".to_owned(), "String".to_owned()),
+			]),
+		].into_iter().collect();
+
+		let mut output = vec![];
+		super::write_range(&mut output, &[
+			super::Version { name: "1.1", mod_root: "v1_1", types: &old },
+			super::Version { name: "1.2", mod_root: "v1_2", types: &new },
+		]).unwrap();
+
+		assert_eq!(String::from_utf8(output).unwrap(), "\
+// Generated from the types of Kubernetes 1.1 through 1.2
+
+pub mod api {
+    pub mod core {
+        pub mod v1 {
+            /// The fields of [`crate::api::core::v1::Pod`] that are common to Kubernetes 1.1 through 1.2.
+            pub trait PodCompat {
+                /// Returns a reference to [`crate::api::core::v1::Pod::metadata`].
+                fn metadata(&self) -> &crate::ObjectMeta;
+
+                /// Returns a mutable reference to [`crate::api::core::v1::Pod::metadata`].
+                fn metadata_mut(&mut self) -> &mut crate::ObjectMeta;
+
+                /// Sets [`crate::api::core::v1::Pod::metadata`] and returns `self`.
+                #[must_use]
+                fn with_metadata(mut self, value: crate::ObjectMeta) -> Self where Self: Sized {
+                    *self.metadata_mut() = value;
+                    self
+                }
+
+                /// Returns a reference to [`crate::api::core::v1::Pod::type_`].
+                fn type_(&self) -> &Option<String>;
+
+                /// Returns a mutable reference to [`crate::api::core::v1::Pod::type_`].
+                fn type_mut(&mut self) -> &mut Option<String>;
+
+                /// Sets [`crate::api::core::v1::Pod::type_`] and returns `self`.
+                #[must_use]
+                fn with_type(mut self, value: Option<String>) -> Self where Self: Sized {
+                    *self.type_mut() = value;
+                    self
+                }
+            }
+
+            impl PodCompat for crate::api::core::v1::Pod {
+                fn metadata(&self) -> &crate::ObjectMeta {
+                    &self.metadata
+                }
+
+                fn metadata_mut(&mut self) -> &mut crate::ObjectMeta {
+                    &mut self.metadata
+                }
+
+                fn type_(&self) -> &Option<String> {
+                    &self.type_
+                }
+
+                fn type_mut(&mut self) -> &mut Option<String> {
+                    &mut self.type_
+                }
+            }
+        }
+    }
+}
+");
+	}
+}
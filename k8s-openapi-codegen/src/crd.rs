@@ -0,0 +1,569 @@
+//! Generates Rust types for the custom resources defined by `CustomResourceDefinition` manifests.
+//!
+//! Each served version of each CRD is converted into a [`swagger20::Spec`] with the same shape as the one that
+//! `#[derive(k8s_openapi_derive::CustomResourceDefinition)]` constructs, and then emitted with [`k8s_openapi_codegen_common::run`].
+
+use k8s_openapi_codegen_common::swagger20;
+
+#[derive(Debug, serde::Deserialize)]
+struct CustomResourceDefinition {
+	kind: String,
+	spec: CustomResourceDefinitionSpec,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct CustomResourceDefinitionSpec {
+	group: String,
+	names: CustomResourceDefinitionNames,
+	scope: String,
+	versions: Vec<CustomResourceDefinitionVersion>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct CustomResourceDefinitionNames {
+	kind: String,
+	plural: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct CustomResourceDefinitionVersion {
+	name: String,
+	served: bool,
+	schema: Option<CustomResourceValidation>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct CustomResourceValidation {
+	#[serde(rename = "openAPIV3Schema")]
+	open_api_v3_schema: serde_json::Value,
+}
+
+pub(crate) fn run(
+	manifests: &[std::path::PathBuf],
+	out_dir: &std::path::Path,
+	generate_schema: bool,
+) -> Result<(), crate::Error> {
+	use std::fmt::Write;
+
+	let mut crds = vec![];
+
+	for manifest in manifests {
+		log::info!("Parsing CRD manifest {} ...", manifest.display());
+
+		let file = std::io::BufReader::new(std::fs::File::open(manifest)?);
+
+		// YAML is a superset of JSON, so this also parses JSON manifests. A YAML file can contain multiple documents.
+		for document in serde_yaml::Deserializer::from_reader(file) {
+			let document: serde_json::Value = serde::Deserialize::deserialize(document)?;
+			if document.is_null() {
+				continue;
+			}
+
+			let crd: CustomResourceDefinition = serde::Deserialize::deserialize(document)?;
+			if crd.kind != "CustomResourceDefinition" {
+				return Err(format!("{} contains a {} instead of a CustomResourceDefinition", manifest.display(), crd.kind).into());
+			}
+
+			crds.push(crd);
+		}
+	}
+
+	log::info!("Creating output directory {} ...", out_dir.display());
+	std::fs::create_dir_all(out_dir)?;
+
+	let mut out_dir_mod_rs = String::new();
+
+	for crd in crds {
+		let kind_mod_name = k8s_openapi_codegen_common::get_rust_ident(&crd.spec.names.kind);
+		let kind_dir = out_dir.join(&*kind_mod_name);
+		std::fs::create_dir_all(&kind_dir)?;
+
+		let mut kind_mod_rs = String::new();
+
+		for version in &crd.spec.versions {
+			if !version.served {
+				log::info!("Skipping {}/{} {} because it is not served", crd.spec.group, version.name, crd.spec.names.kind);
+				continue;
+			}
+
+			log::info!("Generating {}/{} {} ...", crd.spec.group, version.name, crd.spec.names.kind);
+
+			let contents = generate(&crd, version, generate_schema)?;
+
+			let version_mod_name = k8s_openapi_codegen_common::get_rust_ident(&version.name);
+			std::fs::write(kind_dir.join(&*version_mod_name).with_extension("rs"), contents)?;
+			writeln!(kind_mod_rs, "pub mod {version_mod_name};")?;
+		}
+
+		std::fs::write(kind_dir.join("mod.rs"), kind_mod_rs)?;
+		writeln!(out_dir_mod_rs, "pub mod {kind_mod_name};")?;
+	}
+
+	std::fs::write(out_dir.join("mod.rs"), out_dir_mod_rs)?;
+
+	log::info!("OK");
+
+	Ok(())
+}
+
+/// Generates the Rust code for the given version of the CRD.
+fn generate(
+	crd: &CustomResourceDefinition,
+	version: &CustomResourceDefinitionVersion,
+	generate_schema: bool,
+) -> Result<String, crate::Error> {
+	let mut spec = to_spec(crd, version)?;
+
+	let mut run_state = RunState {
+		writer: vec![],
+	};
+
+	for definition_path in spec.definitions.keys() {
+		let _ = k8s_openapi_codegen_common::run(
+			&spec.definitions,
+			&mut spec.operations,
+			definition_path,
+			&MapNamespace,
+			"pub ",
+			if generate_schema { k8s_openapi_codegen_common::GenerateSchema::Yes { feature: None } } else { k8s_openapi_codegen_common::GenerateSchema::No },
+			None,
+			&mut run_state,
+		)?;
+	}
+
+	if let Some(operation) = spec.operations.first() {
+		return Err(format!("operation {} did not get emitted", operation.id).into());
+	}
+
+	let mut result = format!("// Generated from the {}.{} CustomResourceDefinition\n\n", crd.spec.names.plural, crd.spec.group);
+	result.push_str(std::str::from_utf8(&run_state.writer).map_err(|err| err.to_string())?);
+	Ok(result)
+}
+
+/// Converts the given version of the CRD into a spec with the definitions of the custom resource type and the types of its fields,
+/// and the operations of the custom resource type.
+fn to_spec(
+	crd: &CustomResourceDefinition,
+	version: &CustomResourceDefinitionVersion,
+) -> Result<swagger20::Spec, crate::Error> {
+	let CustomResourceDefinitionSpec { group, names: CustomResourceDefinitionNames { kind, plural }, scope, .. } = &crd.spec;
+
+	let namespaced = match &**scope {
+		"Namespaced" => true,
+		"Cluster" => false,
+		scope => return Err(format!("{kind} has unknown scope {scope:?}").into()),
+	};
+
+	let schema =
+		&version.schema.as_ref()
+		.ok_or_else(|| format!("{group}/{} {kind} does not have a schema", version.name))?
+		.open_api_v3_schema;
+
+	let mut definitions = Default::default();
+
+	let required = get_required(schema);
+
+	let mut properties: std::collections::BTreeMap<_, _> = [
+		(swagger20::PropertyName("apiVersion".to_owned()), (swagger20::Schema {
+			description: Some("APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: <https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#resources>".to_owned()),
+			kind: swagger20::SchemaKind::Ty(swagger20::Type::String { format: None }),
+			kubernetes_group_kind_versions: vec![],
+			default: None,
+			nullable: false,
+			list_kind: None,
+			impl_deep_merge: true,
+		}, false)),
+		(swagger20::PropertyName("kind".to_owned()), (swagger20::Schema {
+			description: Some("Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: <https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#types-kinds>".to_owned()),
+			kind: swagger20::SchemaKind::Ty(swagger20::Type::String { format: None }),
+			kubernetes_group_kind_versions: vec![],
+			default: None,
+			nullable: false,
+			list_kind: None,
+			impl_deep_merge: true,
+		}, false)),
+		(swagger20::PropertyName("metadata".to_owned()), (swagger20::Schema {
+			description: Some("Standard object's metadata. More info: <https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#metadata>".to_owned()),
+			kind: swagger20::SchemaKind::Ref(swagger20::RefPath {
+				path: "io.k8s.apimachinery.pkg.apis.meta.v1.ObjectMeta".to_owned(),
+				can_be_default: None,
+			}),
+			kubernetes_group_kind_versions: vec![],
+			default: None,
+			nullable: false,
+			list_kind: None,
+			impl_deep_merge: true,
+		}, true)),
+	].into();
+
+	for (name, property_schema) in get_properties(schema) {
+		if matches!(&**name, "apiVersion" | "kind" | "metadata") {
+			continue;
+		}
+
+		let property_schema = to_schema(property_schema, &format!("{kind}{}", to_pascal_case(name)), &mut definitions)?;
+		properties.insert(swagger20::PropertyName(name.clone()), (property_schema, required.contains(name)));
+	}
+
+	insert_definition(&mut definitions, kind, swagger20::Schema {
+		description: Some(get_description(schema).unwrap_or_else(|| format!("Custom resource `{kind}`"))),
+		kind: swagger20::SchemaKind::Properties(properties),
+		kubernetes_group_kind_versions: vec![
+			swagger20::KubernetesGroupKindVersion {
+				group: group.clone(),
+				kind: kind.clone(),
+				version: version.name.clone(),
+			},
+		],
+		default: None,
+		nullable: false,
+		list_kind: Some(format!("{kind}List")),
+		impl_deep_merge: true,
+	})?;
+
+	Ok(swagger20::Spec {
+		info: swagger20::Info {
+			title: String::new(),
+			version: String::new(),
+		},
+		definitions,
+		operations: swagger20::ResourceOperations {
+			group,
+			version: &version.name,
+			kind,
+			plural,
+			type_name: kind,
+			namespaced,
+		}.operations(),
+	})
+}
+
+/// Converts the given JSON schema to a [`swagger20::Schema`].
+///
+/// Objects with properties are converted to new definitions named `type_name`, since the code generator does not support anonymous types.
+fn to_schema(
+	schema: &serde_json::Value,
+	type_name: &str,
+	definitions: &mut std::collections::BTreeMap<swagger20::DefinitionPath, swagger20::Schema>,
+) -> Result<swagger20::Schema, crate::Error> {
+	let description = get_description(schema);
+
+	let kind =
+		if schema.get("x-kubernetes-int-or-string").and_then(serde_json::Value::as_bool) == Some(true) {
+			int_or_string()
+		}
+		else {
+			match schema.get("type").and_then(serde_json::Value::as_str) {
+				Some("object") | None if schema.get("properties").is_some() => {
+					let required = get_required(schema);
+
+					let mut properties = std::collections::BTreeMap::new();
+					for (name, property_schema) in get_properties(schema) {
+						let property_schema = to_schema(property_schema, &format!("{type_name}{}", to_pascal_case(name)), definitions)?;
+						properties.insert(swagger20::PropertyName(name.clone()), (property_schema, required.contains(name)));
+					}
+
+					insert_definition(definitions, type_name, swagger20::Schema {
+						description: description.clone(),
+						kind: swagger20::SchemaKind::Properties(properties),
+						kubernetes_group_kind_versions: vec![],
+						default: None,
+						nullable: false,
+						list_kind: None,
+						impl_deep_merge: true,
+					})?;
+
+					swagger20::SchemaKind::Ref(swagger20::RefPath {
+						path: type_name.to_owned(),
+						can_be_default: None,
+					})
+				},
+
+				Some("object") => match schema.get("additionalProperties") {
+					Some(additional_properties @ serde_json::Value::Object(_)) => swagger20::SchemaKind::Ty(swagger20::Type::Object {
+						additional_properties: Box::new(to_schema(additional_properties, type_name, definitions)?),
+					}),
+
+					_ => swagger20::SchemaKind::Ty(swagger20::Type::Any),
+				},
+
+				Some("array") => swagger20::SchemaKind::Ty(swagger20::Type::Array {
+					items: Box::new(match schema.get("items") {
+						Some(items) => to_schema(items, type_name, definitions)?,
+						None => to_schema(&serde_json::Value::Object(Default::default()), type_name, definitions)?,
+					}),
+				}),
+
+				Some("boolean") => swagger20::SchemaKind::Ty(swagger20::Type::Boolean),
+
+				Some("integer") => match schema.get("format").and_then(serde_json::Value::as_str) {
+					Some("int32") => swagger20::SchemaKind::Ty(swagger20::Type::Integer { format: swagger20::IntegerFormat::Int32 }),
+					_ => swagger20::SchemaKind::Ty(swagger20::Type::Integer { format: swagger20::IntegerFormat::Int64 }),
+				},
+
+				Some("number") => swagger20::SchemaKind::Ty(swagger20::Type::Number { format: swagger20::NumberFormat::Double }),
+
+				Some("string") => match schema.get("format").and_then(serde_json::Value::as_str) {
+					Some("byte") => swagger20::SchemaKind::Ty(swagger20::Type::String { format: Some(swagger20::StringFormat::Byte) }),
+					Some("date-time") => swagger20::SchemaKind::Ty(swagger20::Type::String { format: Some(swagger20::StringFormat::DateTime) }),
+					Some("int-or-string") => int_or_string(),
+					_ => swagger20::SchemaKind::Ty(swagger20::Type::String { format: None }),
+				},
+
+				// No type, eg a schema with only `x-kubernetes-preserve-unknown-fields: true`, or with only `anyOf` / `oneOf`.
+				None => swagger20::SchemaKind::Ty(swagger20::Type::Any),
+
+				Some(ty) => return Err(format!("{type_name} has unknown type {ty:?}").into()),
+			}
+		};
+
+	Ok(swagger20::Schema {
+		description,
+		kind,
+		kubernetes_group_kind_versions: vec![],
+		default: None,
+		nullable: false,
+		list_kind: None,
+		impl_deep_merge: true,
+	})
+}
+
+fn insert_definition(
+	definitions: &mut std::collections::BTreeMap<swagger20::DefinitionPath, swagger20::Schema>,
+	type_name: &str,
+	schema: swagger20::Schema,
+) -> Result<(), crate::Error> {
+	match definitions.entry(swagger20::DefinitionPath(type_name.to_owned())) {
+		std::collections::btree_map::Entry::Vacant(entry) => {
+			entry.insert(schema);
+			Ok(())
+		},
+
+		std::collections::btree_map::Entry::Occupied(_) => Err(format!("more than one type would be named {type_name}").into()),
+	}
+}
+
+fn int_or_string() -> swagger20::SchemaKind {
+	swagger20::SchemaKind::Ref(swagger20::RefPath {
+		path: "io.k8s.apimachinery.pkg.util.intstr.IntOrString".to_owned(),
+		can_be_default: Some(true),
+	})
+}
+
+fn get_description(schema: &serde_json::Value) -> Option<String> {
+	schema.get("description").and_then(serde_json::Value::as_str).map(ToOwned::to_owned)
+}
+
+fn get_properties(schema: &serde_json::Value) -> impl Iterator<Item = (&String, &serde_json::Value)> {
+	schema.get("properties").and_then(serde_json::Value::as_object).into_iter().flatten()
+}
+
+fn get_required(schema: &serde_json::Value) -> std::collections::BTreeSet<String> {
+	schema.get("required").and_then(serde_json::Value::as_array).into_iter().flatten()
+		.filter_map(serde_json::Value::as_str)
+		.map(ToOwned::to_owned)
+		.collect()
+}
+
+/// Converts a property name like `fooBar` or `foo-bar` to `FooBar`.
+fn to_pascal_case(name: &str) -> String {
+	let mut result = String::with_capacity(name.len());
+	let mut capitalize_next = true;
+	for c in name.chars() {
+		if !c.is_ascii_alphanumeric() {
+			capitalize_next = true;
+		}
+		else if capitalize_next {
+			result.push(c.to_ascii_uppercase());
+			capitalize_next = false;
+		}
+		else {
+			result.push(c);
+		}
+	}
+	result
+}
+
+/// Types from the Kubernetes API are referred to via the `k8s_openapi` crate. All other types are defined in the same module.
+struct MapNamespace;
+
+impl k8s_openapi_codegen_common::MapNamespace for MapNamespace {
+	fn map_namespace<'a>(&self, path_parts: &[&'a str]) -> Option<Vec<&'a str>> {
+		match path_parts {
+			["io", "k8s", rest @ ..] => Some(std::iter::once("k8s_openapi").chain(rest.iter().copied()).collect()),
+			path_parts => Some(path_parts.to_owned()),
+		}
+	}
+}
+
+/// Emits all definitions and operations into a single module.
+struct RunState {
+	writer: Vec<u8>,
+}
+
+impl k8s_openapi_codegen_common::RunState for RunState {
+	type Writer = Vec<u8>;
+
+	fn make_writer(
+		&mut self,
+		_parts: &[&str],
+		_type_feature: Option<&str>,
+	) -> std::io::Result<Self::Writer> {
+		Ok(std::mem::take(&mut self.writer))
+	}
+
+	fn handle_operation_types(
+		&mut self,
+		_operation_optional_parameters_name: Option<&str>,
+		_operation_result_name: Option<&str>,
+	) -> std::io::Result<()> {
+		Ok(())
+	}
+
+	fn finish(&mut self, writer: Self::Writer) {
+		self.writer = writer;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn to_spec() {
+		let crd: super::CustomResourceDefinition = serde_yaml::from_str(r"
+apiVersion: apiextensions.k8s.io/v1
+kind: CustomResourceDefinition
+metadata:
+  name: certificates.cert-manager.io
+spec:
+  group: cert-manager.io
+  names:
+    kind: Certificate
+    plural: certificates
+  scope: Namespaced
+  versions:
+  - name: v1
+    served: true
+    storage: true
+    schema:
+      openAPIV3Schema:
+        description: A Certificate resource
+        type: object
+        required:
+        - spec
+        properties:
+          apiVersion:
+            type: string
+          kind:
+            type: string
+          metadata:
+            type: object
+          spec:
+            type: object
+            required:
+            - secretName
+            properties:
+              secretName:
+                type: string
+              duration:
+                type: string
+              dnsNames:
+                type: array
+                items:
+                  type: string
+              privateKey:
+                type: object
+                properties:
+                  size:
+                    type: integer
+              secretTemplate:
+                type: object
+                properties:
+                  labels:
+                    type: object
+                    additionalProperties:
+                      type: string
+              keystores:
+                type: object
+                x-kubernetes-preserve-unknown-fields: true
+              port:
+                x-kubernetes-int-or-string: true
+          status:
+            type: object
+            properties:
+              conditions:
+                type: array
+                items:
+                  type: object
+                  required:
+                  - status
+                  properties:
+                    lastTransitionTime:
+                      type: string
+                      format: date-time
+                    status:
+                      type: string
+").unwrap();
+
+		let spec = super::to_spec(&crd, &crd.spec.versions[0]).unwrap();
+
+		assert_eq!(
+			spec.definitions.keys().map(|definition_path| &**definition_path).collect::<Vec<_>>(),
+			[
+				"Certificate",
+				"CertificateSpec",
+				"CertificateSpecPrivateKey",
+				"CertificateSpecSecretTemplate",
+				"CertificateStatus",
+				"CertificateStatusConditions",
+			],
+		);
+
+		let certificate = &spec.definitions[&super::swagger20::DefinitionPath("Certificate".to_owned())];
+		assert_eq!(certificate.description.as_deref(), Some("A Certificate resource"));
+		assert_eq!(certificate.list_kind.as_deref(), Some("CertificateList"));
+		let super::swagger20::SchemaKind::Properties(properties) = &certificate.kind else { panic!("{certificate:?}") };
+		assert_eq!(
+			properties.iter().map(|(name, (_, required))| (&**name, *required)).collect::<Vec<_>>(),
+			[("apiVersion", false), ("kind", false), ("metadata", true), ("spec", true), ("status", false)],
+		);
+
+		let certificate_spec = &spec.definitions[&super::swagger20::DefinitionPath("CertificateSpec".to_owned())];
+		let super::swagger20::SchemaKind::Properties(properties) = &certificate_spec.kind else { panic!("{certificate_spec:?}") };
+		assert!(matches!(
+			&properties[&super::swagger20::PropertyName("keystores".to_owned())].0.kind,
+			super::swagger20::SchemaKind::Ty(super::swagger20::Type::Any),
+		));
+		assert!(matches!(
+			&properties[&super::swagger20::PropertyName("port".to_owned())].0.kind,
+			super::swagger20::SchemaKind::Ref(ref_path) if ref_path.path == "io.k8s.apimachinery.pkg.util.intstr.IntOrString",
+		));
+		assert!(properties[&super::swagger20::PropertyName("secretName".to_owned())].1);
+
+		let mut operations: Vec<_> = spec.operations.iter().map(|operation| (&*operation.id, &*operation.path)).collect();
+		operations.sort_unstable();
+		assert_eq!(operations, [
+			("createNamespacedCertificate", "/apis/cert-manager.io/v1/namespaces/{namespace}/certificates"),
+			("deleteCollectionNamespacedCertificate", "/apis/cert-manager.io/v1/namespaces/{namespace}/certificates"),
+			("deleteNamespacedCertificate", "/apis/cert-manager.io/v1/namespaces/{namespace}/certificates/{name}"),
+			("listCertificateForAllNamespaces", "/apis/cert-manager.io/v1/certificates"),
+			("listNamespacedCertificate", "/apis/cert-manager.io/v1/namespaces/{namespace}/certificates"),
+			("patchNamespacedCertificate", "/apis/cert-manager.io/v1/namespaces/{namespace}/certificates/{name}"),
+			("patchNamespacedCertificateStatus", "/apis/cert-manager.io/v1/namespaces/{namespace}/certificates/{name}/status"),
+			("readNamespacedCertificate", "/apis/cert-manager.io/v1/namespaces/{namespace}/certificates/{name}"),
+			("readNamespacedCertificateStatus", "/apis/cert-manager.io/v1/namespaces/{namespace}/certificates/{name}/status"),
+			("replaceNamespacedCertificate", "/apis/cert-manager.io/v1/namespaces/{namespace}/certificates/{name}"),
+			("replaceNamespacedCertificateStatus", "/apis/cert-manager.io/v1/namespaces/{namespace}/certificates/{name}/status"),
+			("watchCertificateForAllNamespaces", "/apis/cert-manager.io/v1/certificates"),
+			("watchNamespacedCertificate", "/apis/cert-manager.io/v1/namespaces/{namespace}/certificates"),
+		]);
+
+		let generated = super::generate(&crd, &crd.spec.versions[0], false).unwrap();
+		assert!(generated.contains("pub struct Certificate {"));
+		assert!(generated.contains("pub spec: CertificateSpec,"));
+		assert!(generated.contains("pub status: Option<CertificateStatus>,"));
+		assert!(generated.contains("pub conditions: Option<Vec<CertificateStatusConditions>>,"));
+		assert!(generated.contains("pub port: Option<k8s_openapi::apimachinery::pkg::util::intstr::IntOrString>,"));
+		assert!(generated.contains("pub fn list_for_all_namespaces("));
+		assert!(generated.contains("impl k8s_openapi::Resource for Certificate {"));
+	}
+}
@@ -0,0 +1,350 @@
+//! Compares the specs of two versions of Kubernetes after their fixups have been applied,
+//! to show which definitions, properties and operations were added, removed or changed between them.
+
+use k8s_openapi_codegen_common::swagger20;
+
+/// The differences between two specs.
+#[derive(Debug, Default, PartialEq, serde::Serialize)]
+pub(crate) struct SpecDiff {
+	pub(crate) added_definitions: Vec<String>,
+	pub(crate) removed_definitions: Vec<String>,
+	pub(crate) changed_definitions: std::collections::BTreeMap<String, DefinitionDiff>,
+	pub(crate) added_operations: Vec<String>,
+	pub(crate) removed_operations: Vec<String>,
+}
+
+/// The differences between two versions of a definition.
+#[derive(Debug, Default, PartialEq, serde::Serialize)]
+pub(crate) struct DefinitionDiff {
+	/// Set if the type of the definition itself changed, such as from a struct to a string.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub(crate) type_change: Option<Change<String>>,
+
+	/// The added properties and their types.
+	#[serde(skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+	pub(crate) added_properties: std::collections::BTreeMap<String, String>,
+
+	/// The removed properties and their types.
+	#[serde(skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+	pub(crate) removed_properties: std::collections::BTreeMap<String, String>,
+
+	#[serde(skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+	pub(crate) changed_properties: std::collections::BTreeMap<String, PropertyDiff>,
+}
+
+/// The differences between two versions of a property of a definition.
+#[derive(Debug, Default, PartialEq, serde::Serialize)]
+pub(crate) struct PropertyDiff {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub(crate) type_change: Option<Change<String>>,
+
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub(crate) required_change: Option<Change<bool>>,
+}
+
+#[derive(Debug, PartialEq, serde::Serialize)]
+pub(crate) struct Change<T> {
+	pub(crate) old: T,
+	pub(crate) new: T,
+}
+
+impl<T> Change<T> where T: PartialEq {
+	fn new(old: T, new: T) -> Option<Self> {
+		(old != new).then_some(Change { old, new })
+	}
+}
+
+pub(crate) fn diff(old: &swagger20::Spec, new: &swagger20::Spec) -> SpecDiff {
+	let mut result = SpecDiff::default();
+
+	for (definition_path, old_schema) in &old.definitions {
+		match new.definitions.get(definition_path) {
+			Some(new_schema) => {
+				let definition_diff = diff_schemas(old_schema, new_schema);
+				if definition_diff != DefinitionDiff::default() {
+					result.changed_definitions.insert(definition_path.to_string(), definition_diff);
+				}
+			},
+
+			None => result.removed_definitions.push(definition_path.to_string()),
+		}
+	}
+
+	for definition_path in new.definitions.keys() {
+		if !old.definitions.contains_key(definition_path) {
+			result.added_definitions.push(definition_path.to_string());
+		}
+	}
+
+	let old_operations: std::collections::BTreeSet<_> = old.operations.iter().map(|operation| &*operation.id).collect();
+	let new_operations: std::collections::BTreeSet<_> = new.operations.iter().map(|operation| &*operation.id).collect();
+	result.added_operations = new_operations.difference(&old_operations).map(|&id| id.to_owned()).collect();
+	result.removed_operations = old_operations.difference(&new_operations).map(|&id| id.to_owned()).collect();
+
+	result
+}
+
+fn diff_schemas(old: &swagger20::Schema, new: &swagger20::Schema) -> DefinitionDiff {
+	let (swagger20::SchemaKind::Properties(old_properties), swagger20::SchemaKind::Properties(new_properties)) = (&old.kind, &new.kind) else {
+		return DefinitionDiff {
+			type_change: Change::new(describe(&old.kind), describe(&new.kind)),
+			..Default::default()
+		};
+	};
+
+	let mut result = DefinitionDiff::default();
+
+	for (name, (old_schema, old_required)) in old_properties {
+		match new_properties.get(name) {
+			Some((new_schema, new_required)) => {
+				let property_diff = PropertyDiff {
+					type_change: Change::new(describe(&old_schema.kind), describe(&new_schema.kind)),
+					required_change: Change::new(*old_required, *new_required),
+				};
+				if property_diff != PropertyDiff::default() {
+					result.changed_properties.insert(name.to_string(), property_diff);
+				}
+			},
+
+			None => { result.removed_properties.insert(name.to_string(), describe(&old_schema.kind)); },
+		}
+	}
+
+	for (name, (new_schema, _)) in new_properties {
+		if !old_properties.contains_key(name) {
+			result.added_properties.insert(name.to_string(), describe(&new_schema.kind));
+		}
+	}
+
+	result
+}
+
+/// A short human-readable description of the type of a schema, like `array of io.k8s.api.core.v1.Container`.
+fn describe(kind: &swagger20::SchemaKind) -> String {
+	match kind {
+		swagger20::SchemaKind::Properties(_) => "object".to_owned(),
+		swagger20::SchemaKind::Ref(ref_path) => ref_path.path.clone(),
+		swagger20::SchemaKind::Ty(ty) => match ty {
+			swagger20::Type::Any => "any".to_owned(),
+			swagger20::Type::Array { items } => format!("array of {}", describe(&items.kind)),
+			swagger20::Type::Boolean => "boolean".to_owned(),
+			swagger20::Type::Integer { format: swagger20::IntegerFormat::Int32 } => "integer (int32)".to_owned(),
+			swagger20::Type::Integer { format: swagger20::IntegerFormat::Int64 } => "integer (int64)".to_owned(),
+			swagger20::Type::Number { format: swagger20::NumberFormat::Double } => "number (double)".to_owned(),
+			swagger20::Type::Object { additional_properties } => format!("map of {}", describe(&additional_properties.kind)),
+			swagger20::Type::String { format: None } => "string".to_owned(),
+			swagger20::Type::String { format: Some(swagger20::StringFormat::Byte) } => "string (byte)".to_owned(),
+			swagger20::Type::String { format: Some(swagger20::StringFormat::DateTime) } => "string (date-time)".to_owned(),
+			swagger20::Type::IntOrString => "int-or-string".to_owned(),
+			swagger20::Type::StringEnum(values) => {
+				let values: Vec<_> = values.iter().map(|(value, _)| &**value).collect();
+				format!("enum of {}", values.join(", "))
+			},
+
+			// Special types created by fixups. Their contents are not interesting to compare.
+			ty => {
+				let ty = format!("{ty:?}");
+				let end = ty.find(['(', ' ']).unwrap_or(ty.len());
+				ty[..end].to_owned()
+			},
+		},
+	}
+}
+
+impl std::fmt::Display for SpecDiff {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		if !self.added_definitions.is_empty() {
+			writeln!(f, "Added definitions:")?;
+			for definition_path in &self.added_definitions {
+				writeln!(f, "    + {definition_path}")?;
+			}
+			writeln!(f)?;
+		}
+
+		if !self.removed_definitions.is_empty() {
+			writeln!(f, "Removed definitions:")?;
+			for definition_path in &self.removed_definitions {
+				writeln!(f, "    - {definition_path}")?;
+			}
+			writeln!(f)?;
+		}
+
+		if !self.changed_definitions.is_empty() {
+			writeln!(f, "Changed definitions:")?;
+			for (definition_path, definition_diff) in &self.changed_definitions {
+				writeln!(f, "    {definition_path}")?;
+
+				if let Some(Change { old, new }) = &definition_diff.type_change {
+					writeln!(f, "        ~ type: {old} -> {new}")?;
+				}
+
+				for (name, ty) in &definition_diff.added_properties {
+					writeln!(f, "        + {name}: {ty}")?;
+				}
+
+				for (name, ty) in &definition_diff.removed_properties {
+					writeln!(f, "        - {name}: {ty}")?;
+				}
+
+				for (name, property_diff) in &definition_diff.changed_properties {
+					if let Some(Change { old, new }) = &property_diff.type_change {
+						writeln!(f, "        ~ {name}: {old} -> {new}")?;
+					}
+
+					if let Some(Change { old, new }) = &property_diff.required_change {
+						writeln!(f, "        ~ {name}: {} -> {}", if *old { "required" } else { "optional" }, if *new { "required" } else { "optional" })?;
+					}
+				}
+			}
+			writeln!(f)?;
+		}
+
+		if !self.added_operations.is_empty() {
+			writeln!(f, "Added operations:")?;
+			for id in &self.added_operations {
+				writeln!(f, "    + {id}")?;
+			}
+			writeln!(f)?;
+		}
+
+		if !self.removed_operations.is_empty() {
+			writeln!(f, "Removed operations:")?;
+			for id in &self.removed_operations {
+				writeln!(f, "    - {id}")?;
+			}
+			writeln!(f)?;
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use k8s_openapi_codegen_common::swagger20;
+
+	fn schema(kind: swagger20::SchemaKind) -> swagger20::Schema {
+		swagger20::Schema {
+			description: None,
+			kind,
+			kubernetes_group_kind_versions: vec![],
+			default: None,
+			nullable: false,
+			list_kind: None,
+			impl_deep_merge: true,
+		}
+	}
+
+	fn string() -> swagger20::Schema {
+		schema(swagger20::SchemaKind::Ty(swagger20::Type::String { format: None }))
+	}
+
+	fn spec(
+		definitions: impl IntoIterator<Item = (&'static str, swagger20::Schema)>,
+		operation_ids: &[&str],
+	) -> swagger20::Spec {
+		swagger20::Spec {
+			info: swagger20::Info { title: String::new(), version: String::new() },
+			definitions: definitions.into_iter().map(|(path, schema)| (swagger20::DefinitionPath(path.to_owned()), schema)).collect(),
+			operations: operation_ids.iter().map(|&id| swagger20::Operation {
+				description: None,
+				id: id.to_owned(),
+				method: swagger20::Method::Get,
+				kubernetes_action: None,
+				kubernetes_group_kind_version: None,
+				parameters: vec![],
+				path: swagger20::Path(String::new()),
+				responses: swagger20::OperationResponses::Map(Default::default()),
+				tag: None,
+			}).collect(),
+		}
+	}
+
+	#[test]
+	fn diff() {
+		let old = spec([
+			("io.k8s.api.core.v1.PodSpec", schema(swagger20::SchemaKind::Properties([
+				(swagger20::PropertyName("hostname".to_owned()), (string(), false)),
+				(swagger20::PropertyName("nodeName".to_owned()), (string(), false)),
+				(swagger20::PropertyName("containers".to_owned()), (schema(swagger20::SchemaKind::Ty(swagger20::Type::Array {
+					items: Box::new(schema(swagger20::SchemaKind::Ref(swagger20::RefPath { path: "io.k8s.api.core.v1.Container".to_owned(), can_be_default: None }))),
+				})), false)),
+			].into_iter().collect()))),
+			("io.k8s.api.core.v1.Removed", string()),
+			("io.k8s.api.core.v1.Unchanged", string()),
+			("io.k8s.apimachinery.pkg.util.intstr.IntOrString", string()),
+		], &["listPod", "removedOperation"]);
+
+		let new = spec([
+			("io.k8s.api.core.v1.Added", string()),
+			("io.k8s.api.core.v1.PodSpec", schema(swagger20::SchemaKind::Properties([
+				(swagger20::PropertyName("hostUsers".to_owned()), (schema(swagger20::SchemaKind::Ty(swagger20::Type::Boolean)), false)),
+				(swagger20::PropertyName("hostname".to_owned()), (schema(swagger20::SchemaKind::Ty(swagger20::Type::Integer { format: swagger20::IntegerFormat::Int32 })), false)),
+				(swagger20::PropertyName("containers".to_owned()), (schema(swagger20::SchemaKind::Ty(swagger20::Type::Array {
+					items: Box::new(schema(swagger20::SchemaKind::Ref(swagger20::RefPath { path: "io.k8s.api.core.v1.Container".to_owned(), can_be_default: None }))),
+				})), true)),
+			].into_iter().collect()))),
+			("io.k8s.api.core.v1.Unchanged", string()),
+			("io.k8s.apimachinery.pkg.util.intstr.IntOrString", schema(swagger20::SchemaKind::Ty(swagger20::Type::IntOrString))),
+		], &["addedOperation", "listPod"]);
+
+		let diff = super::diff(&old, &new);
+
+		assert_eq!(diff, super::SpecDiff {
+			added_definitions: vec!["io.k8s.api.core.v1.Added".to_owned()],
+			removed_definitions: vec!["io.k8s.api.core.v1.Removed".to_owned()],
+			changed_definitions: [
+				("io.k8s.api.core.v1.PodSpec".to_owned(), super::DefinitionDiff {
+					type_change: None,
+					added_properties: [("hostUsers".to_owned(), "boolean".to_owned())].into_iter().collect(),
+					removed_properties: [("nodeName".to_owned(), "string".to_owned())].into_iter().collect(),
+					changed_properties: [
+						("containers".to_owned(), super::PropertyDiff {
+							type_change: None,
+							required_change: Some(super::Change { old: false, new: true }),
+						}),
+						("hostname".to_owned(), super::PropertyDiff {
+							type_change: Some(super::Change { old: "string".to_owned(), new: "integer (int32)".to_owned() }),
+							required_change: None,
+						}),
+					].into_iter().collect(),
+				}),
+				("io.k8s.apimachinery.pkg.util.intstr.IntOrString".to_owned(), super::DefinitionDiff {
+					type_change: Some(super::Change { old: "string".to_owned(), new: "int-or-string".to_owned() }),
+					..Default::default()
+				}),
+			].into_iter().collect(),
+			added_operations: vec!["addedOperation".to_owned()],
+			removed_operations: vec!["removedOperation".to_owned()],
+		});
+
+		assert_eq!(diff.to_string(), "\
+Added definitions:
+    + io.k8s.api.core.v1.Added
+
+Removed definitions:
+    - io.k8s.api.core.v1.Removed
+
+Changed definitions:
+    io.k8s.api.core.v1.PodSpec
+        + hostUsers: boolean
+        - nodeName: string
+        ~ containers: optional -> required
+        ~ hostname: string -> integer (int32)
+    io.k8s.apimachinery.pkg.util.intstr.IntOrString
+        ~ type: string -> int-or-string
+
+Added operations:
+    + addedOperation
+
+Removed operations:
+    - removedOperation
+
+");
+
+		assert_eq!(
+			serde_json::to_value(&diff).unwrap()["changed_definitions"]["io.k8s.api.core.v1.PodSpec"]["changed_properties"]["containers"],
+			serde_json::json!({ "required_change": { "old": false, "new": true } }),
+		);
+	}
+}
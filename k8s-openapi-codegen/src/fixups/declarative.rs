@@ -0,0 +1,394 @@
+//! Fixups that are specified declaratively in a YAML or JSON file instead of as Rust functions,
+//! so that they can also be used by people generating bindings from custom or patched specs.
+//!
+//! The file contains a list of fixups. Each fixup has a `fixup` field with the kind of the fixup, the fields specific to that kind,
+//! and an optional `versions` field with the versions that the fixup applies to, such as `["1.25", "1.26"]`. If `versions` is not specified,
+//! the fixup applies to all versions.
+//!
+//! ```yaml
+//! - fixup: make-property-optional
+//!   versions: ["1.26"]
+//!   definition: io.k8s.api.events.v1.Event
+//!   property: eventTime
+//! ```
+//!
+//! The kinds of fixups are:
+//!
+//! - `make-property-optional`: Makes the `property` of the `definition` optional.
+//!
+//! - `make-property-required`: Makes the `property` of the `definition` required.
+//!
+//! - `override-parameter-type`: Changes the type of the `parameter` of the `operation` from the schema `from` to the schema `to`,
+//!   such as from `{ type: string }` to `{ type: array, items: { type: string } }`. The schemas use the same format as the spec.
+//!
+//! - `replace-operation-gvk`: Changes the group-version-kind of all operations that have the group-version-kind `from` to `to`.
+//!
+//! - `add-definition-gvk`: Adds the group-version-kind `gvk` to the `definition`.
+//!
+//! - `set-definition-gvks`: Replaces the group-version-kinds of the `definition` with `gvks`.
+//!
+//! - `any-of`: Applies all of the `fixups`, which are fixups of the other kinds without a `versions` field.
+//!   Unlike a list of separate fixups, it is only an error if none of them change the spec.
+//!
+//! A fixup that does not change the spec of a version it applies to is an error, so that fixups for upstream bugs that have been fixed are noticed.
+//!
+//! The built-in fixups for upstream bugs are in `upstream_bugs.yaml`.
+
+/// A fixup, and the versions it applies to.
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct Fixup {
+	#[serde(default)]
+	versions: Option<Vec<String>>,
+
+	#[serde(flatten)]
+	kind: FixupKind,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(tag = "fixup", rename_all = "kebab-case")]
+enum FixupKind {
+	MakePropertyOptional {
+		definition: String,
+		property: String,
+	},
+
+	MakePropertyRequired {
+		definition: String,
+		property: String,
+	},
+
+	OverrideParameterType {
+		operation: String,
+		parameter: String,
+		from: crate::swagger20::Schema,
+		to: crate::swagger20::Schema,
+	},
+
+	ReplaceOperationGvk {
+		from: crate::swagger20::KubernetesGroupKindVersion,
+		to: crate::swagger20::KubernetesGroupKindVersion,
+	},
+
+	AddDefinitionGvk {
+		definition: String,
+		gvk: crate::swagger20::KubernetesGroupKindVersion,
+	},
+
+	SetDefinitionGvks {
+		definition: String,
+		gvks: Vec<crate::swagger20::KubernetesGroupKindVersion>,
+	},
+
+	AnyOf {
+		fixups: Vec<FixupKind>,
+	},
+}
+
+/// Returns the built-in fixups for bugs in the upstream specs.
+pub(crate) fn upstream_bugs() -> Result<Vec<Fixup>, crate::Error> {
+	parse(include_str!("upstream_bugs.yaml"), "upstream_bugs.yaml")
+}
+
+/// Reads the fixups in the given YAML or JSON file.
+pub(crate) fn read(path: &std::path::Path) -> Result<Vec<Fixup>, crate::Error> {
+	let contents = std::fs::read_to_string(path).map_err(|err| format!("could not read {}: {err}", path.display()))?;
+	parse(&contents, &path.display().to_string())
+}
+
+fn parse(contents: &str, file_name: &str) -> Result<Vec<Fixup>, crate::Error> {
+	let fixups: Vec<Fixup> = serde_yaml::from_str(contents).map_err(|err| format!("could not parse {file_name}: {err}"))?;
+
+	for fixup in &fixups {
+		for version in fixup.versions.iter().flatten() {
+			if !crate::supported_version::ALL.iter().any(|supported_version| supported_version.name() == version) {
+				return Err(format!("fixup {fixup} in {file_name} applies to unknown version {version:?}").into());
+			}
+		}
+	}
+
+	Ok(fixups)
+}
+
+impl Fixup {
+	/// Whether this fixup applies to the given version.
+	pub(crate) fn applies_to(&self, supported_version: crate::supported_version::SupportedVersion) -> bool {
+		self.versions.as_ref().is_none_or(|versions| versions.iter().any(|version| version == supported_version.name()))
+	}
+
+	/// Whether this fixup applies to all versions, ie it does not have a `versions` field.
+	pub(crate) fn applies_to_all_versions(&self) -> bool {
+		self.versions.is_none()
+	}
+
+	/// Applies this fixup to the given spec.
+	pub(crate) fn apply(&self, spec: &mut crate::swagger20::Spec) -> Result<(), crate::Error> {
+		if self.kind.apply(spec) {
+			Ok(())
+		}
+		else {
+			Err(format!("never applied fixup {self}").into())
+		}
+	}
+}
+
+impl FixupKind {
+	/// Applies this fixup to the given spec. Returns `false` if the fixup did not change the spec.
+	fn apply(&self, spec: &mut crate::swagger20::Spec) -> bool {
+		match self {
+			FixupKind::MakePropertyOptional { definition, property } =>
+				set_property_required(spec, definition, property, false),
+
+			FixupKind::MakePropertyRequired { definition, property } =>
+				set_property_required(spec, definition, property, true),
+
+			FixupKind::OverrideParameterType { operation, parameter, from, to } => {
+				let parameter =
+					spec.operations.iter_mut()
+					.find(|o| o.id == *operation)
+					.and_then(|operation| operation.parameters.iter_mut().find(|p| p.name == *parameter));
+				match parameter {
+					Some(parameter) if parameter.schema.kind == from.kind => {
+						*parameter = std::sync::Arc::new(crate::swagger20::Parameter {
+							location: parameter.location,
+							name: parameter.name.clone(),
+							required: parameter.required,
+							schema: crate::swagger20::Schema {
+								kind: to.kind.clone(),
+								..(parameter.schema.clone())
+							},
+						});
+						true
+					},
+
+					_ => false,
+				}
+			},
+
+			FixupKind::ReplaceOperationGvk { from, to } => {
+				let mut found = false;
+
+				for operation in &mut spec.operations {
+					if let Some(kubernetes_group_kind_version) = &mut operation.kubernetes_group_kind_version {
+						if kubernetes_group_kind_version == from {
+							*kubernetes_group_kind_version = to.clone();
+							found = true;
+						}
+					}
+				}
+
+				found
+			},
+
+			FixupKind::AddDefinitionGvk { definition, gvk } =>
+				match spec.definitions.get_mut(&crate::swagger20::DefinitionPath(definition.clone())) {
+					Some(definition) if !definition.kubernetes_group_kind_versions.contains(gvk) => {
+						definition.kubernetes_group_kind_versions.push(gvk.clone());
+						true
+					},
+
+					_ => false,
+				},
+
+			FixupKind::SetDefinitionGvks { definition, gvks } =>
+				match spec.definitions.get_mut(&crate::swagger20::DefinitionPath(definition.clone())) {
+					Some(definition) if definition.kubernetes_group_kind_versions != *gvks => {
+						definition.kubernetes_group_kind_versions.clone_from(gvks);
+						true
+					},
+
+					_ => false,
+				},
+
+			FixupKind::AnyOf { fixups } => {
+				// Not `.any()`, since all of the fixups should be applied.
+				let mut applied = false;
+
+				for fixup in fixups {
+					applied |= fixup.apply(spec);
+				}
+
+				applied
+			},
+		}
+	}
+}
+
+impl std::fmt::Display for Fixup {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		self.kind.fmt(f)
+	}
+}
+
+impl std::fmt::Display for FixupKind {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			FixupKind::MakePropertyOptional { definition, property } =>
+				write!(f, "make-property-optional {definition}.{property}"),
+
+			FixupKind::MakePropertyRequired { definition, property } =>
+				write!(f, "make-property-required {definition}.{property}"),
+
+			FixupKind::OverrideParameterType { operation, parameter, .. } =>
+				write!(f, "override-parameter-type {operation} {parameter}"),
+
+			FixupKind::ReplaceOperationGvk { from, to } =>
+				write!(f, "replace-operation-gvk {}/{}/{} -> {}/{}/{}", from.group, from.version, from.kind, to.group, to.version, to.kind),
+
+			FixupKind::AddDefinitionGvk { definition, gvk } =>
+				write!(f, "add-definition-gvk {definition} {}/{}/{}", gvk.group, gvk.version, gvk.kind),
+
+			FixupKind::SetDefinitionGvks { definition, .. } =>
+				write!(f, "set-definition-gvks {definition}"),
+
+			FixupKind::AnyOf { fixups } => {
+				f.write_str("any-of [")?;
+				for (i, fixup) in fixups.iter().enumerate() {
+					if i > 0 {
+						f.write_str(", ")?;
+					}
+					write!(f, "{fixup}")?;
+				}
+				f.write_str("]")
+			},
+		}
+	}
+}
+
+/// Sets whether the given property of the given definition is required. Returns `false` if the property doesn't exist or already has that value.
+fn set_property_required(spec: &mut crate::swagger20::Spec, definition: &str, property: &str, required: bool) -> bool {
+	let Some(definition) = spec.definitions.get_mut(&crate::swagger20::DefinitionPath(definition.to_owned())) else { return false; };
+	let crate::swagger20::SchemaKind::Properties(properties) = &mut definition.kind else { return false; };
+	let Some(property) = properties.get_mut(&crate::swagger20::PropertyName(property.to_owned())) else { return false; };
+	if property.1 == required {
+		return false;
+	}
+
+	property.1 = required;
+	true
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn upstream_bugs() {
+		let fixups = super::upstream_bugs().unwrap();
+		assert_eq!(fixups.len(), 8);
+		assert_eq!(
+			fixups.iter().filter(|fixup| fixup.applies_to(crate::supported_version::SupportedVersion::V1_20)).count(),
+			5,
+		);
+		assert_eq!(
+			fixups.iter().filter(|fixup| fixup.applies_to(crate::supported_version::SupportedVersion::V1_26)).count(),
+			6,
+		);
+	}
+
+	#[test]
+	fn apply() {
+		let mut spec: crate::swagger20::Spec = serde_json::from_str(r##"{
+			"definitions": {
+				"io.example.v1.Widget": {
+					"type": "object",
+					"required": ["name"],
+					"properties": {
+						"name": { "type": "string" },
+						"size": { "type": "integer", "format": "int32" }
+					}
+				}
+			},
+			"info": { "title": "Example", "version": "v1.0.0" },
+			"paths": {
+				"/apis/example.io/v1/widgets": {
+					"get": {
+						"operationId": "listExampleV1Widget",
+						"parameters": [
+							{ "in": "query", "name": "names", "type": "string" }
+						],
+						"responses": { "200": { "schema": { "$ref": "#/definitions/io.example.v1.Widget" } } },
+						"x-kubernetes-action": "list",
+						"x-kubernetes-group-version-kind": { "group": "example.io", "version": "v1", "kind": "WidgetOptions" }
+					}
+				}
+			},
+			"swagger": "2.0"
+		}"##).unwrap();
+
+		let fixups = super::parse(r#"
+- fixup: make-property-optional
+  definition: io.example.v1.Widget
+  property: name
+
+- fixup: make-property-required
+  definition: io.example.v1.Widget
+  property: size
+
+- fixup: override-parameter-type
+  operation: listExampleV1Widget
+  parameter: names
+  from: { type: string }
+  to: { type: array, items: { type: string } }
+
+- fixup: replace-operation-gvk
+  from: { group: example.io, version: v1, kind: WidgetOptions }
+  to: { group: example.io, version: v1, kind: Widget }
+
+- fixup: add-definition-gvk
+  definition: io.example.v1.Widget
+  gvk: { group: example.io, version: v1, kind: Widget }
+
+- fixup: make-property-optional
+  versions: ["1.20"]
+  definition: io.example.v1.Widget
+  property: doesNotExist
+"#, "fixups.yaml").unwrap();
+
+		for fixup in &fixups {
+			if fixup.applies_to(crate::supported_version::SupportedVersion::V1_26) {
+				fixup.apply(&mut spec).unwrap();
+			}
+		}
+
+		let widget = &spec.definitions[&crate::swagger20::DefinitionPath("io.example.v1.Widget".to_owned())];
+		let crate::swagger20::SchemaKind::Properties(properties) = &widget.kind else { panic!("{widget:?}") };
+		assert_eq!(
+			properties.iter().map(|(name, (_, required))| (&**name, *required)).collect::<Vec<_>>(),
+			[("name", false), ("size", true)],
+		);
+		assert_eq!(
+			widget.kubernetes_group_kind_versions,
+			[crate::swagger20::KubernetesGroupKindVersion { group: "example.io".to_owned(), kind: "Widget".to_owned(), version: "v1".to_owned() }],
+		);
+
+		let operation = &spec.operations[0];
+		assert_eq!(operation.kubernetes_group_kind_version.as_ref().unwrap().kind, "Widget");
+		assert!(matches!(
+			&operation.parameters[0].schema.kind,
+			crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::Array { items }) if matches!(items.kind, crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::String { format: None })),
+		));
+
+		// Applying a fixup again does not change the spec, which is an error.
+		let err = fixups[0].apply(&mut spec).unwrap_err();
+		assert_eq!(err.to_string().trim_end(), "never applied fixup make-property-optional io.example.v1.Widget.name");
+
+		// An any-of fixup is applied if any of its fixups change the spec.
+		let fixups = super::parse(r"
+- fixup: any-of
+  fixups:
+  - fixup: make-property-optional
+    definition: io.example.v1.Widget
+    property: doesNotExist
+  - fixup: make-property-optional
+    definition: io.example.v1.Widget
+    property: size
+", "fixups.yaml").unwrap();
+		fixups[0].apply(&mut spec).unwrap();
+		let err = fixups[0].apply(&mut spec).unwrap_err();
+		assert_eq!(
+			err.to_string().trim_end(),
+			"never applied fixup any-of [make-property-optional io.example.v1.Widget.doesNotExist, make-property-optional io.example.v1.Widget.size]",
+		);
+
+		let err = super::parse("- fixup: make-property-optional\n  versions: [\"1.0\"]\n  definition: a\n  property: b\n", "fixups.yaml").unwrap_err();
+		assert_eq!(err.to_string().trim_end(), r#"fixup make-property-optional a.b in fixups.yaml applies to unknown version "1.0""#);
+	}
+}
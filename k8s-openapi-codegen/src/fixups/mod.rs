@@ -1,5 +1,5 @@
 #![deny(unused)]
 
-pub(crate) mod special;
+pub(crate) mod declarative;
 
-pub(crate) mod upstream_bugs;
+pub(crate) mod special;
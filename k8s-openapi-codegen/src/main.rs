@@ -6,8 +6,14 @@
 	clippy::too_many_lines,
 )]
 
+mod aggregated;
+mod compat;
+mod crd;
+mod diff;
 mod fixups;
 mod logger;
+mod resource_operations;
+mod spec_dir;
 mod supported_version;
 
 use futures_util::TryStreamExt;
@@ -62,13 +68,14 @@ impl_from_for_error! {
 	log::SetLoggerError,
 	reqwest::Error,
 	serde_json::Error,
+	serde_yaml::Error,
 	tokio::task::JoinError,
 	url::ParseError,
 }
 
 #[tokio::main(flavor = "current_thread")]
 async fn main() -> Result<(), Error> {
-	let Options { versions: requested_versions } = clap::Parser::parse();
+	let Options { versions: requested_versions, spec_dir, fixups: fixup_files, command } = clap::Parser::parse();
 
 	{
 		let logger = logger::Logger;
@@ -81,6 +88,11 @@ async fn main() -> Result<(), Error> {
 		log::set_max_level(logger.filter());
 	}
 
+	if let Some(Command::Crd { manifests, out_dir, generate_schema }) = command {
+		logger::register_thread_local_logger(logger::make_local_logger("crd"));
+		return crd::run(&manifests, &out_dir, generate_schema);
+	}
+
 	let client = std::sync::Arc::new(reqwest::Client::new());
 
 	let out_dir_base: &std::path::Path = env!("CARGO_MANIFEST_DIR").as_ref();
@@ -94,29 +106,75 @@ async fn main() -> Result<(), Error> {
 			requested_versions
 		};
 
+	if let Some(Command::FetchSpecs { pin }) = command {
+		logger::register_thread_local_logger(logger::make_local_logger("fetch-specs"));
+		let spec_dir = spec_dir.ok_or("fetch-specs requires --spec-dir")?;
+		return fetch_specs(requested_versions, &spec_dir, pin, &client).await;
+	}
+
+	let mut fixups = vec![];
+	for fixup_file in &fixup_files {
+		fixups.extend(fixups::declarative::read(fixup_file)?);
+	}
+
+	if let Some(Command::Aggregated { spec_url, namespace_mappings, crate_name, out_dir }) = command {
+		logger::register_thread_local_logger(logger::make_local_logger("aggregated"));
+		return aggregated::run(&spec_url, &namespace_mappings, &crate_name, &out_dir, &fixups, &client).await;
+	}
+
+	if let Some(Command::Diff { old, new, json }) = command {
+		logger::register_thread_local_logger(logger::make_local_logger("diff"));
+		return diff(old, new, json, spec_dir.as_deref(), &fixups, &client).await;
+	}
+
+	let spec_dir = spec_dir.map(std::sync::Arc::new);
+	let fixups = std::sync::Arc::new(fixups);
+
 	let tasks: futures_util::stream::FuturesUnordered<_> =
 		requested_versions.into_iter()
-		.map(|RequestedVersion { version, overriden_spec_url }| {
+		.map(|requested_version| {
 			let out_dir_base = out_dir_base.clone();
 			let client = client.clone();
+			let spec_dir = spec_dir.clone();
+			let fixups = fixups.clone();
 
 			async move {
+				let version = requested_version.version;
 				let task_local_logger = logger::make_local_logger(version.name());
-				let spec_url = overriden_spec_url.as_deref().unwrap_or_else(|| version.spec_url());
+				let spec_source = requested_version.spec_source(spec_dir.as_deref().map(std::path::PathBuf::as_path));
 				logger::TASK_LOCAL_LOGGER.scope(task_local_logger, async {
-					match run(version, spec_url, &out_dir_base, &client).await {
-						Ok(()) => Ok(()),
+					match run(version, spec_source, fixups, &out_dir_base, &client).await {
+						Ok(types) => Ok((version, types)),
 						Err(err) => {
 							log::error!("Error: {err}");
 							Err(err)
 						},
 					}
-				}).await?;
-				Ok::<_, Error>(())
+				}).await
 			}
 		})
 		.collect();
-	tasks.try_for_each(|()| std::future::ready(Ok(()))).await?;
+	let generated_versions: Vec<_> = tasks.try_collect().await?;
+
+	// The compat module is generated from the types of all supported versions, so it can only be regenerated when all of them have been generated.
+	if supported_version::ALL.iter().all(|&version| generated_versions.iter().any(|&(generated_version, _)| generated_version == version)) {
+		logger::register_thread_local_logger(logger::make_local_logger("compat"));
+
+		let versions: Vec<_> =
+			supported_version::ALL.iter()
+			.map(|&version| {
+				let (_, types) = generated_versions.iter().find(|&&(generated_version, _)| generated_version == version).unwrap();
+				compat::Version { name: version.name(), mod_root: version.mod_root(), types }
+			})
+			.collect();
+
+		log::info!("Generating compat module...");
+		compat::write(&out_dir_base.join("compat"), &versions)?;
+		log::info!("OK");
+	}
+	else {
+		log::info!("Not generating compat module because not all supported versions were generated");
+	}
 
 	Ok(())
 }
@@ -134,12 +192,124 @@ struct Options {
 	/// `--generate=1.20:file:///path/to/swagger.json` means "generate binding for v1.20,
 	/// using the OpenAPI spec in the file /path/to/swagger.json".
 	///
+	/// `--generate=1.26:file:///path/to/openapi-spec/v3/` means "generate bindings for v1.26,
+	/// using the v3 documents of the spec in the `.json` files in the directory `/path/to/openapi-spec/v3/`".
+	/// This is the layout of the `api/openapi-spec/v3` directory of the <https://github.com/kubernetes/kubernetes> repository.
+	///
 	/// This parameter can be specified multiple times to generate bindings for multiple versions.
 	///
 	/// If this parameter isn't specified, bindings will be generated for all supported versions,
 	/// using their respective OpenAPI specs from the https://github.com/kubernetes/kubernetes repository.
 	#[clap(long = "generate", value_name = "VERSION")]
 	versions: Vec<RequestedVersion>,
+
+	/// Read the specs of the requested versions from this directory instead of downloading them,
+	/// so that the bindings can be generated without network access.
+	///
+	/// The directory must contain either a `<VERSION>.json` file, such as `1.26.json`, or a `<VERSION>/` directory of v3 documents
+	/// for each requested version. Every spec file must match the SHA-256 hash that is pinned for it in `k8s-openapi-codegen/SHA256SUMS`.
+	/// Use the `fetch-specs` subcommand to populate the directory.
+	///
+	/// This does not apply to versions whose spec URL is specified explicitly with `--generate`, except for `fetch-specs` which writes to this directory.
+	#[clap(long, value_name = "SPEC_DIR")]
+	spec_dir: Option<std::path::PathBuf>,
+
+	/// Apply the fixups in this YAML or JSON file to the specs, in addition to the built-in fixups for upstream bugs.
+	/// This is useful to work around problems in custom or patched specs.
+	///
+	/// See `k8s-openapi-codegen/src/fixups/upstream_bugs.yaml` for an example. Each fixup must change the spec of every version it applies to.
+	///
+	/// This parameter can be specified multiple times to apply the fixups of multiple files.
+	#[clap(long = "fixups", value_name = "FILE")]
+	fixups: Vec<std::path::PathBuf>,
+
+	#[clap(subcommand)]
+	command: Option<Command>,
+}
+
+#[derive(clap::Subcommand)]
+enum Command {
+	/// Generates Rust types and API functions for the custom resources defined in `CustomResourceDefinition` manifests,
+	/// instead of generating the bindings of Kubernetes itself.
+	///
+	/// For every served version of every CRD, a module is written to `<OUT_DIR>/<kind>/<version>.rs`,
+	/// along with `mod.rs` files that declare these modules.
+	/// The generated code refers to the types of the Kubernetes API via the `k8s_openapi` crate.
+	Crd {
+		/// The YAML or JSON files containing the `CustomResourceDefinition`s. A YAML file can contain multiple CRDs as separate documents.
+		#[clap(required = true, value_name = "MANIFEST")]
+		manifests: Vec<std::path::PathBuf>,
+
+		/// The directory to write the generated code to.
+		#[clap(long, value_name = "OUT_DIR")]
+		out_dir: std::path::PathBuf,
+
+		/// Also generate `schemars::JsonSchema` impls for the generated types.
+		#[clap(long)]
+		generate_schema: bool,
+	},
+
+	/// Downloads the specs of the requested versions into the directory specified with `--spec-dir`,
+	/// after verifying them against the SHA-256 hashes pinned in `k8s-openapi-codegen/SHA256SUMS`.
+	///
+	/// A `file://` spec URL specified with `--generate` can also be a directory of v3 documents,
+	/// which are written to a `<VERSION>/` directory in the spec directory.
+	FetchSpecs {
+		/// Record the SHA-256 hashes of the downloaded specs in `k8s-openapi-codegen/SHA256SUMS` instead of verifying them.
+		/// Use this when updating the spec of a version, and review the changes to the manifest.
+		#[clap(long)]
+		pin: bool,
+	},
+
+	/// Prints the definitions, properties and operations that were added, removed or changed between the specs of two versions,
+	/// after their fixups have been applied.
+	///
+	/// The versions are specified like the `--generate` parameter, eg `diff 1.25 1.26` or `diff 1.25 1.26:file:///path/to/swagger.json`.
+	/// The specs are read from `--spec-dir` if it is specified.
+	Diff {
+		/// The older version.
+		#[clap(value_name = "OLD_VERSION")]
+		old: RequestedVersion,
+
+		/// The newer version.
+		#[clap(value_name = "NEW_VERSION")]
+		new: RequestedVersion,
+
+		/// Print the diff as JSON instead of text.
+		#[clap(long)]
+		json: bool,
+	},
+
+	/// Generates a standalone crate with the Rust types and API functions of an aggregated API server, such as the metrics server's `metrics.k8s.io`,
+	/// instead of generating the bindings of Kubernetes itself.
+	///
+	/// The crate is written to `<OUT_DIR>/src/` along with a `<OUT_DIR>/Cargo.toml`. Only the definitions that are mapped into `crate` with `--map` are generated.
+	/// The generated code refers to all other types of the Kubernetes API, like `ObjectMeta`, via the `k8s_openapi` crate.
+	///
+	/// Fixups specified with `--fixups` are applied to the spec, and must not be restricted to specific versions.
+	Aggregated {
+		/// The URL of the spec of the aggregated API server, either `http(s)://` or `file://`.
+		/// Like with `--generate`, a `file://` URL can also be a directory of v3 documents.
+		#[clap(value_name = "SPEC_URL")]
+		spec_url: String,
+
+		/// Maps the definitions whose paths start with PREFIX to the Rust module PATH, such as `--map io.k8s.metrics.pkg.apis=crate`.
+		/// The longest matching prefix takes precedence.
+		///
+		/// Definitions under `io.k8s.` that are not mapped are referred to via the `k8s_openapi` crate.
+		///
+		/// This parameter can be specified multiple times.
+		#[clap(long = "map", required = true, value_name = "PREFIX=PATH")]
+		namespace_mappings: Vec<aggregated::NamespaceMapping>,
+
+		/// The name of the generated crate.
+		#[clap(long, value_name = "CRATE_NAME")]
+		crate_name: String,
+
+		/// The directory to write the generated crate to.
+		#[clap(long, value_name = "OUT_DIR")]
+		out_dir: std::path::PathBuf,
+	},
 }
 
 #[derive(Clone)]
@@ -148,6 +318,17 @@ struct RequestedVersion {
 	overriden_spec_url: Option<String>,
 }
 
+impl RequestedVersion {
+	/// The source of the spec of this version. The spec URL specified explicitly with `--generate` takes precedence over `--spec-dir`.
+	fn spec_source<'a>(&'a self, spec_dir: Option<&'a std::path::Path>) -> SpecSource<'a> {
+		match (self.overriden_spec_url.as_deref(), spec_dir) {
+			(Some(spec_url), _) => SpecSource::Url(spec_url),
+			(None, Some(spec_dir)) => SpecSource::SpecDir(spec_dir),
+			(None, None) => SpecSource::Url(self.version.spec_url()),
+		}
+	}
+}
+
 impl std::str::FromStr for RequestedVersion {
 	type Err = Error;
 
@@ -183,28 +364,128 @@ impl std::str::FromStr for RequestedVersion {
 	}
 }
 
-async fn run(
-	supported_version: supported_version::SupportedVersion,
-	spec_url: &str,
-	out_dir_base: &std::path::Path,
+/// Where to read the spec of a version from.
+#[derive(Clone, Copy)]
+enum SpecSource<'a> {
+	/// A URL, either `http(s)://` or `file://`.
+	Url(&'a str),
+
+	/// A directory of pinned spec files. See the [`spec_dir`] module.
+	SpecDir(&'a std::path::Path),
+}
+
+async fn fetch_specs(
+	requested_versions: Vec<RequestedVersion>,
+	spec_dir: &std::path::Path,
+	pin: bool,
 	client: &reqwest::Client,
 ) -> Result<(), Error> {
-	let mod_root = supported_version.mod_root();
+	let mut manifest = spec_dir::Manifest::pinned()?;
 
-	let out_dir = out_dir_base.join(mod_root);
+	for RequestedVersion { version, overriden_spec_url } in requested_versions {
+		let spec_url = overriden_spec_url.as_deref().unwrap_or_else(|| version.spec_url());
+		log::info!("Fetching spec of {} from {spec_url} ...", version.name());
 
-	let mut num_generated_structs = 0_usize;
-	let mut num_generated_type_aliases = 0_usize;
-	let mut num_generated_apis = 0_usize;
-
-	let mut spec: swagger20::Spec = {
-		log::info!("Parsing spec file at {spec_url} ...");
 		let spec_url: url::Url = spec_url.parse()?;
+		let contents =
+			if spec_url.scheme() == "file" {
+				let spec_path = spec_url.to_file_path().map_err(|()| "not a file path")?;
+				if spec_path.is_dir() {
+					spec_dir::SpecContents::OpenApiV3(read_openapi_v3_documents(&spec_path)?)
+				}
+				else {
+					spec_dir::SpecContents::Swagger20(std::fs::read(spec_path)?)
+				}
+			}
+			else {
+				let response = client.get(spec_url).send().await?;
+				let status = response.status();
+				if status != http::StatusCode::OK {
+					return Err(status.to_string().into());
+				}
+				spec_dir::SpecContents::Swagger20(response.bytes().await?.to_vec())
+			};
+
+		// Ensure that the spec can be parsed before writing it.
+		let _ = parse_spec_contents(&contents)?;
+
+		spec_dir::write(spec_dir, &mut manifest, pin, version, &contents)?;
+		log::info!("OK");
+	}
+
+	Ok(())
+}
+
+async fn diff(
+	old: RequestedVersion,
+	new: RequestedVersion,
+	json: bool,
+	spec_dir: Option<&std::path::Path>,
+	fixups: &[fixups::declarative::Fixup],
+	client: &reqwest::Client,
+) -> Result<(), Error> {
+	let mut specs = vec![];
+
+	for requested_version in [old, new] {
+		let version = requested_version.version;
+		let mut spec = load_spec(version, requested_version.spec_source(spec_dir), client).await?;
+
+		log::info!("Applying fixups to {} ...", version.name());
+		version.fixup(&mut spec, fixups)?;
+
+		specs.push(spec);
+	}
+
+	let spec_diff = diff::diff(&specs[0], &specs[1]);
+
+	if json {
+		println!("{}", serde_json::to_string_pretty(&spec_diff)?);
+	}
+	else {
+		print!("{spec_diff}");
+	}
+
+	Ok(())
+}
+
+/// Loads the spec of the given version from the given source.
+async fn load_spec(
+	supported_version: supported_version::SupportedVersion,
+	spec_source: SpecSource<'_>,
+	client: &reqwest::Client,
+) -> Result<swagger20::Spec, Error> {
+	let spec = match spec_source {
+		SpecSource::SpecDir(spec_dir) => {
+			log::info!("Parsing spec file from spec directory {} ...", spec_dir.display());
+			let manifest = spec_dir::Manifest::pinned()?;
+			let contents = spec_dir::read(spec_dir, &manifest, supported_version)?;
+			parse_spec_contents(&contents)?
+		},
+
+		SpecSource::Url(spec_url) => load_spec_from_url(spec_url, client).await?,
+	};
+
+	Ok(spec)
+}
+
+/// Loads a spec from the given `http(s)://` or `file://` URL.
+///
+/// A `file://` URL can also be a directory of v3 documents, which are combined into a single spec.
+async fn load_spec_from_url(spec_url: &str, client: &reqwest::Client) -> Result<swagger20::Spec, Error> {
+	log::info!("Parsing spec file at {spec_url} ...");
+	let spec_url: url::Url = spec_url.parse()?;
+	let spec =
 		if spec_url.scheme() == "file" {
 			let spec_path = spec_url.to_file_path().map_err(|()| "not a file path")?;
-			let spec_file = std::fs::File::open(spec_path)?;
-			let spec_file = std::io::BufReader::new(spec_file);
-			serde::Deserialize::deserialize(&mut serde_json::Deserializer::from_reader(spec_file))?
+			if spec_path.is_dir() {
+				let documents = read_openapi_v3_documents(&spec_path)?;
+				parse_spec_contents(&spec_dir::SpecContents::OpenApiV3(documents))?
+			}
+			else {
+				let spec_file = std::fs::File::open(spec_path)?;
+				let spec_file = std::io::BufReader::new(spec_file);
+				serde::Deserialize::deserialize(&mut serde_json::Deserializer::from_reader(spec_file))?
+			}
 		}
 		else {
 			let response = client.get(spec_url).send().await?;
@@ -213,23 +494,77 @@ async fn run(
 				return Err(status.to_string().into());
 			}
 			response.json().await?
+		};
+
+	Ok(spec)
+}
+
+/// Reads the v3 documents in the `.json` files of the given directory, in the order of their file names.
+fn read_openapi_v3_documents(dir: &std::path::Path) -> Result<Vec<(String, Vec<u8>)>, Error> {
+	let mut documents = vec![];
+	for entry in std::fs::read_dir(dir)? {
+		let document_path = entry?.path();
+		if document_path.extension() == Some("json".as_ref()) {
+			let file_name = document_path.file_name().and_then(std::ffi::OsStr::to_str).ok_or("spec file name is not valid UTF-8")?.to_owned();
+			let contents = std::fs::read(&document_path).map_err(|err| format!("could not read {}: {err}", document_path.display()))?;
+			documents.push((file_name, contents));
 		}
-	};
+	}
+	documents.sort_by(|(file_name1, _), (file_name2, _)| file_name1.cmp(file_name2));
+	Ok(documents)
+}
+
+/// Parses the given spec contents. The v3 documents of a spec are combined into a single spec.
+fn parse_spec_contents(contents: &spec_dir::SpecContents) -> Result<swagger20::Spec, Error> {
+	match contents {
+		spec_dir::SpecContents::Swagger20(contents) => Ok(serde_json::from_slice(contents)?),
+
+		spec_dir::SpecContents::OpenApiV3(documents) => {
+			let mut result = vec![];
+			for (file_name, contents) in documents {
+				log::trace!("Parsing OpenAPI v3 document {file_name} ...");
+				let document: swagger20::OpenApiV3Document = serde_json::from_slice(contents)?;
+				result.push(document);
+			}
+
+			Ok(swagger20::Spec::from_openapi_v3(result)?)
+		},
+	}
+}
+
+async fn run(
+	supported_version: supported_version::SupportedVersion,
+	spec_source: SpecSource<'_>,
+	fixups: std::sync::Arc<Vec<fixups::declarative::Fixup>>,
+	out_dir_base: &std::path::Path,
+	client: &reqwest::Client,
+) -> Result<compat::Types, Error> {
+	let mod_root = supported_version.mod_root();
 
-	let () = tokio::task::spawn_blocking(move || -> Result<(), Error> {
+	let out_dir = out_dir_base.join(mod_root);
+
+	let mut num_generated_structs = 0_usize;
+	let mut num_generated_type_aliases = 0_usize;
+	let mut num_generated_apis = 0_usize;
+
+	let mut spec = load_spec(supported_version, spec_source, client).await?;
+
+	let types = tokio::task::spawn_blocking(move || -> Result<compat::Types, Error> {
 		{
 			let thread_local_logger = logger::make_local_logger(supported_version.name());
 			logger::register_thread_local_logger(thread_local_logger);
 		}
 
 		log::info!("Applying fixups...");
-		supported_version.fixup(&mut spec)?;
+		supported_version.fixup(&mut spec, &fixups)?;
 
 		let expected_num_generated_types: usize = spec.definitions.len();
 		let expected_num_generated_apis: usize = spec.operations.len();
 
 		log::info!("OK. Spec has {expected_num_generated_types} definitions and {expected_num_generated_apis} operations");
 
+		let resource_operations = resource_operations::collect(&spec.operations)?;
+
 		loop {
 			log::info!("Removing output directory {} ...", out_dir.display());
 			match std::fs::remove_dir_all(&out_dir) {
@@ -252,15 +587,19 @@ async fn run(
 
 		log::info!("Generating types...");
 
+		let mut types = Default::default();
+
 		for definition_path in spec.definitions.keys() {
 			log::trace!("Working on {definition_path} ...");
 
 			let run_state = RunState {
 				out_dir: &out_dir,
 				parent_mod_rs_file_and_mod_name: None,
+				types: &mut types,
+				current_type_path: None,
 			};
 
-			let run_result = k8s_openapi_codegen_common::run(
+			let run_result = k8s_openapi_codegen_common::run_with_options(
 				&spec.definitions,
 				&mut spec.operations,
 				definition_path,
@@ -268,6 +607,10 @@ async fn run(
 				"pub ",
 				k8s_openapi_codegen_common::GenerateSchema::Yes { feature: Some("schemars") },
 				Some("api"),
+				&k8s_openapi_codegen_common::Options {
+					derive_eq_hash_ord: k8s_openapi_codegen_common::DeriveEqHashOrd::Yes { feature: Some("eq_hash_ord") },
+					..Default::default()
+				},
 				run_state,
 			)?;
 
@@ -301,6 +644,27 @@ async fn run(
 			}
 		}
 
+		// Resource operations table
+		{
+			use std::io::Write;
+
+			log::info!("Generating resource operations table...");
+
+			let mut file = std::io::BufWriter::new(std::fs::File::create(out_dir.join("resource_operations.rs"))?);
+			resource_operations::write(&mut file, &resource_operations)?;
+			file.flush()?;
+
+			let mut mod_root_file = std::io::BufWriter::new(std::fs::OpenOptions::new().append(true).open(out_dir.join("mod.rs"))?);
+			writeln!(mod_root_file)?;
+			writeln!(mod_root_file, r#"#[cfg(feature = "api")]"#)?;
+			writeln!(mod_root_file, "mod resource_operations;")?;
+			writeln!(mod_root_file, r#"#[cfg(feature = "api")]"#)?;
+			writeln!(mod_root_file, "pub use self::resource_operations::RESOURCE_OPERATIONS;")?;
+			mod_root_file.flush()?;
+
+			log::info!("OK. Generated {} resource operations", resource_operations.len());
+		}
+
 		log::info!("OK");
 		log::info!("Generated {num_generated_structs} structs");
 		log::info!("Generated {num_generated_type_aliases} type aliases");
@@ -316,10 +680,10 @@ async fn run(
 
 		log::info!("");
 
-		Ok(())
+		Ok(types)
 	}).await??;
 
-	Ok(())
+	Ok(types)
 }
 
 struct MapNamespace;
@@ -336,6 +700,8 @@ impl k8s_openapi_codegen_common::MapNamespace for MapNamespace {
 struct RunState<'a> {
 	out_dir: &'a std::path::Path,
 	parent_mod_rs_file_and_mod_name: Option<(<Self as k8s_openapi_codegen_common::RunState>::Writer, std::borrow::Cow<'static, str>)>,
+	types: &'a mut compat::Types,
+	current_type_path: Option<String>,
 }
 
 impl k8s_openapi_codegen_common::RunState for RunState<'_> {
@@ -349,6 +715,7 @@ impl k8s_openapi_codegen_common::RunState for RunState<'_> {
 		use std::io::Write;
 
 		let mut current = self.out_dir.to_owned();
+		let mut type_path = String::new();
 
 		for part in parts.iter().skip(1).rev().skip(1).rev() {
 			log::trace!("Current directory: {}", current.display());
@@ -356,6 +723,8 @@ impl k8s_openapi_codegen_common::RunState for RunState<'_> {
 			let mod_name = k8s_openapi_codegen_common::get_rust_ident(part);
 
 			current.push(&*mod_name);
+			type_path.push_str(&mod_name);
+			type_path.push_str("::");
 
 			log::trace!("Checking if subdirectory {} exists...", current.display());
 
@@ -400,6 +769,9 @@ impl k8s_openapi_codegen_common::RunState for RunState<'_> {
 
 		self.parent_mod_rs_file_and_mod_name = Some((parent_mod_rs, mod_name));
 
+		type_path.push_str(type_name);
+		self.current_type_path = Some(type_path);
+
 		Ok(file)
 	}
 
@@ -430,5 +802,13 @@ impl k8s_openapi_codegen_common::RunState for RunState<'_> {
 		Ok(())
 	}
 
+	fn handle_struct_fields(&mut self, fields: &[(&str, &str)]) -> std::io::Result<()> {
+		let type_path =
+			self.current_type_path.take()
+			.ok_or_else(|| std::io::Error::other("handle_struct_fields was called without a preceding make_writer"))?;
+		self.types.insert(type_path, fields.iter().map(|&(field_name, field_type_name)| (field_name.to_owned(), field_type_name.to_owned())).collect());
+		Ok(())
+	}
+
 	fn finish(&mut self, _writer: Self::Writer) { }
 }
@@ -0,0 +1,137 @@
+//! Generates the `RESOURCE_OPERATIONS` table of a version, which maps each resource operation to the resource and verb
+//! that the API server lists for it in its discovery information.
+
+/// A resource operation, identified the same way as in an `APIResourceList` discovery response.
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub(crate) struct ResourceOperation {
+	pub(crate) group: String,
+	pub(crate) version: String,
+	pub(crate) resource: String,
+	pub(crate) verb: &'static str,
+	pub(crate) operation_id: String,
+}
+
+/// Collects the resource operations of the given operations, sorted by group, version, resource and verb.
+///
+/// Operations that are not associated with a resource type, like `getAPIVersions`, are skipped.
+pub(crate) fn collect(operations: &[crate::swagger20::Operation]) -> Result<Vec<ResourceOperation>, crate::Error> {
+	let mut result = vec![];
+
+	for operation in operations {
+		if operation.kubernetes_group_kind_version.is_none() {
+			continue;
+		}
+
+		let kubernetes_action = operation.kubernetes_action.ok_or_else(|| format!("operation {} does not have an action", operation.id))?;
+
+		let verb = match kubernetes_action {
+			crate::swagger20::KubernetesAction::Delete => "delete",
+			crate::swagger20::KubernetesAction::DeleteCollection => "deletecollection",
+			crate::swagger20::KubernetesAction::Get => "get",
+			crate::swagger20::KubernetesAction::List => "list",
+			crate::swagger20::KubernetesAction::Patch => "patch",
+			crate::swagger20::KubernetesAction::Post => "create",
+			crate::swagger20::KubernetesAction::Put => "update",
+			crate::swagger20::KubernetesAction::Watch |
+			crate::swagger20::KubernetesAction::WatchList => "watch",
+
+			// The verb of a connect operation is determined by its HTTP method, eg `POST .../pods/{name}/exec` is the `create` verb of `pods/exec`
+			crate::swagger20::KubernetesAction::Connect |
+			crate::swagger20::KubernetesAction::Proxy => match operation.method {
+				crate::swagger20::Method::Delete => "delete",
+				crate::swagger20::Method::Get => "get",
+				crate::swagger20::Method::Patch => "patch",
+				crate::swagger20::Method::Post => "create",
+				crate::swagger20::Method::Put => "update",
+			},
+		};
+
+		let (group, version, resource) =
+			parse_path(&operation.path)
+			.ok_or_else(|| format!("could not determine the resource of operation {} from its path {}", operation.id, &*operation.path))?;
+
+		result.push(ResourceOperation {
+			group: group.to_owned(),
+			version: version.to_owned(),
+			resource,
+			verb,
+			operation_id: operation.id.clone(),
+		});
+	}
+
+	result.sort();
+
+	Ok(result)
+}
+
+/// Parses the group, version and resource from the path of an operation.
+///
+/// The resource of a subresource operation like `/api/v1/namespaces/{namespace}/pods/{name}/log` is `pods/log`.
+fn parse_path(path: &str) -> Option<(&str, &str, String)> {
+	let mut components = path.strip_prefix('/')?.split('/');
+
+	let (group, version) = match components.next()? {
+		"api" => ("", components.next()?),
+		"apis" => (components.next()?, components.next()?),
+		_ => return None,
+	};
+
+	let mut components: Vec<_> = components.collect();
+	if components.first() == Some(&"watch") {
+		components.remove(0);
+	}
+	if let ["namespaces", "{namespace}", _, ..] = &*components {
+		components.drain(..2);
+	}
+
+	match &*components {
+		[resource] | [resource, "{name}"] => Some((group, version, (*resource).to_owned())),
+		[resource, "{name}", subresource] | [resource, "{name}", subresource, "{path}"] => Some((group, version, format!("{resource}/{subresource}"))),
+		_ => None,
+	}
+}
+
+/// Writes the table of resource operations as the `RESOURCE_OPERATIONS` static.
+pub(crate) fn write(mut writer: impl std::io::Write, resource_operations: &[ResourceOperation]) -> std::io::Result<()> {
+	writeln!(writer, "// Generated from the operations of the spec")?;
+	writeln!(writer)?;
+	writeln!(writer, "/// The resource operations of this version, sorted by group, version, resource and verb.")?;
+	writeln!(writer, "///")?;
+	writeln!(writer, "/// Use [`crate::unserved_operations`] to find the operations that an API server does not serve.")?;
+	writeln!(writer, "pub static RESOURCE_OPERATIONS: &[crate::ResourceOperation] = &[")?;
+	for ResourceOperation { group, version, resource, verb, operation_id } in resource_operations {
+		writeln!(
+			writer,
+			"    crate::ResourceOperation {{ group: {group:?}, version: {version:?}, resource: {resource:?}, verb: {verb:?}, operation_id: {operation_id:?} }},",
+		)?;
+	}
+	writeln!(writer, "];")?;
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn parse_path() {
+		for (path, expected) in [
+			("/api/v1/namespaces", Some(("", "v1", "namespaces"))),
+			("/api/v1/namespaces/{name}", Some(("", "v1", "namespaces"))),
+			("/api/v1/namespaces/{name}/finalize", Some(("", "v1", "namespaces/finalize"))),
+			("/api/v1/namespaces/{namespace}/pods", Some(("", "v1", "pods"))),
+			("/api/v1/namespaces/{namespace}/pods/{name}/log", Some(("", "v1", "pods/log"))),
+			("/api/v1/namespaces/{namespace}/pods/{name}/proxy/{path}", Some(("", "v1", "pods/proxy"))),
+			("/api/v1/nodes/{name}/proxy/{path}", Some(("", "v1", "nodes/proxy"))),
+			("/api/v1/pods", Some(("", "v1", "pods"))),
+			("/apis/apps/v1/namespaces/{namespace}/deployments/{name}/scale", Some(("apps", "v1", "deployments/scale"))),
+			("/apis/apps/v1/watch/namespaces/{namespace}/deployments/{name}", Some(("apps", "v1", "deployments"))),
+			("/apis/authentication.k8s.io/v1/tokenreviews", Some(("authentication.k8s.io", "v1", "tokenreviews"))),
+			("/apis/", None),
+			("/logs/{logpath}", None),
+		] {
+			let actual = super::parse_path(path);
+			let actual = actual.as_ref().map(|(group, version, resource)| (*group, *version, &**resource));
+			assert_eq!(actual, expected, "{path}");
+		}
+	}
+}
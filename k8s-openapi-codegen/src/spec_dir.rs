@@ -0,0 +1,296 @@
+//! A directory of pinned spec files, so that the bindings can be regenerated without network access.
+//!
+//! The directory contains the spec of each supported version, either as a single `<version>.json` Swagger 2.0 spec, such as `1.26.json`,
+//! or as a `<version>/` directory of v3 documents, such as `1.27/api__v1_openapi.json`.
+//!
+//! The SHA-256 of every spec file is pinned in the [`Manifest`] that is committed to this repository at `k8s-openapi-codegen/SHA256SUMS`.
+//! A spec file is only used if its SHA-256 matches the one in the manifest, both when it is fetched into the directory with the `fetch-specs` subcommand
+//! and when it is read from the directory with the `--spec-dir` option.
+
+/// The SHA-256 hashes of the pinned spec files, in the format of `sha256sum`.
+///
+/// The file names are relative to the spec directory. Lines starting with `#` are comments.
+#[derive(Debug)]
+pub(crate) struct Manifest {
+	path: std::path::PathBuf,
+	hashes: std::collections::BTreeMap<String, String>,
+}
+
+/// The contents of the spec of a version.
+pub(crate) enum SpecContents {
+	/// A single Swagger 2.0 spec.
+	Swagger20(Vec<u8>),
+
+	/// The v3 documents of the spec, in the order of their file names.
+	OpenApiV3(Vec<(String, Vec<u8>)>),
+}
+
+impl Manifest {
+	/// Reads the manifest that is committed to this repository.
+	pub(crate) fn pinned() -> Result<Self, crate::Error> {
+		let path: &std::path::Path = env!("CARGO_MANIFEST_DIR").as_ref();
+		Self::read(path.join("SHA256SUMS"))
+	}
+
+	fn read(path: std::path::PathBuf) -> Result<Self, crate::Error> {
+		let manifest = std::fs::read_to_string(&path).map_err(|err| format!("could not read {}: {err}", path.display()))?;
+
+		let mut hashes: std::collections::BTreeMap<_, _> = Default::default();
+
+		for line in manifest.lines() {
+			if line.trim().is_empty() || line.starts_with('#') {
+				continue;
+			}
+
+			// `sha256sum` separates the hash and the file name with a space, followed by a space for text mode or `*` for binary mode.
+			let (hash, file_name) =
+				line.split_once(' ')
+				.and_then(|(hash, rest)| Some((hash, rest.strip_prefix(' ').or_else(|| rest.strip_prefix('*'))?)))
+				.filter(|(hash, _)| hash.len() == 64 && hash.bytes().all(|b| b.is_ascii_hexdigit()))
+				.ok_or_else(|| format!("malformed line in {}: {line:?}", path.display()))?;
+
+			hashes.insert(file_name.to_owned(), hash.to_ascii_lowercase());
+		}
+
+		Ok(Manifest { path, hashes })
+	}
+
+	/// Verifies that the given contents of the spec of the given version match the hashes of the manifest.
+	///
+	/// The contents must consist of exactly the files that the manifest has entries for.
+	fn verify(&self, supported_version: crate::supported_version::SupportedVersion, contents: &SpecContents) -> Result<(), crate::Error> {
+		let files = files(supported_version, contents);
+
+		let expected_file_names: Vec<_> = self.file_names(supported_version).collect();
+		if expected_file_names.is_empty() {
+			return Err(format!(
+				"{} does not contain an entry for {}. Use `fetch-specs --pin` to pin its spec.",
+				self.path.display(), supported_version.name(),
+			).into());
+		}
+
+		for expected_file_name in &expected_file_names {
+			if !files.iter().any(|(file_name, _)| file_name == expected_file_name) {
+				return Err(format!("spec of {} does not contain {expected_file_name} that is pinned in {}", supported_version.name(), self.path.display()).into());
+			}
+		}
+
+		for (file_name, contents) in files {
+			let expected_hash =
+				self.hashes.get(&file_name)
+				.ok_or_else(|| format!("{} does not contain an entry for {file_name}", self.path.display()))?;
+
+			let actual_hash = sha256(contents);
+			if actual_hash != *expected_hash {
+				return Err(format!("SHA-256 of {file_name} is {actual_hash} but {} expects {expected_hash}", self.path.display()).into());
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Records the hashes of the given contents of the spec of the given version in the manifest file, replacing its previous entries for that version.
+	fn pin(&mut self, supported_version: crate::supported_version::SupportedVersion, contents: &SpecContents) -> Result<(), crate::Error> {
+		let previous_file_names: Vec<_> = self.file_names(supported_version).map(ToOwned::to_owned).collect();
+		for file_name in previous_file_names {
+			self.hashes.remove(&file_name);
+		}
+
+		for (file_name, contents) in files(supported_version, contents) {
+			self.hashes.insert(file_name, sha256(contents));
+		}
+
+		// Keep the comments at the start of the manifest file.
+		let mut manifest = String::new();
+		for line in std::fs::read_to_string(&self.path)?.lines().take_while(|line| line.starts_with('#')) {
+			manifest.push_str(line);
+			manifest.push('\n');
+		}
+		for (file_name, hash) in &self.hashes {
+			manifest.push_str(hash);
+			manifest.push_str("  ");
+			manifest.push_str(file_name);
+			manifest.push('\n');
+		}
+		std::fs::write(&self.path, manifest)?;
+
+		Ok(())
+	}
+
+	/// The file names of the manifest entries of the given version.
+	fn file_names(&self, supported_version: crate::supported_version::SupportedVersion) -> impl Iterator<Item = &str> {
+		let swagger20_file_name = format!("{}.json", supported_version.name());
+		let openapi_v3_dir_name = format!("{}/", supported_version.name());
+		self.hashes.keys()
+			.filter(move |file_name| **file_name == swagger20_file_name || file_name.starts_with(&openapi_v3_dir_name))
+			.map(String::as_str)
+	}
+}
+
+/// Reads the spec of the given version from the spec directory, after verifying it against the manifest.
+pub(crate) fn read(
+	spec_dir: &std::path::Path,
+	manifest: &Manifest,
+	supported_version: crate::supported_version::SupportedVersion,
+) -> Result<SpecContents, crate::Error> {
+	let swagger20_path = spec_dir.join(format!("{}.json", supported_version.name()));
+	let openapi_v3_dir = spec_dir.join(supported_version.name());
+
+	let contents =
+		if openapi_v3_dir.is_dir() {
+			SpecContents::OpenApiV3(crate::read_openapi_v3_documents(&openapi_v3_dir)?)
+		}
+		else {
+			let contents = std::fs::read(&swagger20_path).map_err(|err| format!("could not read {}: {err}", swagger20_path.display()))?;
+			SpecContents::Swagger20(contents)
+		};
+
+	manifest.verify(supported_version, &contents)?;
+
+	Ok(contents)
+}
+
+/// Writes the spec of the given version to the spec directory.
+///
+/// If `pin` is set, the hashes of the spec files are first recorded in the manifest. Otherwise they are verified against the manifest,
+/// so a spec that does not match the pinned one is not written.
+pub(crate) fn write(
+	spec_dir: &std::path::Path,
+	manifest: &mut Manifest,
+	pin: bool,
+	supported_version: crate::supported_version::SupportedVersion,
+	contents: &SpecContents,
+) -> Result<(), crate::Error> {
+	if pin {
+		manifest.pin(supported_version, contents)?;
+	}
+	else {
+		manifest.verify(supported_version, contents)?;
+	}
+
+	std::fs::create_dir_all(spec_dir)?;
+
+	// Remove the spec of the version in the other layout, if any, so that `read` does not pick the wrong one.
+	let swagger20_path = spec_dir.join(format!("{}.json", supported_version.name()));
+	let openapi_v3_dir = spec_dir.join(supported_version.name());
+	match std::fs::remove_file(&swagger20_path) {
+		Ok(()) => (),
+		Err(err) if err.kind() == std::io::ErrorKind::NotFound => (),
+		Err(err) => return Err(err.into()),
+	}
+	match std::fs::remove_dir_all(&openapi_v3_dir) {
+		Ok(()) => (),
+		Err(err) if err.kind() == std::io::ErrorKind::NotFound => (),
+		Err(err) => return Err(err.into()),
+	}
+
+	match contents {
+		SpecContents::Swagger20(contents) => std::fs::write(swagger20_path, contents)?,
+
+		SpecContents::OpenApiV3(documents) => {
+			std::fs::create_dir(&openapi_v3_dir)?;
+			for (file_name, contents) in documents {
+				std::fs::write(openapi_v3_dir.join(file_name), contents)?;
+			}
+		},
+	}
+
+	Ok(())
+}
+
+/// The spec files of the given contents, with their file names relative to the spec directory.
+fn files(supported_version: crate::supported_version::SupportedVersion, contents: &SpecContents) -> Vec<(String, &[u8])> {
+	match contents {
+		SpecContents::Swagger20(contents) => vec![(format!("{}.json", supported_version.name()), contents)],
+
+		SpecContents::OpenApiV3(documents) =>
+			documents.iter()
+			.map(|(file_name, contents)| (format!("{}/{file_name}", supported_version.name()), &**contents))
+			.collect(),
+	}
+}
+
+fn sha256(contents: &[u8]) -> String {
+	use std::fmt::Write;
+
+	let hash = <sha2::Sha256 as sha2::Digest>::digest(contents);
+
+	let mut result = String::with_capacity(hash.len() * 2);
+	for b in hash {
+		write!(result, "{b:02x}").unwrap();
+	}
+	result
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn sha256() {
+		assert_eq!(super::sha256(b""), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+		assert_eq!(super::sha256(b"abc"), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+	}
+
+	#[test]
+	fn pinned_manifest() {
+		let _ = super::Manifest::pinned().unwrap();
+	}
+
+	#[test]
+	fn write_and_read() {
+		let dir = std::env::temp_dir().join(format!("k8s-openapi-codegen-spec-dir-{}", std::process::id()));
+		let _ = std::fs::remove_dir_all(&dir);
+		let spec_dir = dir.join("specs");
+		std::fs::create_dir_all(&dir).unwrap();
+
+		let manifest_path = dir.join("SHA256SUMS");
+		std::fs::write(&manifest_path, "# Pinned specs\n").unwrap();
+		let mut manifest = super::Manifest::read(manifest_path.clone()).unwrap();
+
+		let swagger20 = super::SpecContents::Swagger20(b"{}".to_vec());
+		let openapi_v3 = super::SpecContents::OpenApiV3(vec![("api__v1_openapi.json".to_owned(), b"[]".to_vec())]);
+
+		// Not pinned
+		assert!(super::write(&spec_dir, &mut manifest, false, crate::supported_version::SupportedVersion::V1_26, &swagger20).is_err());
+		assert!(!spec_dir.exists());
+
+		super::write(&spec_dir, &mut manifest, true, crate::supported_version::SupportedVersion::V1_26, &swagger20).unwrap();
+		super::write(&spec_dir, &mut manifest, true, crate::supported_version::SupportedVersion::V1_25, &openapi_v3).unwrap();
+		assert_eq!(
+			std::fs::read_to_string(&manifest_path).unwrap(),
+			"# Pinned specs\n\
+			4f53cda18c2baa0c0354bb5f9a3ecbe5ed12ab4d8e11ba873c2f11161202b945  1.25/api__v1_openapi.json\n\
+			44136fa355b3678a1146ad16f7e8649e94fb4fc21fe77e8310c060f61caaff8a  1.26.json\n",
+		);
+
+		// Pinned in a fresh read of the manifest
+		let mut manifest = super::Manifest::read(manifest_path).unwrap();
+		super::write(&spec_dir, &mut manifest, false, crate::supported_version::SupportedVersion::V1_26, &swagger20).unwrap();
+		assert!(matches!(
+			super::read(&spec_dir, &manifest, crate::supported_version::SupportedVersion::V1_26).unwrap(),
+			super::SpecContents::Swagger20(contents) if contents == b"{}",
+		));
+		assert!(matches!(
+			super::read(&spec_dir, &manifest, crate::supported_version::SupportedVersion::V1_25).unwrap(),
+			super::SpecContents::OpenApiV3(documents) if documents == [("api__v1_openapi.json".to_owned(), b"[]".to_vec())],
+		));
+
+		// Fetched spec that differs from the pinned one
+		assert!(super::write(&spec_dir, &mut manifest, false, crate::supported_version::SupportedVersion::V1_26, &super::SpecContents::Swagger20(b"{ }".to_vec())).is_err());
+
+		// Modified spec file
+		std::fs::write(spec_dir.join("1.26.json"), b"{ }").unwrap();
+		assert!(super::read(&spec_dir, &manifest, crate::supported_version::SupportedVersion::V1_26).is_err());
+
+		// Missing and additional v3 documents
+		std::fs::write(spec_dir.join("1.25").join("apis__apps__v1_openapi.json"), b"[]").unwrap();
+		assert!(super::read(&spec_dir, &manifest, crate::supported_version::SupportedVersion::V1_25).is_err());
+		std::fs::remove_file(spec_dir.join("1.25").join("api__v1_openapi.json")).unwrap();
+		assert!(super::read(&spec_dir, &manifest, crate::supported_version::SupportedVersion::V1_25).is_err());
+
+		// Spec file without a manifest entry
+		std::fs::write(spec_dir.join("1.24.json"), b"{}").unwrap();
+		assert!(super::read(&spec_dir, &manifest, crate::supported_version::SupportedVersion::V1_24).is_err());
+
+		std::fs::remove_dir_all(&dir).unwrap();
+	}
+}
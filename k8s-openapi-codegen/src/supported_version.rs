@@ -8,7 +8,7 @@ pub(crate) const ALL: &[SupportedVersion] = &[
 	SupportedVersion::V1_26,
 ];
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub(crate) enum SupportedVersion {
 	V1_20,
 	V1_21,
@@ -56,61 +56,18 @@ impl SupportedVersion {
 		}
 	}
 
-	pub(crate) fn fixup(self, spec: &mut crate::swagger20::Spec) -> Result<(), crate::Error> {
-		#[allow(clippy::match_same_arms, clippy::type_complexity)]
-		let upstream_bugs_fixups: &[fn(&mut crate::swagger20::Spec) -> Result<(), crate::Error>] = match self {
-			SupportedVersion::V1_20 => &[
-				crate::fixups::upstream_bugs::connect_options_gvk,
-				crate::fixups::upstream_bugs::optional_properties::containerimage,
-				crate::fixups::upstream_bugs::optional_properties::eventsv1beta1_event,
-				crate::fixups::upstream_bugs::optional_properties::eventsv1_event,
-				crate::fixups::upstream_bugs::pod_exec_command_parameter_type,
-			],
+	/// Applies the built-in fixups for upstream bugs, then the given additional fixups, then the special fixups to the spec of this version.
+	///
+	/// The `string_enums` special fixup is applied before all of them.
+	pub(crate) fn fixup(self, spec: &mut crate::swagger20::Spec, additional_fixups: &[crate::fixups::declarative::Fixup]) -> Result<(), crate::Error> {
+		crate::fixups::special::string_enums(spec);
 
-			SupportedVersion::V1_21 => &[
-				crate::fixups::upstream_bugs::connect_options_gvk,
-				crate::fixups::upstream_bugs::optional_properties::containerimage,
-				crate::fixups::upstream_bugs::optional_properties::eventsv1beta1_event,
-				crate::fixups::upstream_bugs::optional_properties::eventsv1_event,
-				crate::fixups::upstream_bugs::pod_exec_command_parameter_type,
-			],
-
-			SupportedVersion::V1_22 => &[
-				crate::fixups::upstream_bugs::connect_options_gvk,
-				crate::fixups::upstream_bugs::optional_properties::eventsv1beta1_event,
-				crate::fixups::upstream_bugs::optional_properties::eventsv1_event,
-				crate::fixups::upstream_bugs::pod_exec_command_parameter_type,
-			],
-
-			SupportedVersion::V1_23 => &[
-				crate::fixups::upstream_bugs::connect_options_gvk,
-				crate::fixups::upstream_bugs::optional_properties::eventsv1beta1_event,
-				crate::fixups::upstream_bugs::optional_properties::eventsv1_event,
-				crate::fixups::upstream_bugs::pod_exec_command_parameter_type,
-			],
-
-			SupportedVersion::V1_24 => &[
-				crate::fixups::upstream_bugs::connect_options_gvk,
-				crate::fixups::upstream_bugs::optional_properties::eventsv1beta1_event,
-				crate::fixups::upstream_bugs::optional_properties::eventsv1_event,
-				crate::fixups::upstream_bugs::pod_exec_command_parameter_type,
-			],
-
-			SupportedVersion::V1_25 => &[
-				crate::fixups::upstream_bugs::connect_options_gvk,
-				crate::fixups::upstream_bugs::optional_properties::eventsv1_event,
-				crate::fixups::upstream_bugs::pod_exec_command_parameter_type,
-			],
-
-			SupportedVersion::V1_26 => &[
-				crate::fixups::upstream_bugs::connect_options_gvk,
-				crate::fixups::upstream_bugs::optional_properties::eventsv1_event,
-				crate::fixups::upstream_bugs::pod_exec_command_parameter_type,
-				crate::fixups::upstream_bugs::required_properties::validating_admission_policy_binding_list,
-				crate::fixups::upstream_bugs::required_properties::validating_admission_policy_list,
-				crate::fixups::upstream_bugs::status_extra_gvk,
-			],
-		};
+		let upstream_bugs_fixups = crate::fixups::declarative::upstream_bugs()?;
+		for fixup in upstream_bugs_fixups.iter().chain(additional_fixups) {
+			if fixup.applies_to(self) {
+				fixup.apply(spec)?;
+			}
+		}
 
 		let special_fixups = &[
 			crate::fixups::special::json_ty::json_schema_props_or_array,
@@ -123,13 +80,16 @@ impl SupportedVersion {
 			crate::fixups::special::remove_delete_operations_query_parameters,
 			crate::fixups::special::remove_read_operations_query_parameters,
 			crate::fixups::special::separate_watch_from_list_operations,
+			crate::fixups::special::optional_enums, // Must run after create_delete_optional, create_optionals and separate_watch_from_list_operations
 			crate::fixups::special::watch_event,
-			crate::fixups::special::list, // Must run after separate_watch_from_list_operations
+			crate::fixups::special::table,
+			crate::fixups::special::partial_object_metadata,
+			crate::fixups::special::list, // Must run after separate_watch_from_list_operations and partial_object_metadata
 			crate::fixups::special::response_types,
 			crate::fixups::special::resource_metadata_not_optional,
 		];
 
-		for fixup in upstream_bugs_fixups.iter().chain(special_fixups) {
+		for fixup in special_fixups {
 			fixup(spec)?;
 		}
 
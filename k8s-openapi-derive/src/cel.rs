@@ -0,0 +1,396 @@
+//! A syntax checker for CEL expressions used in `x-kubernetes-validations` rules.
+//!
+//! This only verifies that the expression parses according to the CEL grammar. It does not type-check the expression
+//! against the schema, so the API server may still reject a rule that refers to fields that don't exist.
+//!
+//! Ref: <https://github.com/google/cel-spec/blob/master/doc/langdef.md#syntax>
+
+pub(super) fn check_syntax(expr: &str) -> Result<(), String> {
+	let tokens = tokenize(expr)?;
+	if tokens.is_empty() {
+		return Err("rule is empty".to_owned());
+	}
+
+	let mut parser = Parser { tokens: &tokens, pos: 0 };
+	parser.expr()?;
+	match parser.tokens.get(parser.pos) {
+		None => Ok(()),
+		Some(token) => Err(format!("unexpected {} at offset {}", token.kind, token.offset)),
+	}
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum TokenKind {
+	Ident(String),
+	Literal,
+	Punct(&'static str),
+}
+
+impl std::fmt::Display for TokenKind {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			TokenKind::Ident(ident) => write!(f, "identifier `{ident}`"),
+			TokenKind::Literal => f.write_str("literal"),
+			TokenKind::Punct(punct) => write!(f, "`{punct}`"),
+		}
+	}
+}
+
+struct Token {
+	kind: TokenKind,
+	offset: usize,
+}
+
+const PUNCTS: &[&str] = &[
+	"==", "!=", "<=", ">=", "&&", "||",
+	"<", ">", "!", "+", "-", "*", "/", "%", "?", ":", ".", ",", "(", ")", "[", "]", "{", "}",
+];
+
+const RESERVED: &[&str] = &[
+	"as", "break", "const", "continue", "else", "for", "function", "if", "import", "let", "loop", "package", "namespace", "return", "var", "void", "while",
+];
+
+fn tokenize(expr: &str) -> Result<Vec<Token>, String> {
+	let mut result = vec![];
+	let bytes = expr.as_bytes();
+	let mut pos = 0;
+
+	while pos < bytes.len() {
+		let offset = pos;
+		let rest = &expr[pos..];
+		let c = bytes[pos];
+
+		if c.is_ascii_whitespace() {
+			pos += 1;
+		}
+		else if rest.starts_with("//") {
+			pos += rest.find('\n').unwrap_or(rest.len());
+		}
+		else if c.is_ascii_digit() || (c == b'.' && bytes.get(pos + 1).is_some_and(u8::is_ascii_digit)) {
+			pos += number_len(rest);
+			result.push(Token { kind: TokenKind::Literal, offset });
+		}
+		else if c.is_ascii_alphabetic() || c == b'_' {
+			let len = rest.find(|c: char| !c.is_ascii_alphanumeric() && c != '_').unwrap_or(rest.len());
+			let ident = &rest[..len];
+
+			// String and bytes literals with prefixes, like r"...", b'...', br"..."
+			let prefix_len = if ident.len() <= 2 && ident.chars().all(|c| matches!(c, 'r' | 'R' | 'b' | 'B')) { len } else { 0 };
+			if prefix_len > 0 && matches!(bytes.get(pos + prefix_len), Some(b'"' | b'\'')) {
+				let raw = ident.contains(['r', 'R']);
+				pos += prefix_len;
+				pos += string_len(&expr[pos..], raw).map_err(|err| format!("{err} at offset {offset}"))?;
+				result.push(Token { kind: TokenKind::Literal, offset });
+				continue;
+			}
+
+			if RESERVED.contains(&ident) {
+				return Err(format!("reserved word `{ident}` at offset {offset}"));
+			}
+
+			pos += len;
+			if matches!(ident, "true" | "false" | "null") {
+				result.push(Token { kind: TokenKind::Literal, offset });
+			}
+			else {
+				result.push(Token { kind: TokenKind::Ident(ident.to_owned()), offset });
+			}
+		}
+		else if c == b'"' || c == b'\'' {
+			pos += string_len(rest, false).map_err(|err| format!("{err} at offset {offset}"))?;
+			result.push(Token { kind: TokenKind::Literal, offset });
+		}
+		else if let Some(punct) = PUNCTS.iter().find(|punct| rest.starts_with(**punct)) {
+			pos += punct.len();
+			result.push(Token { kind: TokenKind::Punct(punct), offset });
+		}
+		else {
+			let c = rest.chars().next().unwrap_or_default();
+			return Err(format!("unexpected character {c:?} at offset {offset}"));
+		}
+	}
+
+	Ok(result)
+}
+
+fn number_len(s: &str) -> usize {
+	let bytes = s.as_bytes();
+
+	if s.starts_with("0x") || s.starts_with("0X") {
+		let len = 2 + bytes[2..].iter().take_while(|b| b.is_ascii_hexdigit()).count();
+		return if matches!(bytes.get(len), Some(b'u' | b'U')) { len + 1 } else { len };
+	}
+
+	let mut len = bytes.iter().take_while(|b| b.is_ascii_digit()).count();
+	let mut is_float = false;
+
+	if bytes.get(len) == Some(&b'.') && bytes.get(len + 1).is_some_and(u8::is_ascii_digit) {
+		is_float = true;
+		len += 1;
+		len += bytes[len..].iter().take_while(|b| b.is_ascii_digit()).count();
+	}
+
+	if matches!(bytes.get(len), Some(b'e' | b'E')) {
+		let mut exponent_len = 1;
+		if matches!(bytes.get(len + exponent_len), Some(b'+' | b'-')) {
+			exponent_len += 1;
+		}
+		let digits = bytes[(len + exponent_len).min(bytes.len())..].iter().take_while(|b| b.is_ascii_digit()).count();
+		if digits > 0 {
+			is_float = true;
+			len += exponent_len + digits;
+		}
+	}
+
+	if !is_float && matches!(bytes.get(len), Some(b'u' | b'U')) {
+		len += 1;
+	}
+
+	len
+}
+
+/// Returns the length of the string literal at the start of `s`, including the quotes.
+fn string_len(s: &str, raw: bool) -> Result<usize, &'static str> {
+	let quote = if s.starts_with("\"\"\"") { "\"\"\"" } else if s.starts_with("'''") { "'''" } else { &s[..1] };
+	let multi_line = quote.len() == 3;
+
+	let mut chars = s[quote.len()..].char_indices();
+	while let Some((i, c)) = chars.next() {
+		if s[quote.len() + i..].starts_with(quote) {
+			return Ok(quote.len() + i + quote.len());
+		}
+
+		match c {
+			'\\' if !raw => { let _ = chars.next(); },
+			'\n' | '\r' if !multi_line => return Err("newline in string literal"),
+			_ => (),
+		}
+	}
+
+	Err("unterminated string literal")
+}
+
+struct Parser<'a> {
+	tokens: &'a [Token],
+	pos: usize,
+}
+
+impl Parser<'_> {
+	fn peek(&self) -> Option<&TokenKind> {
+		self.tokens.get(self.pos).map(|token| &token.kind)
+	}
+
+	fn eat(&mut self, punct: &str) -> bool {
+		if matches!(self.peek(), Some(TokenKind::Punct(p)) if *p == punct) {
+			self.pos += 1;
+			true
+		}
+		else {
+			false
+		}
+	}
+
+	fn expect(&mut self, punct: &str) -> Result<(), String> {
+		if self.eat(punct) {
+			Ok(())
+		}
+		else {
+			Err(self.unexpected(&format!("`{punct}`")))
+		}
+	}
+
+	fn unexpected(&self, expected: &str) -> String {
+		match self.tokens.get(self.pos) {
+			Some(token) => format!("expected {expected} but found {} at offset {}", token.kind, token.offset),
+			None => format!("expected {expected} but the rule ended"),
+		}
+	}
+
+	// Expr = ConditionalOr ["?" ConditionalOr ":" Expr]
+	fn expr(&mut self) -> Result<(), String> {
+		self.binary(0)?;
+		if self.eat("?") {
+			self.binary(0)?;
+			self.expect(":")?;
+			self.expr()?;
+		}
+		Ok(())
+	}
+
+	// ConditionalOr, ConditionalAnd, Relation, Addition and Multiplication, in increasing order of precedence.
+	fn binary(&mut self, level: usize) -> Result<(), String> {
+		const LEVELS: &[&[&str]] = &[
+			&["||"],
+			&["&&"],
+			&["==", "!=", "<=", ">=", "<", ">", "in"],
+			&["+", "-"],
+			&["*", "/", "%"],
+		];
+
+		let Some(operators) = LEVELS.get(level) else {
+			return self.unary();
+		};
+
+		self.binary(level + 1)?;
+		loop {
+			let is_operator = match self.peek() {
+				Some(TokenKind::Punct(punct)) => operators.contains(punct),
+				Some(TokenKind::Ident(ident)) => ident == "in" && operators.contains(&"in"),
+				_ => false,
+			};
+			if !is_operator {
+				return Ok(());
+			}
+			self.pos += 1;
+			self.binary(level + 1)?;
+		}
+	}
+
+	// Unary = Member | "!" {"!"} Member | "-" {"-"} Member
+	fn unary(&mut self) -> Result<(), String> {
+		while self.eat("!") || self.eat("-") {}
+		self.member()
+	}
+
+	// Member = Primary {"." IDENT ["(" [ExprList] ")"] | "[" Expr "]"}
+	//
+	// Also allows the qualified message names of message construction, like `google.protobuf.Duration{seconds: 1}`
+	fn member(&mut self) -> Result<(), String> {
+		self.primary()?;
+		loop {
+			if self.eat(".") {
+				self.ident()?;
+				if self.eat("(") {
+					self.expr_list(")")?;
+				}
+				else if self.eat("{") {
+					self.inits("}", Self::ident)?;
+				}
+			}
+			else if self.eat("[") {
+				self.expr()?;
+				self.expect("]")?;
+			}
+			else {
+				return Ok(());
+			}
+		}
+	}
+
+	// Primary = ["."] IDENT ["(" [ExprList] ")"] | ["."] IDENT {"." IDENT} "{" [FieldInits] "}"
+	//         | "(" Expr ")" | "[" [ExprList] "]" | "{" [MapInits] "}" | LITERAL
+	fn primary(&mut self) -> Result<(), String> {
+		match self.peek() {
+			Some(TokenKind::Literal) => {
+				self.pos += 1;
+				Ok(())
+			},
+
+			Some(TokenKind::Ident(_) | TokenKind::Punct(".")) => {
+				let _ = self.eat(".");
+				self.ident()?;
+				if self.eat("(") {
+					self.expr_list(")")?;
+				}
+				else if self.eat("{") {
+					self.inits("}", Self::ident)?;
+				}
+				Ok(())
+			},
+
+			Some(TokenKind::Punct("(")) => {
+				self.pos += 1;
+				self.expr()?;
+				self.expect(")")
+			},
+
+			Some(TokenKind::Punct("[")) => {
+				self.pos += 1;
+				self.expr_list("]")
+			},
+
+			Some(TokenKind::Punct("{")) => {
+				self.pos += 1;
+				self.inits("}", Self::expr)
+			},
+
+			_ => Err(self.unexpected("an expression")),
+		}
+	}
+
+	fn ident(&mut self) -> Result<(), String> {
+		if let Some(TokenKind::Ident(_)) = self.peek() {
+			self.pos += 1;
+			Ok(())
+		}
+		else {
+			Err(self.unexpected("an identifier"))
+		}
+	}
+
+	/// Comma-separated expressions with an optional trailing comma, up to and including the `close` token.
+	fn expr_list(&mut self, close: &str) -> Result<(), String> {
+		while !self.eat(close) {
+			self.expr()?;
+			if !self.eat(",") {
+				return self.expect(close);
+			}
+		}
+		Ok(())
+	}
+
+	/// Comma-separated `key: value` pairs with an optional trailing comma, up to and including the `close` token.
+	fn inits(&mut self, close: &str, mut key: impl FnMut(&mut Self) -> Result<(), String>) -> Result<(), String> {
+		while !self.eat(close) {
+			key(self)?;
+			self.expect(":")?;
+			self.expr()?;
+			if !self.eat(",") {
+				return self.expect(close);
+			}
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn check_syntax() {
+		for expr in [
+			"self.minReplicas <= self.replicas",
+			"self.replicas >= 0 && self.replicas < 10 || self.paused",
+			"self.name.startsWith('foo-') ? size(self.name) < 64 : true",
+			"self.items.all(i, i.value in ['a', \"b\", r'c\\d'])",
+			"has(self.spec) && self.spec.x == -1.5e3 && self.count == 0x1Fu",
+			"{'a': 1, 'b': 2}[self.key] > 0",
+			"self.labels.exists(k, k == '''multi\nline''')",
+			"!self.enabled",
+			"[1, 2, 3,].size() == 3",
+			"google.protobuf.Duration{seconds: 1}",
+		] {
+			if let Err(err) = super::check_syntax(expr) {
+				panic!("expected {expr:?} to be valid but it failed: {err}");
+			}
+		}
+
+		for (expr, expected) in [
+			("", "rule is empty"),
+			("   ", "rule is empty"),
+			("self.replicas >", "expected an expression but the rule ended"),
+			("self.replicas > > 1", "expected an expression but found `>` at offset 16"),
+			("(self.replicas > 1", "expected `)` but the rule ended"),
+			("self.replicas > 1)", "unexpected `)` at offset 17"),
+			("self.name == 'foo", "unterminated string literal at offset 13"),
+			("self.name = 'foo'", "unexpected character '=' at offset 10"),
+			("self.x ? 1", "expected `:` but the rule ended"),
+			("self.for == 1", "reserved word `for` at offset 5"),
+			("self.replicas # 1", "unexpected character '#' at offset 14"),
+		] {
+			match super::check_syntax(expr) {
+				Ok(()) => panic!("expected {expr:?} to be invalid but it succeeded"),
+				Err(err) => assert_eq!(err, expected, "unexpected error for {expr:?}"),
+			}
+		}
+	}
+}
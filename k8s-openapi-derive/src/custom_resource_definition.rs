@@ -14,6 +14,37 @@ pub(super) struct CustomResourceDefinition {
 	namespaced: bool,
 	has_subresources: Option<String>,
 	impl_deep_merge: bool,
+
+	/// Set by `#[custom_resource_definition(kind = "...")]`, in which case the derive is on the whole resource type
+	/// rather than on its spec type.
+	kind: Option<String>,
+
+	/// The fields of the resource type when deriving on the whole resource type, other than `metadata`.
+	resource_fields: Vec<ResourceField>,
+
+	/// `#[crd(validation = "...")]` attributes on the struct itself
+	struct_validations: Vec<Validation>,
+
+	/// `#[crd(...)]` attributes on the fields of the struct
+	properties: Vec<Property>,
+}
+
+struct ResourceField {
+	name: String,
+	ty: String,
+	required: bool,
+}
+
+struct Validation {
+	rule: String,
+	message: Option<String>,
+}
+
+struct Property {
+	name: String,
+	validations: Vec<Validation>,
+	/// The literal value of `#[crd(default = ...)]`, as Rust source
+	default: Option<String>,
 }
 
 impl super::CustomDerive for CustomResourceDefinition {
@@ -28,6 +59,7 @@ impl super::CustomDerive for CustomResourceDefinition {
 		let mut namespaced = false;
 		let mut has_subresources = None;
 		let mut impl_deep_merge = false;
+		let mut kind = None;
 
 		for attr in &input.attrs {
 			let syn::AttrStyle::Outer = attr.style else { continue; };
@@ -65,6 +97,13 @@ impl super::CustomDerive for CustomResourceDefinition {
 							plural = Some(lit.value());
 							continue;
 						}
+						else if meta.path.is_ident("kind") {
+							let syn::Lit::Str(lit) = &meta.lit else {
+								return Err(r#"#[custom_resource_definition(kind = "...")] expects a string literal value"#).spanning(meta);
+							};
+							kind = Some(lit.value());
+							continue;
+						}
 						else if meta.path.is_ident("has_subresources") {
 							let syn::Lit::Str(lit) = &meta.lit else {
 								return Err(r#"#[custom_resource_definition(has_subresources = "...")] expects a string literal value"#).spanning(meta);
@@ -99,7 +138,7 @@ impl super::CustomDerive for CustomResourceDefinition {
 				return
 					Err(r#"\
 						#[derive(CustomResourceDefinition)] found unexpected meta. \
-						Expected `group = "..."`, `namespaced`, `plural = "..."`, `version = "..." or `has_subresources` = "..."`"#)
+						Expected `group = "..."`, `kind = "..."`, `namespaced`, `plural = "..."`, `version = "..." or `has_subresources` = "..."`"#)
 					.spanning(meta);
 			}
 		}
@@ -117,6 +156,95 @@ impl super::CustomDerive for CustomResourceDefinition {
 			.ok_or(r#"#[derive(CustomResourceDefinition)] did not find a #[custom_resource_definition(plural = "...")] attribute on the struct"#)
 			.spanning(&tokens)?;
 
+		if kind.is_some() && has_subresources.is_some() {
+			return Err(r#"#[custom_resource_definition(has_subresources = "...")] is not supported with `kind = "..."`. Add a `status` field to the struct instead."#).spanning(&tokens);
+		}
+
+		let mut struct_validations = vec![];
+		parse_crd_attrs(&input.attrs, &mut struct_validations, None)?;
+
+		let mut resource_fields = vec![];
+		let mut has_metadata = false;
+		let mut properties = vec![];
+		match &input.data {
+			syn::Data::Struct(syn::DataStruct { fields: syn::Fields::Named(fields), .. }) => {
+				let rename_all = get_serde_meta(&input.attrs, "rename_all");
+
+				for field in &fields.named {
+					let field_name = field.ident.as_ref().map(ToString::to_string).unwrap_or_default();
+					let field_name = field_name.strip_prefix("r#").unwrap_or(&field_name);
+
+					let name =
+						if kind.is_some() {
+							// The generated serde impls name the field after the property, so the property name must round-trip to the field name.
+							let name = to_camel_case(field_name);
+							if k8s_openapi_codegen_common::get_rust_ident(&name) != field_name {
+								return Err(format!(
+									"#[derive(CustomResourceDefinition)] cannot infer the property name of this field. \
+									The field would need to be named `{}` for the property `{name}`",
+									k8s_openapi_codegen_common::get_rust_ident(&name))).spanning(field);
+							}
+
+							let (ty, required) = match get_option_inner_type(&field.ty) {
+								Some(ty) => (ty, false),
+								None => (&field.ty, true),
+							};
+							let ty = quote::ToTokens::into_token_stream(ty).to_string();
+							if ty.contains('.') {
+								return Err("#[derive(CustomResourceDefinition)] does not support this type").spanning(&field.ty);
+							}
+
+							match &*name {
+								"metadata" if !required => return Err("the `metadata` field must not be optional").spanning(field),
+								"metadata" => has_metadata = true,
+								"apiVersion" | "kind" => return Err(format!(
+									"the `{field_name}` field must not be specified. It is set automatically by the generated serde impls.")).spanning(field),
+								_ => resource_fields.push(ResourceField { name: name.clone(), ty, required }),
+							}
+
+							name
+						}
+						else if let Some(rename) = get_serde_meta(&field.attrs, "rename") {
+							rename.value()
+						}
+						else {
+							match &rename_all {
+								Some(rename_all) => rename_field(field_name, rename_all)?,
+								None => field_name.to_owned(),
+							}
+						};
+
+					let mut validations = vec![];
+					let mut default = None;
+					parse_crd_attrs(&field.attrs, &mut validations, Some(&mut default))?;
+					if !validations.is_empty() || default.is_some() {
+						properties.push(Property { name, validations, default });
+					}
+				}
+			},
+
+			syn::Data::Struct(syn::DataStruct { fields, .. }) =>
+				if kind.is_some() {
+					return Err(r#"#[custom_resource_definition(kind = "...")] requires a struct with named fields"#).spanning(&tokens);
+				}
+				else if let Some(attr) = fields.iter().flat_map(|field| &field.attrs).find(|attr| attr.path.is_ident("crd")) {
+					return Err("#[crd] attributes are only supported on named fields").spanning(attr);
+				},
+
+			syn::Data::Enum(_) | syn::Data::Union(_) =>
+				if kind.is_some() {
+					return Err(r#"#[custom_resource_definition(kind = "...")] requires a struct with named fields"#).spanning(&tokens);
+				},
+		}
+
+		if kind.is_some() && !has_metadata {
+			return Err(r#"#[custom_resource_definition(kind = "...")] requires the struct to have a `metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta` field"#).spanning(&tokens);
+		}
+
+		if !generate_schema && (!struct_validations.is_empty() || !properties.is_empty()) {
+			return Err("#[crd] attributes require #[custom_resource_definition(generate_schema)]").spanning(&tokens);
+		}
+
 		Ok(CustomResourceDefinition {
 			ident,
 			vis,
@@ -129,76 +257,156 @@ impl super::CustomDerive for CustomResourceDefinition {
 			namespaced,
 			has_subresources,
 			impl_deep_merge,
+
+			kind,
+			resource_fields,
+			struct_validations,
+			properties,
 		})
 	}
 
 	fn emit(self) -> Result<proc_macro2::TokenStream, syn::Error> {
-		let CustomResourceDefinition { ident: cr_spec_name, vis, tokens, group, version, plural, generate_schema, namespaced, has_subresources, impl_deep_merge } = self;
+		let CustomResourceDefinition {
+			ident,
+			vis,
+			tokens,
+			group,
+			version,
+			plural,
+			generate_schema,
+			namespaced,
+			has_subresources,
+			impl_deep_merge,
+			kind,
+			resource_fields,
+			struct_validations,
+			properties,
+		} = self;
 
 		let vis: std::borrow::Cow<'_, str> = match vis {
 			syn::Visibility::Inherited => "".into(),
 			vis => format!("{} ", quote::ToTokens::into_token_stream(vis)).into(),
 		};
 
-		let (cr_spec_name, cr_name) = {
-			let cr_spec_name_string = cr_spec_name.to_string();
-			if !cr_spec_name_string.ends_with("Spec") {
-				return Err("#[derive(CustomResourceDefinition)] requires the name of the struct to end with `Spec`").spanning(cr_spec_name);
+		// `cr_spec_name` is `None` when deriving on the whole resource type.
+		let (cr_spec_name, cr_name, cr_kind) =
+			if let Some(kind) = kind {
+				(None, ident.to_string(), kind)
+			}
+			else {
+				let cr_spec_name_string = ident.to_string();
+				if !cr_spec_name_string.ends_with("Spec") {
+					return Err(r#"#[derive(CustomResourceDefinition)] requires the name of the struct to end with `Spec`, or a `kind = "..."` meta"#).spanning(ident);
+				}
+				let cr_name_string = cr_spec_name_string[..(cr_spec_name_string.len() - 4)].to_owned();
+				(Some(cr_spec_name_string), cr_name_string.clone(), cr_name_string)
+			};
+		let is_whole_resource = cr_spec_name.is_none();
+
+		let structural_schema_impl = if generate_schema {
+			use std::fmt::Write;
+
+			let mut crd_attributes = String::new();
+			write_validations(&mut crd_attributes, "schema", &struct_validations, "        ");
+			for Property { name, validations, default } in &properties {
+				writeln!(crd_attributes, "        match schema.object().properties.get_mut({name:?}) {{").unwrap();
+				writeln!(crd_attributes, "            Some(k8s_openapi::schemars::schema::Schema::Object(property)) => {{").unwrap();
+				write_validations(&mut crd_attributes, "property", validations, "                ");
+				if let Some(default) = default {
+					writeln!(crd_attributes, "                property.metadata().default = Some(k8s_openapi::serde_json::json!({default}));").unwrap();
+				}
+				writeln!(crd_attributes, "            }},").unwrap();
+				let path = if cr_spec_name.is_some() { format!(".properties[spec].properties[{name}]") } else { format!(".properties[{name}]") };
+				writeln!(crd_attributes,
+					"            _ => return Err(k8s_openapi::StructuralSchemaError::__new({path:?}, \"the schema of {} does not have this property to apply its #[crd] attributes to\")),",
+					cr_spec_name.as_deref().unwrap_or(&cr_name)).unwrap();
+				writeln!(crd_attributes, "        }}").unwrap();
 			}
-			let cr_name_string = cr_spec_name_string[..(cr_spec_name_string.len() - 4)].to_owned();
-			(cr_spec_name_string, cr_name_string)
-		};
 
-		let body_parameter =
-			std::sync::Arc::new(swagger20::Parameter {
-				location: swagger20::ParameterLocation::Body,
-				name: "body".to_owned(),
-				required: true,
-				schema: swagger20::Schema {
-					description: None,
-					kind: swagger20::SchemaKind::Ref(swagger20::RefPath {
-						path: cr_name.clone(),
-						can_be_default: None,
-					}),
-					kubernetes_group_kind_versions: vec![],
-					list_kind: None,
-					impl_deep_merge: true,
-				},
-			});
-
-		let name_parameter =
-			std::sync::Arc::new(swagger20::Parameter {
-				location: swagger20::ParameterLocation::Path,
-				name: "name".to_owned(),
-				required: true,
-				schema: swagger20::Schema {
-					description: Some(format!("name of the `{cr_name}`")),
-					kind: swagger20::SchemaKind::Ty(swagger20::Type::String { format: None }),
-					kubernetes_group_kind_versions: vec![],
-					list_kind: None,
-					impl_deep_merge: true,
-				},
-			});
-
-		let (namespace_operation_id_component, namespace_parameter, namespace_path_component) =
-			if namespaced {
-				("Namespaced", Some(std::sync::Arc::new(swagger20::Parameter {
-					location: swagger20::ParameterLocation::Path,
-					name: "namespace".to_owned(),
-					required: true,
-					schema: swagger20::Schema {
-						description: Some("object name and auth scope, such as for teams and projects".to_owned()),
-						kind: swagger20::SchemaKind::Ty(swagger20::Type::String { format: None }),
-						kubernetes_group_kind_versions: vec![],
-						list_kind: None,
-						impl_deep_merge: true,
-					},
-				})), "/namespaces/{namespace}")
+			let body = if let Some(cr_spec_name) = &cr_spec_name {
+				let crd_attributes =
+					if crd_attributes.is_empty() {
+						crd_attributes
+					}
+					else {
+						format!("\
+        if let Some(k8s_openapi::schemars::schema::Schema::Object(schema)) =
+            definitions.get_mut(&<{cr_spec_name} as k8s_openapi::schemars::JsonSchema>::schema_name())
+        {{
+{}        }}
+",
+							crd_attributes.lines().fold(String::new(), |mut result, line| { writeln!(result, "    {line}").unwrap(); result }))
+					};
+
+				// The status subresource is not part of the spec type, so accept any value for it.
+				let status_property =
+					if has_subresources.is_some() { "                        (\"status\".to_owned(), k8s_openapi::schemars::schema::Schema::Bool(true)),\n" } else { "" };
+
+				format!("\
+        let mut __gen = k8s_openapi::schemars::gen::SchemaSettings::draft07().into_generator();
+        let spec = __gen.subschema_for::<{cr_spec_name}>();
+        let ty = |instance_type: k8s_openapi::schemars::schema::InstanceType| k8s_openapi::schemars::schema::Schema::Object(k8s_openapi::schemars::schema::SchemaObject {{
+            instance_type: Some(instance_type.into()),
+            ..Default::default()
+        }});
+
+        #[allow(unused_mut)]
+        let mut definitions = __gen.take_definitions();
+{crd_attributes}
+        k8s_openapi::make_structural(k8s_openapi::schemars::schema::RootSchema {{
+            meta_schema: None,
+            schema: k8s_openapi::schemars::schema::SchemaObject {{
+                instance_type: Some(k8s_openapi::schemars::schema::InstanceType::Object.into()),
+                object: Some(Box::new(k8s_openapi::schemars::schema::ObjectValidation {{
+                    properties: [
+                        (\"apiVersion\".to_owned(), ty(k8s_openapi::schemars::schema::InstanceType::String)),
+                        (\"kind\".to_owned(), ty(k8s_openapi::schemars::schema::InstanceType::String)),
+                        (\"metadata\".to_owned(), ty(k8s_openapi::schemars::schema::InstanceType::Object)),
+                        (\"spec\".to_owned(), spec),
+{status_property}                    ].into_iter().collect(),
+                    ..Default::default()
+                }})),
+                ..Default::default()
+            }},
+            definitions,
+        }})
+")
 			}
 			else {
-				("", None, "")
+				format!("\
+        let mut root = k8s_openapi::schemars::gen::SchemaSettings::draft07().into_generator().into_root_schema_for::<Self>();
+        let schema = &mut root.schema;
+
+        // Only the name and generateName of metadata may be constrained by the schema, so just replace the full ObjectMeta schema.
+        schema.object().properties.insert(\"metadata\".to_owned(), k8s_openapi::schemars::schema::Schema::Object(k8s_openapi::schemars::schema::SchemaObject {{
+            instance_type: Some(k8s_openapi::schemars::schema::InstanceType::Object.into()),
+            ..Default::default()
+        }}));
+{crd_attributes}
+        k8s_openapi::make_structural(root)
+")
+			};
+
+			let schema_description = match &cr_spec_name {
+				Some(cr_spec_name) => format!("The schema of `{cr_spec_name}` is converted"),
+				None => "The schema is converted".to_owned(),
 			};
 
+			format!("
+impl {cr_name} {{
+    /// Returns the structural schema of this custom resource, suitable for the `openAPIV3Schema` of its `CustomResourceDefinition`.
+    ///
+    /// {schema_description} with [`k8s_openapi::make_structural`]. Call this in a test to detect types
+    /// that cannot be expressed as a structural schema.
+    {vis}fn structural_schema() -> Result<k8s_openapi::schemars::schema::SchemaObject, k8s_openapi::StructuralSchemaError> {{
+{body}    }}
+}}
+")
+		}
+		else {
+			String::new()
+		};
+
 		let mut spec = swagger20::Spec {
 			info: swagger20::Info {
 				title: String::new(),
@@ -206,12 +414,17 @@ impl super::CustomDerive for CustomResourceDefinition {
 			},
 			definitions: [
 				(swagger20::DefinitionPath(cr_name.clone()), swagger20::Schema {
-					description: Some(format!("Custom resource for `{cr_spec_name}`")),
+					description: Some(match &cr_spec_name {
+						Some(cr_spec_name) => format!("Custom resource for `{cr_spec_name}`"),
+						None => format!("Custom resource `{cr_name}`"),
+					}),
 					kind: swagger20::SchemaKind::Properties([
 						(swagger20::PropertyName("apiVersion".to_owned()), (swagger20::Schema {
 							description: Some("APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: <https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#resources>".to_owned()),
 							kind: swagger20::SchemaKind::Ty(swagger20::Type::String { format: None }),
 							kubernetes_group_kind_versions: vec![],
+							default: None,
+							nullable: false,
 							list_kind: None,
 							impl_deep_merge: true,
 						}, false)),
@@ -219,6 +432,8 @@ impl super::CustomDerive for CustomResourceDefinition {
 							description: Some("Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: <https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#types-kinds>".to_owned()),
 							kind: swagger20::SchemaKind::Ty(swagger20::Type::String { format: None }),
 							kubernetes_group_kind_versions: vec![],
+							default: None,
+							nullable: false,
 							list_kind: None,
 							impl_deep_merge: true,
 						}, false)),
@@ -229,25 +444,48 @@ impl super::CustomDerive for CustomResourceDefinition {
 								can_be_default: None,
 							}),
 							kubernetes_group_kind_versions: vec![],
+							default: None,
+							nullable: false,
 							list_kind: None,
 							impl_deep_merge: true,
 						}, true)),
-						(swagger20::PropertyName("spec".to_owned()), (swagger20::Schema {
-							description: Some(format!("Specification of the `{cr_name}` custom resource")),
-							kind: swagger20::SchemaKind::Ref(swagger20::RefPath {
-								path: cr_spec_name,
-								can_be_default: None,
-							}),
-							kubernetes_group_kind_versions: vec![],
-							list_kind: None,
-							impl_deep_merge: true,
-						}, false)),
 					].into_iter().chain(
+						cr_spec_name.map(|cr_spec_name|
+							(swagger20::PropertyName("spec".to_owned()), (swagger20::Schema {
+								description: Some(format!("Specification of the `{cr_name}` custom resource")),
+								kind: swagger20::SchemaKind::Ref(swagger20::RefPath {
+									path: cr_spec_name,
+									can_be_default: None,
+								}),
+								kubernetes_group_kind_versions: vec![],
+								default: None,
+								nullable: false,
+								list_kind: None,
+								impl_deep_merge: true,
+							}, false)))
+					).chain(
+						resource_fields.into_iter().map(|ResourceField { name, ty, required }|
+							(swagger20::PropertyName(name), (swagger20::Schema {
+								description: None,
+								kind: swagger20::SchemaKind::Ref(swagger20::RefPath {
+									path: ty,
+									// The type isn't known to be Default, so a missing required field fails to deserialize like it would with serde_derive.
+									can_be_default: Some(false),
+								}),
+								kubernetes_group_kind_versions: vec![],
+								default: None,
+								nullable: false,
+								list_kind: None,
+								impl_deep_merge: true,
+							}, required)))
+					).chain(
 						has_subresources.map(|has_subresources|
 							(swagger20::PropertyName("subresources".to_owned()), (swagger20::Schema {
 								description: Some(format!("Subresources of the `{cr_name}` custom resource")),
 								kind: swagger20::SchemaKind::Ty(swagger20::Type::CustomResourceSubresources(has_subresources)),
 								kubernetes_group_kind_versions: vec![],
+								default: None,
+								nullable: false,
 								list_kind: None,
 								impl_deep_merge: true,
 							}, true)))
@@ -255,414 +493,30 @@ impl super::CustomDerive for CustomResourceDefinition {
 					kubernetes_group_kind_versions: vec![
 						swagger20::KubernetesGroupKindVersion {
 							group: group.clone(),
-							kind: cr_name.clone(),
+							kind: cr_kind.clone(),
 							version: version.clone(),
 						},
 					],
-					list_kind: Some(format!("{cr_name}List")),
+					default: None,
+					nullable: false,
+					list_kind: Some(format!("{cr_kind}List")),
 					impl_deep_merge,
 				}),
 			].into(),
-			operations: vec![
-				swagger20::Operation {
-					description: Some(format!("Create a `{cr_name}`")),
-					id: format!("create{namespace_operation_id_component}{cr_name}"),
-					method: swagger20::Method::Post,
-					kubernetes_action: Some(swagger20::KubernetesAction::Post),
-					kubernetes_group_kind_version: Some(swagger20::KubernetesGroupKindVersion {
-						group: group.clone(),
-						kind: cr_name.clone(),
-						version: version.clone(),
-					}),
-					parameters: [
-						Some(body_parameter.clone()),
-						namespace_parameter.clone(),
-					].into_iter().flatten().collect(),
-					path: swagger20::Path(format!("/apis/{group}/{version}{namespace_path_component}/{plural}")),
-					responses: swagger20::OperationResponses::Common(swagger20::Type::CreateResponse),
-					tag: None,
-				},
-
-				swagger20::Operation {
-					description: Some(format!("Delete a `{cr_name}`")),
-					id: format!("delete{namespace_operation_id_component}{cr_name}"),
-					method: swagger20::Method::Delete,
-					kubernetes_action: Some(swagger20::KubernetesAction::Delete),
-					kubernetes_group_kind_version: Some(swagger20::KubernetesGroupKindVersion {
-						group: group.clone(),
-						kind: cr_name.clone(),
-						version: version.clone(),
-					}),
-					parameters: [
-						Some(name_parameter.clone()),
-						namespace_parameter.clone(),
-						Some(std::sync::Arc::new(swagger20::Parameter {
-							location: swagger20::ParameterLocation::Query,
-							name: "optional".to_owned(),
-							required: true,
-							schema: swagger20::Schema {
-								description: Some("Optional parameters. Use `Default::default()` to not pass any.".to_owned()),
-								kind: swagger20::SchemaKind::Ref(swagger20::RefPath {
-									path: "io.k8s.DeleteOptional".to_owned(),
-									can_be_default: None,
-								}),
-								kubernetes_group_kind_versions: vec![],
-								list_kind: None,
-								impl_deep_merge: true,
-							},
-						})),
-					].into_iter().flatten().collect(),
-					path: swagger20::Path(format!("/apis/{group}/{version}{namespace_path_component}/{plural}/{{name}}")),
-					responses: swagger20::OperationResponses::Common(swagger20::Type::DeleteResponse),
-					tag: None,
-				},
-
-				swagger20::Operation {
-					description: Some(format!("Delete a collection of objects of kind `{cr_name}`")),
-					id: format!("deleteCollection{namespace_operation_id_component}{cr_name}"),
-					method: swagger20::Method::Delete,
-					kubernetes_action: Some(swagger20::KubernetesAction::DeleteCollection),
-					kubernetes_group_kind_version: Some(swagger20::KubernetesGroupKindVersion {
-						group: group.clone(),
-						kind: cr_name.clone(),
-						version: version.clone(),
-					}),
-					parameters: [
-						namespace_parameter.clone(),
-						Some(std::sync::Arc::new(swagger20::Parameter {
-							location: swagger20::ParameterLocation::Query,
-							name: "deleteOptional".to_owned(),
-							required: true,
-							schema: swagger20::Schema {
-								description: Some("Delete options. Use `Default::default()` to not pass any.".to_owned()),
-								kind: swagger20::SchemaKind::Ref(swagger20::RefPath {
-									path: "io.k8s.DeleteOptional".to_owned(),
-									can_be_default: None,
-								}),
-								kubernetes_group_kind_versions: vec![],
-								list_kind: None,
-								impl_deep_merge: true,
-							},
-						})),
-						Some(std::sync::Arc::new(swagger20::Parameter {
-							location: swagger20::ParameterLocation::Query,
-							name: "listOptional".to_owned(),
-							required: true,
-							schema: swagger20::Schema {
-								description: Some("List options. Use `Default::default()` to not pass any.".to_owned()),
-								kind: swagger20::SchemaKind::Ref(swagger20::RefPath {
-									path: "io.k8s.ListOptional".to_owned(),
-									can_be_default: None,
-								}),
-								kubernetes_group_kind_versions: vec![],
-								list_kind: None,
-								impl_deep_merge: true,
-							},
-						})),
-					].into_iter().flatten().collect(),
-					path: swagger20::Path(format!("/apis/{group}/{version}{namespace_path_component}/{plural}")),
-					responses: swagger20::OperationResponses::Common(swagger20::Type::DeleteResponse),
-					tag: None,
-				},
-
-				swagger20::Operation {
-					description: Some(format!("List objects of kind `{cr_name}`")),
-					id: format!("list{namespace_operation_id_component}{cr_name}"),
-					method: swagger20::Method::Get,
-					kubernetes_action: Some(swagger20::KubernetesAction::List),
-					kubernetes_group_kind_version: Some(swagger20::KubernetesGroupKindVersion {
-						group: group.clone(),
-						kind: cr_name.clone(),
-						version: version.clone(),
-					}),
-					parameters: [
-						namespace_parameter.clone(),
-						Some(std::sync::Arc::new(swagger20::Parameter {
-							location: swagger20::ParameterLocation::Query,
-							name: "optional".to_owned(),
-							required: true,
-							schema: swagger20::Schema {
-								description: Some("Optional parameters. Use `Default::default()` to not pass any.".to_owned()),
-								kind: swagger20::SchemaKind::Ref(swagger20::RefPath {
-									path: "io.k8s.ListOptional".to_owned(),
-									can_be_default: None,
-								}),
-								kubernetes_group_kind_versions: vec![],
-								list_kind: None,
-								impl_deep_merge: true,
-							},
-						})),
-					].into_iter().flatten().collect(),
-					path: swagger20::Path(format!("/apis/{group}/{version}{namespace_path_component}/{plural}")),
-					responses: swagger20::OperationResponses::Common(swagger20::Type::ListResponse),
-					tag: None,
-				},
-
-				swagger20::Operation {
-					description: Some(format!("Partially update the specified `{cr_name}`")),
-					id: format!("patch{namespace_operation_id_component}{cr_name}"),
-					method: swagger20::Method::Patch,
-					kubernetes_action: Some(swagger20::KubernetesAction::Patch),
-					kubernetes_group_kind_version: Some(swagger20::KubernetesGroupKindVersion {
-						group: group.clone(),
-						kind: cr_name.clone(),
-						version: version.clone(),
-					}),
-					parameters: [
-						Some(std::sync::Arc::new(swagger20::Parameter {
-							location: swagger20::ParameterLocation::Body,
-							name: "body".to_owned(),
-							required: true,
-							schema: swagger20::Schema {
-								description: None,
-								kind: swagger20::SchemaKind::Ref(swagger20::RefPath {
-									path: "io.k8s.apimachinery.pkg.apis.meta.v1.Patch".to_owned(),
-									can_be_default: None,
-								}),
-								kubernetes_group_kind_versions: vec![],
-								list_kind: None,
-								impl_deep_merge: true,
-							},
-						})),
-						Some(name_parameter.clone()),
-						namespace_parameter.clone(),
-						Some(std::sync::Arc::new(swagger20::Parameter {
-							location: swagger20::ParameterLocation::Body,
-							name: "optional".to_owned(),
-							required: true,
-							schema: swagger20::Schema {
-								description: None,
-								kind: swagger20::SchemaKind::Ref(swagger20::RefPath {
-									path: "io.k8s.PatchOptional".to_owned(),
-									can_be_default: None,
-								}),
-								kubernetes_group_kind_versions: vec![],
-								list_kind: None,
-								impl_deep_merge: true,
-							},
-						})),
-					].into_iter().flatten().collect(),
-					path: swagger20::Path(format!("/apis/{group}/{version}{namespace_path_component}/{plural}/{{name}}")),
-					responses: swagger20::OperationResponses::Common(swagger20::Type::PatchResponse),
-					tag: None,
-				},
-
-				swagger20::Operation {
-					description: Some(format!("Partially update the state of the specified `{cr_name}`")),
-					id: format!("patch{namespace_operation_id_component}{cr_name}Status"),
-					method: swagger20::Method::Patch,
-					kubernetes_action: Some(swagger20::KubernetesAction::Patch),
-					kubernetes_group_kind_version: Some(swagger20::KubernetesGroupKindVersion {
-						group: group.clone(),
-						kind: cr_name.clone(),
-						version: version.clone(),
-					}),
-					parameters: [
-						Some(std::sync::Arc::new(swagger20::Parameter {
-							location: swagger20::ParameterLocation::Body,
-							name: "body".to_owned(),
-							required: true,
-							schema: swagger20::Schema {
-								description: None,
-								kind: swagger20::SchemaKind::Ref(swagger20::RefPath {
-									path: "io.k8s.apimachinery.pkg.apis.meta.v1.Patch".to_owned(),
-									can_be_default: None,
-								}),
-								kubernetes_group_kind_versions: vec![],
-								list_kind: None,
-								impl_deep_merge: true,
-							},
-						})),
-						Some(name_parameter.clone()),
-						namespace_parameter.clone(),
-						Some(std::sync::Arc::new(swagger20::Parameter {
-							location: swagger20::ParameterLocation::Body,
-							name: "optional".to_owned(),
-							required: true,
-							schema: swagger20::Schema {
-								description: None,
-								kind: swagger20::SchemaKind::Ref(swagger20::RefPath {
-									path: "io.k8s.PatchOptional".to_owned(),
-									can_be_default: None,
-								}),
-								kubernetes_group_kind_versions: vec![],
-								list_kind: None,
-								impl_deep_merge: true,
-							},
-						})),
-					].into_iter().flatten().collect(),
-					path: swagger20::Path(format!("/apis/{group}/{version}{namespace_path_component}/{plural}/{{name}}/status")),
-					responses: swagger20::OperationResponses::Common(swagger20::Type::PatchResponse),
-					tag: None,
-				},
-
-				swagger20::Operation {
-					description: Some(format!("Read the specified `{cr_name}`")),
-					id: format!("read{namespace_operation_id_component}{cr_name}"),
-					method: swagger20::Method::Get,
-					kubernetes_action: Some(swagger20::KubernetesAction::Get),
-					kubernetes_group_kind_version: Some(swagger20::KubernetesGroupKindVersion {
-						group: group.clone(),
-						kind: cr_name.clone(),
-						version: version.clone(),
-					}),
-					parameters: [
-						Some(name_parameter.clone()),
-						namespace_parameter.clone(),
-					].into_iter().flatten().collect(),
-					path: swagger20::Path(format!("/apis/{group}/{version}{namespace_path_component}/{plural}/{{name}}")),
-					responses: swagger20::OperationResponses::Map([
-						(http::StatusCode::OK, swagger20::Schema {
-							description: Some("OK".to_owned()),
-							kind: swagger20::SchemaKind::Ref(swagger20::RefPath {
-								path: cr_name.clone(),
-								can_be_default: None,
-							}),
-							kubernetes_group_kind_versions: vec![],
-							list_kind: None,
-							impl_deep_merge: true,
-						}),
-					].into()),
-					tag: None,
-				},
-
-				swagger20::Operation {
-					description: Some(format!("Read status of the specified `{cr_name}`")),
-					id: format!("read{namespace_operation_id_component}{cr_name}Status"),
-					method: swagger20::Method::Get,
-					kubernetes_action: Some(swagger20::KubernetesAction::Get),
-					kubernetes_group_kind_version: Some(swagger20::KubernetesGroupKindVersion {
-						group: group.clone(),
-						kind: cr_name.clone(),
-						version: version.clone(),
-					}),
-					parameters: [
-						Some(name_parameter.clone()),
-						namespace_parameter.clone(),
-					].into_iter().flatten().collect(),
-					path: swagger20::Path(format!("/apis/{group}/{version}{namespace_path_component}/{plural}/{{name}}/status")),
-					responses: swagger20::OperationResponses::Map([
-						(http::StatusCode::OK, swagger20::Schema {
-							description: Some("OK".to_owned()),
-							kind: swagger20::SchemaKind::Ref(swagger20::RefPath {
-								path: cr_name.clone(),
-								can_be_default: None,
-							}),
-							kubernetes_group_kind_versions: vec![],
-							list_kind: None,
-							impl_deep_merge: true,
-						}),
-					].into()),
-					tag: None,
-				},
-
-				swagger20::Operation {
-					description: Some(format!("Replace the specified `{cr_name}`")),
-					id: format!("replace{namespace_operation_id_component}{cr_name}"),
-					method: swagger20::Method::Put,
-					kubernetes_action: Some(swagger20::KubernetesAction::Put),
-					kubernetes_group_kind_version: Some(swagger20::KubernetesGroupKindVersion {
-						group: group.clone(),
-						kind: cr_name.clone(),
-						version: version.clone(),
-					}),
-					parameters: [
-						Some(body_parameter.clone()),
-						Some(name_parameter.clone()),
-						namespace_parameter.clone(),
-						Some(std::sync::Arc::new(swagger20::Parameter {
-							location: swagger20::ParameterLocation::Body,
-							name: "optional".to_owned(),
-							required: true,
-							schema: swagger20::Schema {
-								description: None,
-								kind: swagger20::SchemaKind::Ref(swagger20::RefPath {
-									path: "io.k8s.ReplaceOptional".to_owned(),
-									can_be_default: None,
-								}),
-								kubernetes_group_kind_versions: vec![],
-								list_kind: None,
-								impl_deep_merge: true,
-							},
-						})),
-					].into_iter().flatten().collect(),
-					path: swagger20::Path(format!("/apis/{group}/{version}{namespace_path_component}/{plural}/{{name}}")),
-					responses: swagger20::OperationResponses::Common(swagger20::Type::ReplaceResponse),
-					tag: None,
-				},
-
-				swagger20::Operation {
-					description: Some(format!("Replace status of the specified `{cr_name}`")),
-					id: format!("replace{namespace_operation_id_component}{cr_name}Status"),
-					method: swagger20::Method::Put,
-					kubernetes_action: Some(swagger20::KubernetesAction::Put),
-					kubernetes_group_kind_version: Some(swagger20::KubernetesGroupKindVersion {
-						group: group.clone(),
-						kind: cr_name.clone(),
-						version: version.clone(),
-					}),
-					parameters: [
-						Some(body_parameter),
-						Some(name_parameter),
-						namespace_parameter.clone(),
-						Some(std::sync::Arc::new(swagger20::Parameter {
-							location: swagger20::ParameterLocation::Body,
-							name: "optional".to_owned(),
-							required: true,
-							schema: swagger20::Schema {
-								description: None,
-								kind: swagger20::SchemaKind::Ref(swagger20::RefPath {
-									path: "io.k8s.ReplaceOptional".to_owned(),
-									can_be_default: None,
-								}),
-								kubernetes_group_kind_versions: vec![],
-								list_kind: None,
-								impl_deep_merge: true,
-							},
-						})),
-					].into_iter().flatten().collect(),
-					path: swagger20::Path(format!("/apis/{group}/{version}{namespace_path_component}/{plural}/{{name}}/status")),
-					responses: swagger20::OperationResponses::Common(swagger20::Type::ReplaceResponse),
-					tag: None,
-				},
-
-				swagger20::Operation {
-					description: Some(format!("Watch objects of kind `{cr_name}`")),
-					id: format!("watch{namespace_operation_id_component}{cr_name}"),
-					method: swagger20::Method::Get,
-					kubernetes_action: Some(swagger20::KubernetesAction::Watch),
-					kubernetes_group_kind_version: Some(swagger20::KubernetesGroupKindVersion {
-						group: group.clone(),
-						kind: cr_name.clone(),
-						version: version.clone(),
-					}),
-					parameters: [
-						namespace_parameter,
-						Some(std::sync::Arc::new(swagger20::Parameter {
-							location: swagger20::ParameterLocation::Query,
-							name: "optional".to_owned(),
-							required: true,
-							schema: swagger20::Schema {
-								description: Some("Optional parameters. Use `Default::default()` to not pass any.".to_owned()),
-								kind: swagger20::SchemaKind::Ref(swagger20::RefPath {
-									path: "io.k8s.WatchOptional".to_owned(),
-									can_be_default: None,
-								}),
-								kubernetes_group_kind_versions: vec![],
-								list_kind: None,
-								impl_deep_merge: true,
-							},
-						})),
-					].into_iter().flatten().collect(),
-					path: swagger20::Path(format!("/apis/{group}/{version}{namespace_path_component}/{plural}")),
-					responses: swagger20::OperationResponses::Common(swagger20::Type::WatchResponse),
-					tag: None,
-				},
-			],
+			operations: swagger20::ResourceOperations {
+				group: &group,
+				version: &version,
+				kind: &cr_kind,
+				plural: &plural,
+				type_name: &cr_name,
+				namespaced,
+			}.operations(),
 		};
 
 		let mut run_state = RunState {
 			writer: vec![],
+			// The whole resource type is the type the derive is on, so it's already defined.
+			emit_struct_definition: !is_whole_resource,
 		};
 
 		let _ =
@@ -681,7 +535,8 @@ impl super::CustomDerive for CustomResourceDefinition {
 
 		assert!(spec.operations.is_empty());
 
-		let out = String::from_utf8(run_state.writer).map_err(|err| format!("#[derive(CustomResourceDefinition)] failed: {err}")).spanning(&tokens)?;
+		let mut out = String::from_utf8(run_state.writer).map_err(|err| format!("#[derive(CustomResourceDefinition)] failed: {err}")).spanning(&tokens)?;
+		out.push_str(&structural_schema_impl);
 		let result = out.parse().map_err(|err| format!("#[derive(CustomResourceDefinition)] failed: {err:?}")).spanning(&tokens)?;
 		Ok(result)
 	}
@@ -700,6 +555,7 @@ impl k8s_openapi_codegen_common::MapNamespace for MapNamespace {
 
 struct RunState {
 	writer: Vec<u8>,
+	emit_struct_definition: bool,
 }
 
 impl k8s_openapi_codegen_common::RunState for RunState {
@@ -721,7 +577,167 @@ impl k8s_openapi_codegen_common::RunState for RunState {
 		Ok(())
 	}
 
+	fn emit_struct_definition(&self) -> bool {
+		self.emit_struct_definition
+	}
+
 	fn finish(&mut self, writer: Self::Writer) {
 		self.writer = writer;
 	}
 }
+
+/// Writes the statement that sets `x-kubernetes-validations` on the schema object bound to `var`.
+fn write_validations(out: &mut String, var: &str, validations: &[Validation], indent: &str) {
+	use std::fmt::Write;
+
+	if validations.is_empty() {
+		return;
+	}
+
+	writeln!(out, "{indent}{var}.extensions.insert(\"x-kubernetes-validations\".to_owned(), k8s_openapi::serde_json::json!([").unwrap();
+	for Validation { rule, message } in validations {
+		match message {
+			Some(message) => writeln!(out, "{indent}    {{ \"rule\": {rule:?}, \"message\": {message:?} }},").unwrap(),
+			None => writeln!(out, "{indent}    {{ \"rule\": {rule:?} }},").unwrap(),
+		}
+	}
+	writeln!(out, "{indent}]));").unwrap();
+}
+
+/// Parses `#[crd(validation = "...", message = "...")]` and `#[crd(default = ...)]` attributes.
+///
+/// Each attribute adds at most one validation rule, so multiple rules require multiple attributes.
+/// `default` is only allowed if `default` is `Some`, ie for fields.
+fn parse_crd_attrs(
+	attrs: &[syn::Attribute],
+	validations: &mut Vec<Validation>,
+	mut default: Option<&mut Option<String>>,
+) -> Result<(), syn::Error> {
+	for attr in attrs {
+		let syn::AttrStyle::Outer = attr.style else { continue; };
+
+		if !attr.path.is_ident("crd") {
+			continue;
+		}
+
+		let metas = match attr.parse_meta()? {
+			syn::Meta::List(meta) => meta.nested,
+			meta => return Err(r#"#[crd] expects a list of metas, like `#[crd(validation = "...")]`"#).spanning(meta),
+		};
+
+		let mut rule = None;
+		let mut message = None;
+
+		for meta in metas {
+			let syn::NestedMeta::Meta(syn::Meta::NameValue(meta)) = &meta else {
+				return Err(r#"#[crd] found unexpected meta. Expected `validation = "..."`, `message = "..."` or `default = ...`"#).spanning(meta);
+			};
+
+			if meta.path.is_ident("validation") {
+				let syn::Lit::Str(lit) = &meta.lit else {
+					return Err(r#"#[crd(validation = "...")] expects a string literal value"#).spanning(meta);
+				};
+				let value = lit.value();
+				if let Err(err) = crate::cel::check_syntax(&value) {
+					return Err(format!("#[crd(validation = \"...\")] is not a valid CEL expression: {err}")).spanning(lit);
+				}
+				rule = Some(value);
+			}
+			else if meta.path.is_ident("message") {
+				let syn::Lit::Str(lit) = &meta.lit else {
+					return Err(r#"#[crd(message = "...")] expects a string literal value"#).spanning(meta);
+				};
+				message = Some(lit.value());
+			}
+			else if meta.path.is_ident("default") {
+				let Some(default) = &mut default else {
+					return Err("#[crd(default = ...)] is only supported on fields").spanning(meta);
+				};
+				if let syn::Lit::ByteStr(_) | syn::Lit::Byte(_) | syn::Lit::Verbatim(_) = &meta.lit {
+					return Err("#[crd(default = ...)] expects a string, char, integer, float or boolean literal value").spanning(meta);
+				}
+				**default = Some(quote::ToTokens::into_token_stream(&meta.lit).to_string());
+			}
+			else {
+				return Err(r#"#[crd] found unexpected meta. Expected `validation = "..."`, `message = "..."` or `default = ...`"#).spanning(meta);
+			}
+		}
+
+		match (rule, message) {
+			(Some(rule), message) => validations.push(Validation { rule, message }),
+			(None, Some(_)) => return Err(r#"#[crd(message = "...")] requires a `validation = "..."` in the same attribute"#).spanning(attr),
+			(None, None) => (),
+		}
+	}
+
+	Ok(())
+}
+
+/// Gets the value of `#[schemars(name = "...")]` or `#[serde(name = "...")]`, since these determine the property names in the schema.
+/// The former takes precedence, like it does for `#[derive(schemars::JsonSchema)]`.
+fn get_serde_meta(attrs: &[syn::Attribute], name: &str) -> Option<syn::LitStr> {
+	let mut result = None;
+
+	for attr in attrs {
+		let is_schemars = attr.path.is_ident("schemars");
+		if !is_schemars && !attr.path.is_ident("serde") {
+			continue;
+		}
+
+		// Other attributes may use syntax that isn't a valid meta, but that's for their own derives to complain about.
+		let Ok(syn::Meta::List(meta)) = attr.parse_meta() else { continue; };
+
+		for meta in meta.nested {
+			if let syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue { path, lit: syn::Lit::Str(lit), .. })) = meta {
+				if path.is_ident(name) {
+					if is_schemars {
+						return Some(lit);
+					}
+					result = Some(lit);
+				}
+			}
+		}
+	}
+
+	result
+}
+
+/// Applies `#[serde(rename_all = "...")]` to the name of a field, which is expected to be in `snake_case`.
+fn rename_field(field_name: &str, rename_all: &syn::LitStr) -> Result<String, syn::Error> {
+	Ok(match &*rename_all.value() {
+		"lowercase" | "snake_case" => field_name.to_owned(),
+		"UPPERCASE" | "SCREAMING_SNAKE_CASE" => field_name.to_uppercase(),
+		"PascalCase" => field_name.split('_').filter(|word| !word.is_empty()).map(capitalize).collect(),
+		"camelCase" => to_camel_case(field_name),
+		"kebab-case" => field_name.replace('_', "-"),
+		"SCREAMING-KEBAB-CASE" => field_name.replace('_', "-").to_uppercase(),
+		_ => return Err("#[derive(CustomResourceDefinition)] does not recognize this `rename_all` value").spanning(rename_all),
+	})
+}
+
+/// Converts a `snake_case` field name to `camelCase`.
+fn to_camel_case(field_name: &str) -> String {
+	let mut words = field_name.split('_').filter(|word| !word.is_empty());
+	words.next().map(str::to_owned).into_iter().chain(words.map(capitalize)).collect()
+}
+
+fn capitalize(word: &str) -> String {
+	let mut chars = word.chars();
+	chars.next().map(|c| c.to_uppercase().chain(chars).collect()).unwrap_or_default()
+}
+
+/// Returns `T` if the given type is `Option<T>`.
+fn get_option_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+	let syn::Type::Path(syn::TypePath { qself: None, path }) = ty else { return None; };
+	let segment = path.segments.last()?;
+	if segment.ident != "Option" {
+		return None;
+	}
+
+	let syn::PathArguments::AngleBracketed(args) = &segment.arguments else { return None; };
+	let mut args = args.args.iter();
+	match (args.next(), args.next()) {
+		(Some(syn::GenericArgument::Type(ty)), None) => Some(ty),
+		_ => None,
+	}
+}
@@ -7,6 +7,7 @@
 
 //! This crate contains custom derives related to the [`k8s-openapi`](https://crates.io/crates/k8s-openapi) crate.
 
+mod cel;
 mod custom_resource_definition;
 
 trait CustomDerive: Sized {
@@ -109,6 +110,33 @@ impl<T, E> ResultExt<T> for Result<T, E> where E: std::fmt::Display {
 /// The `schemars` feature of the `k8s-openapi` crate must be enabled so that the types in that crate also have their `schemars::JsonSchema` impls enabled.
 /// You will also need to impl `schemars::JsonSchema` on the `Spec` type itself, either manually or via `#[derive(schemars::JsonSchema)]`.
 ///
+/// With `generate_schema`, the custom resource type also gets a `fn structural_schema()` that returns the schema of the custom resource
+/// converted into a [structural schema](https://kubernetes.io/docs/tasks/extend-kubernetes/custom-resources/custom-resource-definitions/#specifying-a-structural-schema)
+/// by `k8s_openapi::make_structural`, suitable for the `openAPIV3Schema` of the `CustomResourceDefinition`. `$ref`s are inlined,
+/// `Option`s become `nullable`, and enums are rewritten into the forms that structural schemas allow. If the spec type cannot be expressed
+/// as a structural schema, the function returns an error with the path of the offending schema, so calling it in a test catches such specs early.
+///
+/// Fields of the spec type can have `#[crd(validation = "...", message = "...")]` attributes, which add
+/// [validation rules](https://kubernetes.io/docs/tasks/extend-kubernetes/custom-resources/custom-resource-definitions/#validation-rules)
+/// to the field's schema in `x-kubernetes-validations`. The `message` is optional. A field can have multiple such attributes to add multiple rules.
+/// The rules are checked to be syntactically valid CEL expressions at compile time. The attribute can also be set on the spec type itself,
+/// for rules that involve multiple fields. Fields can also have a `#[crd(default = ...)]` attribute with a string, integer, float or boolean literal,
+/// which sets the `default` of the field's schema. Both attributes require `generate_schema`, and only affect the schema returned by `structural_schema()`.
+/// If the schema of the spec type does not have a property for a field with these attributes, such as when the field is `#[serde(skip)]`,
+/// `structural_schema()` returns an error with the path of the missing property.
+///
+/// ```rust,ignore
+/// #[serde(rename_all = "camelCase")]
+/// #[crd(validation = "self.minReplicas <= self.replicas", message = "replicas must not be less than minReplicas")]
+/// struct FooBarSpec {
+///     #[crd(validation = "self <= 10")]
+///     replicas: i32,
+///
+///     #[crd(default = 1)]
+///     min_replicas: i32,
+/// }
+/// ```
+///
 /// The `has_subresources` meta item is optional. If set, the generated custom resource type will have a `subresources` field. The value of the meta item
 /// specifies which namespace the type will be used from. For example, setting `has_subresources = "v1"` causes the field to be of the
 /// `k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresources` type.
@@ -116,6 +144,34 @@ impl<T, E> ResultExt<T> for Result<T, E> where E: std::fmt::Display {
 /// The `impl_deep_merge` meta item is optional. If set, the generated custom resource type will impl the `k8s_openapi::DeepMerge` trait. This impl will require
 /// you to impl `k8s_openapi::DeepMerge` on the spec type yourself.
 ///
+/// The `kind` meta item is optional. If set, the macro is applied to the whole custom resource type rather than to its spec type, so the type name
+/// does not need to end in `Spec`, and the resource can have top-level fields other than `spec`, like a `ConfigMap`'s `data`.
+/// The "kind" is set to the value of the meta item, and the list kind to that value with a `List` suffix.
+/// The type must have a `metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta` field, and must not have `api_version` or `kind` fields.
+/// Every other field must be named such that its camelCase name is the property name, and fields of type `Option<T>` are optional properties.
+/// The macro generates the same impls and clientset functions as above for the type itself, including the `serde` impls, so the type must not
+/// `#[derive(serde::Deserialize, serde::Serialize)]` or `#[derive(schemars::JsonSchema)]` itself. `has_subresources` is not supported with `kind`.
+///
+/// ```rust,ignore
+/// #[derive(
+///     Clone, Debug, PartialEq,
+///     k8s_openapi_derive::CustomResourceDefinition,
+/// )]
+/// #[custom_resource_definition(
+///     group = "k8s-openapi-tests-custom-resource-definition.com",
+///     version = "v1",
+///     plural = "foobarconfigs",
+///     kind = "FooBarConfig",
+///     generate_schema,
+/// )]
+/// struct FooBarConfig {
+///     metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta,
+///     data: Option<std::collections::BTreeMap<String, String>>,
+/// }
+/// ```
+///
+/// Since `namespaced` is not set, this resource is cluster-scoped.
+///
 /// You would then register this custom resource definition with Kubernetes, with code like this:
 ///
 /// ```rust,ignore
@@ -163,7 +219,7 @@ impl<T, E> ResultExt<T> for Result<T, E> where E: std::fmt::Display {
 ///     fn create(
 ///         namespace: &str,
 ///         body: &FooBar,
-///         optional: k8s_openapi::DeleteOptional<'_>,
+///         optional: k8s_openapi::CreateOptional<'_>,
 ///     ) ->
 ///         Result<
 ///             (
@@ -218,6 +274,19 @@ impl<T, E> ResultExt<T> for Result<T, E> where E: std::fmt::Display {
 ///         >
 ///     { ... }
 ///
+///     /// List objects of kind FooBar across all namespaces
+///     fn list_for_all_namespaces(
+///         optional: k8s_openapi::ListOptional<'_>,
+///     ) ->
+///         Result<
+///             (
+///                 k8s_openapi::http::Request<Vec<u8>>,
+///                 fn(k8s_openapi::http::StatusCode) -> k8s_openapi::ResponseBody<k8s_openapi::ListResponse<Self>>
+///             ),
+///             k8s_openapi::RequestError,
+///         >
+///     { ... }
+///
 ///     /// Partially update the specified FooBar
 ///     fn patch(
 ///         name: &str,
@@ -319,16 +388,33 @@ impl<T, E> ResultExt<T> for Result<T, E> where E: std::fmt::Display {
 ///             k8s_openapi::RequestError,
 ///         >
 ///     { ... }
+///
+///     /// Watch objects of kind FooBar across all namespaces
+///     fn watch_for_all_namespaces(
+///         optional: k8s_openapi::WatchOptional<'_>,
+///     ) ->
+///         Result<
+///             (
+///                 k8s_openapi::http::Request<Vec<u8>>,
+///                 fn(k8s_openapi::http::StatusCode) -> k8s_openapi::ResponseBody<k8s_openapi::WatchResponse<Self>>
+///             ),
+///             k8s_openapi::RequestError,
+///         >
+///     { ... }
 /// }
 /// ```
 ///
+/// `list_for_all_namespaces` and `watch_for_all_namespaces` are only generated if the custom resource is `namespaced`.
+/// The operations are constructed by `k8s_openapi_codegen_common::swagger20::ResourceOperations`, so their URLs are the same
+/// as those of the corresponding functions of built-in resource types like `Pod`.
+///
 /// (You may wish to generate your own crate's docs, or run it through `cargo-expand`, to be able to see the macro expansion.)
 ///
 /// Refer to [the `k8s-openapi` crate docs](https://arnavion.github.io/k8s-openapi/) to learn more about how to use the return values of these functions.
 ///
 /// See the [`custom_resource_definition` test in the repository](https://github.com/Arnavion/k8s-openapi/blob/master/k8s-openapi-tests/src/custom_resource_definition.rs)
 /// for a full example of using this custom derive.
-#[proc_macro_derive(CustomResourceDefinition, attributes(custom_resource_definition))]
+#[proc_macro_derive(CustomResourceDefinition, attributes(custom_resource_definition, crd))]
 pub fn derive_custom_resource_definition(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 	run_custom_derive::<custom_resource_definition::CustomResourceDefinition>(input)
 }
@@ -0,0 +1,61 @@
+#[test]
+fn accessors() {
+	use k8s_openapi::compat::v1_20::api::core::v1::{ContainerCompat, PodCompat, PodSpecCompat};
+
+	let mut pod = k8s_openapi::api::core::v1::Pod {
+		spec: Some(k8s_openapi::api::core::v1::PodSpec {
+			containers: vec![k8s_openapi::api::core::v1::Container {
+				name: "container1".to_owned(),
+				..Default::default()
+			}],
+			..Default::default()
+		}),
+		..Default::default()
+	};
+
+	assert_eq!(container_names(&pod), ["container1"]);
+
+	pod.spec_mut().as_mut().unwrap().containers_mut()[0].image_mut().replace("image1".to_owned());
+	assert_eq!(pod.spec.as_ref().unwrap().containers[0].image.as_deref(), Some("image1"));
+
+	pod.metadata_mut().name = Some("pod1".to_owned());
+	assert_eq!(pod.metadata.name.as_deref(), Some("pod1"));
+}
+
+#[test]
+fn builders() {
+	use k8s_openapi::compat::v1_20::api::core::v1::{ContainerCompat, PodCompat, PodSpecCompat};
+	use k8s_openapi::compat::v1_20::apimachinery::pkg::apis::meta::v1::ObjectMetaCompat;
+
+	// No struct literals, so this works even if a later version adds fields that a struct literal would have to name.
+	let pod =
+		k8s_openapi::api::core::v1::Pod::default()
+		.with_metadata(k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta::default().with_name(Some("pod1".to_owned())))
+		.with_spec(Some(
+			k8s_openapi::api::core::v1::PodSpec::default()
+			.with_containers(vec![k8s_openapi::api::core::v1::Container::default().with_name("container1".to_owned())]),
+		));
+
+	assert_eq!(pod.metadata.name.as_deref(), Some("pod1"));
+	assert_eq!(container_names(&pod), ["container1"]);
+}
+
+#[test]
+fn range() {
+	k8s_openapi::k8s_if_ge_1_22! {
+		use k8s_openapi::compat::v1_22::api::batch::v1::CronJobSpecCompat;
+
+		let cron_job_spec = k8s_openapi::api::batch::v1::CronJobSpec {
+			schedule: "* * * * *".to_owned(),
+			..Default::default()
+		};
+		assert_eq!(cron_job_spec.schedule(), "* * * * *");
+	}
+}
+
+/// A function that works with the `Pod` type of any version from 1.20 onwards.
+fn container_names<T>(pod: &T) -> Vec<&str> where T: k8s_openapi::compat::v1_20::api::core::v1::PodCompat {
+	use k8s_openapi::compat::v1_20::api::core::v1::{ContainerCompat, PodSpecCompat};
+
+	pod.spec().iter().flat_map(PodSpecCompat::containers).map(|container| &**container.name()).collect()
+}
@@ -207,7 +207,7 @@ async fn test() {
 		subresources: Default::default(),
 	};
 	let (request, response_body) =
-		FooBar::create("default", &fb1)
+		FooBar::create("default", &fb1, Default::default())
 		.expect("couldn't create FooBar");
 	let fb1 = match client.get_single_value(request, response_body).await {
 		(k8s_openapi::CreateResponse::Ok(fb) | k8s_openapi::CreateResponse::Created(fb), _) => fb,
@@ -358,3 +358,388 @@ fn dont_require_deep_merge_when_not_requested() {
 		prop3: Option<i32>,
 	}
 }
+
+#[test]
+fn structural_schema() {
+	#[derive(
+		Clone, Debug, PartialEq,
+		k8s_openapi_derive::CustomResourceDefinition,
+		schemars::JsonSchema,
+		serde::Deserialize, serde::Serialize,
+	)]
+	#[custom_resource_definition(
+		group = "k8s-openapi-tests-custom-resource-definition.com",
+		version = "v1",
+		plural = "foobars",
+		generate_schema,
+		namespaced,
+		has_subresources = "v1",
+	)]
+	struct FooBarSpec {
+		prop1: String,
+		prop2: Vec<bool>,
+		#[serde(skip_serializing_if = "Option::is_none")]
+		prop3: Option<i32>,
+	}
+
+	let schema = FooBar::structural_schema().expect("FooBar schema is not structural");
+	assert_eq!(serde_json::to_value(schema).unwrap(), serde_json::json!({
+		"type": "object",
+		"properties": {
+			"apiVersion": { "type": "string" },
+			"kind": { "type": "string" },
+			"metadata": { "type": "object" },
+			"spec": {
+				"type": "object",
+				"properties": {
+					"prop1": { "type": "string" },
+					"prop2": { "type": "array", "items": { "type": "boolean" } },
+					"prop3": { "type": "integer", "format": "int32", "nullable": true },
+				},
+				"required": ["prop1", "prop2"],
+			},
+			"status": { "x-kubernetes-preserve-unknown-fields": true },
+		},
+	}));
+}
+
+#[test]
+fn structural_schema_rewrites() {
+	#[derive(schemars::JsonSchema)]
+	#[allow(dead_code)]
+	struct Spec {
+		/// Unit variants
+		mode: Mode,
+		action: Option<Action>,
+		port: Port,
+		labels: std::collections::BTreeMap<String, String>,
+		extra: serde_json::Value,
+		target_port: k8s_openapi::apimachinery::pkg::util::intstr::IntOrString,
+	}
+
+	#[derive(schemars::JsonSchema)]
+	#[allow(dead_code)]
+	enum Mode {
+		/// Fast
+		Fast,
+		/// Slow
+		Slow,
+	}
+
+	#[derive(schemars::JsonSchema)]
+	#[allow(dead_code)]
+	#[serde(tag = "type")]
+	enum Action {
+		Run { command: Vec<String> },
+		Sleep { seconds: u32 },
+	}
+
+	#[derive(schemars::JsonSchema)]
+	#[allow(dead_code)]
+	#[serde(untagged)]
+	enum Port {
+		Number(i32),
+		Name(String),
+	}
+
+	let schema = k8s_openapi::structural_schema_for::<Spec>().expect("Spec schema is not structural");
+	let schema = serde_json::to_value(schema).unwrap();
+	assert_eq!(schema["properties"]["mode"], serde_json::json!({
+		"description": "Unit variants",
+		"type": "string",
+		"enum": ["Fast", "Slow"],
+	}));
+	assert_eq!(schema["properties"]["action"], serde_json::json!({
+		"type": "object",
+		"properties": {
+			"command": { "type": "array", "items": { "type": "string" } },
+			"seconds": { "type": "integer", "format": "uint32", "minimum": 0.0 },
+			"type": { "type": "string", "enum": ["Run", "Sleep"] },
+		},
+		"oneOf": [
+			{ "required": ["command", "type"] },
+			{ "required": ["seconds", "type"] },
+		],
+		"nullable": true,
+	}));
+	assert_eq!(schema["properties"]["port"], serde_json::json!({
+		"x-kubernetes-int-or-string": true,
+		"anyOf": [{ "type": "integer" }, { "type": "string" }],
+	}));
+	assert_eq!(schema["properties"]["labels"], serde_json::json!({
+		"type": "object",
+		"additionalProperties": { "type": "string" },
+	}));
+	assert_eq!(schema["properties"]["extra"], serde_json::json!({
+		"x-kubernetes-preserve-unknown-fields": true,
+	}));
+	assert_eq!(schema["properties"]["target_port"]["x-kubernetes-int-or-string"], true);
+	assert_eq!(schema["properties"]["target_port"].get("type"), None);
+}
+
+#[test]
+fn structural_schema_errors() {
+	#[derive(schemars::JsonSchema)]
+	#[allow(dead_code)]
+	struct Spec {
+		nested: Nested,
+	}
+
+	#[derive(schemars::JsonSchema)]
+	#[allow(dead_code)]
+	struct Nested {
+		values: Vec<Value>,
+	}
+
+	#[derive(schemars::JsonSchema)]
+	#[allow(dead_code)]
+	#[serde(untagged)]
+	enum Value {
+		Name(String),
+		Object { name: String },
+	}
+
+	let err = k8s_openapi::structural_schema_for::<Spec>().expect_err("expected Spec schema to not be structural");
+	assert_eq!(err.path(), ".properties[nested].properties[values].items");
+	assert_eq!(err.message(), "`anyOf` variants of types [String, Object] cannot be combined into a single structural schema");
+
+	#[derive(schemars::JsonSchema)]
+	#[allow(dead_code)]
+	struct Recursive {
+		children: Vec<Recursive>,
+	}
+
+	let err = k8s_openapi::structural_schema_for::<Recursive>().expect_err("expected Recursive schema to not be structural");
+	assert_eq!(err.path(), ".properties[children].items.properties[children].items");
+	assert_eq!(err.message(), r##"reference "#/definitions/Recursive" is recursive"##);
+}
+
+#[test]
+fn structural_schema_validations_and_defaults() {
+	#[derive(
+		Clone, Debug, PartialEq,
+		k8s_openapi_derive::CustomResourceDefinition,
+		schemars::JsonSchema,
+		serde::Deserialize, serde::Serialize,
+	)]
+	#[custom_resource_definition(
+		group = "k8s-openapi-tests-custom-resource-definition.com",
+		version = "v1",
+		plural = "foobars",
+		generate_schema,
+		namespaced,
+	)]
+	#[serde(rename_all = "camelCase")]
+	#[crd(validation = "self.minReplicas <= self.replicas", message = "replicas must not be less than minReplicas")]
+	struct FooBarSpec {
+		#[crd(validation = "self <= 10")]
+		#[crd(validation = "self % 2 == 0", message = "replicas must be even")]
+		replicas: i32,
+
+		#[crd(default = 1)]
+		min_replicas: i32,
+
+		#[serde(rename = "mode", skip_serializing_if = "Option::is_none")]
+		#[crd(default = "fast")]
+		#[crd(validation = "self in ['fast', 'slow']")]
+		the_mode: Option<String>,
+	}
+
+	let schema = FooBar::structural_schema().expect("FooBar schema is not structural");
+	let schema = serde_json::to_value(schema).unwrap();
+	assert_eq!(schema["properties"]["spec"], serde_json::json!({
+		"type": "object",
+		"properties": {
+			"replicas": {
+				"type": "integer",
+				"format": "int32",
+				"x-kubernetes-validations": [
+					{ "rule": "self <= 10" },
+					{ "rule": "self % 2 == 0", "message": "replicas must be even" },
+				],
+			},
+			"minReplicas": {
+				"type": "integer",
+				"format": "int32",
+				"default": 1,
+			},
+			"mode": {
+				"type": "string",
+				"default": "fast",
+				"nullable": true,
+				"x-kubernetes-validations": [
+					{ "rule": "self in ['fast', 'slow']" },
+				],
+			},
+		},
+		"required": ["minReplicas", "replicas"],
+		"x-kubernetes-validations": [
+			{ "rule": "self.minReplicas <= self.replicas", "message": "replicas must not be less than minReplicas" },
+		],
+	}));
+
+	#[derive(
+		Clone, Debug, PartialEq,
+		k8s_openapi_derive::CustomResourceDefinition,
+		schemars::JsonSchema,
+		serde::Deserialize, serde::Serialize,
+	)]
+	#[custom_resource_definition(
+		group = "k8s-openapi-tests-custom-resource-definition.com",
+		version = "v1",
+		plural = "foobazs",
+		generate_schema,
+		namespaced,
+	)]
+	struct FooBazSpec {
+		#[serde(skip)]
+		#[crd(default = 1)]
+		replicas: i32,
+	}
+
+	let err = FooBaz::structural_schema().expect_err("expected FooBaz schema to not have the replicas property");
+	assert_eq!(err.path(), ".properties[spec].properties[replicas]");
+	assert_eq!(err.message(), "the schema of FooBazSpec does not have this property to apply its #[crd] attributes to");
+}
+
+#[test]
+fn whole_resource() {
+	use k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
+
+	#[derive(
+		Clone, Debug, Default, PartialEq,
+		k8s_openapi_derive::CustomResourceDefinition,
+	)]
+	#[custom_resource_definition(
+		group = "k8s-openapi-tests-custom-resource-definition.com",
+		version = "v1",
+		plural = "foobarconfigs",
+		kind = "FooBarConfiguration",
+		generate_schema,
+	)]
+	struct FooBarConfig {
+		metadata: meta::ObjectMeta,
+		data: Option<std::collections::BTreeMap<String, String>>,
+		#[crd(validation = "self >= 0")]
+		revision: i64,
+	}
+
+	assert_eq!(<FooBarConfig as k8s_openapi::Resource>::API_VERSION, "k8s-openapi-tests-custom-resource-definition.com/v1");
+	assert_eq!(<FooBarConfig as k8s_openapi::Resource>::KIND, "FooBarConfiguration");
+	assert_eq!(<FooBarConfig as k8s_openapi::Resource>::URL_PATH_SEGMENT, "foobarconfigs");
+	assert_eq!(<FooBarConfig as k8s_openapi::ListableResource>::LIST_KIND, "FooBarConfigurationList");
+
+	let fbc: FooBarConfig =
+		serde_json::from_str(r#"{ "metadata": { "name": "fbc1" }, "data": { "foo": "bar" }, "revision": 5 }"#).unwrap();
+	assert_eq!(fbc, FooBarConfig {
+		metadata: meta::ObjectMeta { name: Some("fbc1".to_owned()), ..Default::default() },
+		data: Some([("foo".to_owned(), "bar".to_owned())].into()),
+		revision: 5,
+	});
+	let fbc = serde_json::to_string(&fbc).unwrap();
+	assert_eq!(fbc, "\
+		{\
+			\"apiVersion\":\"k8s-openapi-tests-custom-resource-definition.com/v1\",\
+			\"kind\":\"FooBarConfiguration\",\
+			\"data\":{\"foo\":\"bar\"},\
+			\"metadata\":{\"name\":\"fbc1\"},\
+			\"revision\":5\
+		}\
+	");
+
+	let (request, _) = FooBarConfig::read("fbc1").expect("couldn't read FooBarConfig");
+	assert_eq!(request.uri(), "/apis/k8s-openapi-tests-custom-resource-definition.com/v1/foobarconfigs/fbc1");
+
+	let schema = FooBarConfig::structural_schema().expect("FooBarConfig schema is not structural");
+	let schema = serde_json::to_value(schema).unwrap();
+	assert_eq!(schema["properties"]["metadata"], serde_json::json!({ "type": "object" }));
+	assert_eq!(schema["properties"]["data"], serde_json::json!({
+		"type": "object",
+		"additionalProperties": { "type": "string" },
+	}));
+	assert_eq!(schema["properties"]["revision"], serde_json::json!({
+		"type": "integer",
+		"format": "int64",
+		"x-kubernetes-validations": [
+			{ "rule": "self >= 0" },
+		],
+	}));
+}
+
+#[test]
+fn operations_match_builtin_types() {
+	use k8s_openapi::api::core::v1 as api;
+
+	#[derive(
+		Clone, Debug, PartialEq,
+		k8s_openapi_derive::CustomResourceDefinition,
+		serde::Deserialize, serde::Serialize,
+	)]
+	#[custom_resource_definition(
+		group = "k8s-openapi-tests-custom-resource-definition.com",
+		version = "v1",
+		plural = "foobars",
+		namespaced,
+	)]
+	struct FooBarSpec {
+		prop1: String,
+	}
+
+	let fb: FooBar = FooBar { metadata: Default::default(), spec: None };
+	let pod: api::Pod = Default::default();
+	let patch = k8s_openapi::apimachinery::pkg::apis::meta::v1::Patch::Merge(serde_json::json!({}));
+	let create_optional = k8s_openapi::CreateOptional {
+		dry_run: Some(&[k8s_openapi::DryRun::All]),
+		field_manager: Some("k8s-openapi-tests"),
+		..Default::default()
+	};
+
+	let _: fn(&str, &FooBar, k8s_openapi::CreateOptional<'_>) -> Result<_, k8s_openapi::RequestError> = FooBar::create;
+
+	let requests: Vec<(http::Request<Vec<u8>>, http::Request<Vec<u8>>)> = vec![
+		(FooBar::create("ns", &fb, create_optional).unwrap().0, api::Pod::create("ns", &pod, create_optional).unwrap().0),
+		(FooBar::delete("fb1", "ns", Default::default()).unwrap().0, api::Pod::delete("fb1", "ns", Default::default()).unwrap().0),
+		(
+			FooBar::delete_collection("ns", Default::default(), Default::default()).unwrap().0,
+			api::Pod::delete_collection("ns", Default::default(), Default::default()).unwrap().0,
+		),
+		(FooBar::list("ns", Default::default()).unwrap().0, api::Pod::list("ns", Default::default()).unwrap().0),
+		(FooBar::list_for_all_namespaces(Default::default()).unwrap().0, api::Pod::list_for_all_namespaces(Default::default()).unwrap().0),
+		(FooBar::patch("fb1", "ns", &patch, Default::default()).unwrap().0, api::Pod::patch("fb1", "ns", &patch, Default::default()).unwrap().0),
+		(
+			FooBar::patch_status("fb1", "ns", &patch, Default::default()).unwrap().0,
+			api::Pod::patch_status("fb1", "ns", &patch, Default::default()).unwrap().0,
+		),
+		(FooBar::read("fb1", "ns").unwrap().0, api::Pod::read("fb1", "ns").unwrap().0),
+		(FooBar::read_status("fb1", "ns").unwrap().0, api::Pod::read_status("fb1", "ns").unwrap().0),
+		(FooBar::replace("fb1", "ns", &fb, Default::default()).unwrap().0, api::Pod::replace("fb1", "ns", &pod, Default::default()).unwrap().0),
+		(
+			FooBar::replace_status("fb1", "ns", &fb, Default::default()).unwrap().0,
+			api::Pod::replace_status("fb1", "ns", &pod, Default::default()).unwrap().0,
+		),
+		(FooBar::watch("ns", Default::default()).unwrap().0, api::Pod::watch("ns", Default::default()).unwrap().0),
+		(FooBar::watch_for_all_namespaces(Default::default()).unwrap().0, api::Pod::watch_for_all_namespaces(Default::default()).unwrap().0),
+	];
+
+	for (fb_request, pod_request) in requests {
+		assert_eq!(fb_request.method(), pod_request.method());
+
+		let fb_path = fb_request.uri().path();
+		let pod_path = pod_request.uri().path();
+		assert_eq!(
+			fb_path.strip_prefix("/apis/k8s-openapi-tests-custom-resource-definition.com/v1").unwrap().replace("foobars", "pods"),
+			pod_path.strip_prefix("/api/v1").unwrap(),
+		);
+
+		assert_eq!(fb_request.uri().query(), pod_request.uri().query());
+	}
+
+	let (request, _) = FooBar::create("ns", &fb, create_optional).unwrap();
+	assert_eq!(request.uri(), "/apis/k8s-openapi-tests-custom-resource-definition.com/v1/namespaces/ns/foobars?&dryRun=All&fieldManager=k8s-openapi-tests");
+
+	let (request, _) = FooBar::list_for_all_namespaces(Default::default()).unwrap();
+	assert_eq!(request.uri(), "/apis/k8s-openapi-tests-custom-resource-definition.com/v1/foobars?");
+
+	let (request, _) = FooBar::watch_for_all_namespaces(Default::default()).unwrap();
+	assert_eq!(request.uri(), "/apis/k8s-openapi-tests-custom-resource-definition.com/v1/foobars?&watch=true");
+}
@@ -0,0 +1,272 @@
+#[test]
+fn api_resource_lists() {
+	let discovery = discovery();
+
+	let deployments = discovery.get("apps", "v1", "Deployment").unwrap();
+	assert_eq!(*deployments, k8s_openapi::DiscoveredResource {
+		group: "apps".to_owned(),
+		version: "v1".to_owned(),
+		kind: "Deployment".to_owned(),
+		plural: "deployments".to_owned(),
+		singular: "deployment".to_owned(),
+		namespaced: true,
+		verbs: vec!["get".to_owned(), "list".to_owned()],
+		short_names: vec!["deploy".to_owned()],
+		categories: vec!["all".to_owned()],
+		subresources: vec!["scale".to_owned(), "status".to_owned()],
+	});
+
+	assert_eq!(discovery.preferred_version(""), Some("v1"));
+	assert_eq!(discovery.preferred_version("autoscaling"), Some("v2"));
+	assert_eq!(discovery.preferred_version("batch"), None);
+
+	assert!(discovery.get("apps", "v1beta1", "Deployment").is_none());
+}
+
+#[test]
+fn resolve() {
+	let discovery = discovery();
+
+	for (input, expected) in [
+		("deploy", Some(("apps", "v1", "deployments"))),
+		("deployments", Some(("apps", "v1", "deployments"))),
+		("deployment", Some(("apps", "v1", "deployments"))),
+		("Deployment", Some(("apps", "v1", "deployments"))),
+		("deployments.apps", Some(("apps", "v1", "deployments"))),
+		("deploy.apps", Some(("apps", "v1", "deployments"))),
+		("deployments.v1.apps", Some(("apps", "v1", "deployments"))),
+		("deployments.v2.apps", None),
+		("deployments.batch", None),
+
+		("po", Some(("", "v1", "pods"))),
+
+		// Core group is preferred over other groups
+		("events", Some(("", "v1", "events"))),
+		("ev", Some(("", "v1", "events"))),
+		("events.events.k8s.io", Some(("events.k8s.io", "v1", "events"))),
+
+		// Preferred version is preferred over other versions
+		("hpa", Some(("autoscaling", "v2", "horizontalpodautoscalers"))),
+		("hpa.v1.autoscaling", Some(("autoscaling", "v1", "horizontalpodautoscalers"))),
+
+		("ingresses.networking.k8s.io", Some(("networking.k8s.io", "v1", "ingresses"))),
+		("ing", Some(("networking.k8s.io", "v1", "ingresses"))),
+
+		("foo", None),
+	] {
+		let actual = discovery.resolve(input).map(|resource| (&*resource.group, &*resource.version, &*resource.plural));
+		assert_eq!(actual, expected, "{input}");
+	}
+}
+
+#[test]
+fn aggregated_discovery() {
+	let mut discovery = k8s_openapi::Discovery::new();
+
+	discovery.add_aggregated_discovery(br#"{
+		"kind": "APIGroupDiscoveryList",
+		"apiVersion": "apidiscovery.k8s.io/v2beta1",
+		"metadata": {},
+		"items": [
+			{
+				"metadata": { "creationTimestamp": null },
+				"versions": [
+					{
+						"version": "v1",
+						"resources": [
+							{
+								"resource": "pods",
+								"responseKind": { "group": "", "kind": "Pod", "version": "" },
+								"scope": "Namespaced",
+								"singularResource": "pod",
+								"verbs": ["create", "delete", "get", "list"],
+								"shortNames": ["po"],
+								"categories": ["all"],
+								"subresources": [
+									{ "subresource": "log", "responseKind": { "group": "", "kind": "Pod", "version": "" }, "verbs": ["get"] },
+									{ "subresource": "status", "responseKind": { "group": "", "kind": "Pod", "version": "" }, "verbs": ["get", "patch", "update"] }
+								]
+							},
+							{
+								"resource": "namespaces",
+								"responseKind": { "group": "", "kind": "Namespace", "version": "" },
+								"scope": "Cluster",
+								"singularResource": "namespace",
+								"verbs": ["get", "list"],
+								"shortNames": ["ns"]
+							}
+						],
+						"freshness": "Current"
+					}
+				]
+			},
+			{
+				"metadata": { "name": "batch", "creationTimestamp": null },
+				"versions": [
+					{
+						"version": "v1",
+						"resources": [
+							{
+								"resource": "jobs",
+								"responseKind": { "group": "", "kind": "Job", "version": "" },
+								"scope": "Namespaced",
+								"singularResource": "job",
+								"verbs": ["get", "list"],
+								"categories": ["all"]
+							}
+						]
+					},
+					{
+						"version": "v1beta1",
+						"resources": []
+					}
+				]
+			}
+		]
+	}"#).unwrap();
+
+	assert_eq!(discovery.preferred_version(""), Some("v1"));
+	assert_eq!(discovery.preferred_version("batch"), Some("v1"));
+
+	let pods = discovery.get("", "v1", "Pod").unwrap();
+	assert_eq!(*pods, k8s_openapi::DiscoveredResource {
+		group: String::new(),
+		version: "v1".to_owned(),
+		kind: "Pod".to_owned(),
+		plural: "pods".to_owned(),
+		singular: "pod".to_owned(),
+		namespaced: true,
+		verbs: vec!["create".to_owned(), "delete".to_owned(), "get".to_owned(), "list".to_owned()],
+		short_names: vec!["po".to_owned()],
+		categories: vec!["all".to_owned()],
+		subresources: vec!["log".to_owned(), "status".to_owned()],
+	});
+
+	assert!(!discovery.resolve("ns").unwrap().namespaced);
+	assert_eq!(discovery.resolve("jobs.batch").unwrap().kind, "Job");
+
+	assert!(discovery.add_aggregated_discovery(br#"{ "kind": "APIGroupList", "groups": [] }"#).is_err());
+	assert!(discovery.add_aggregated_discovery(br#"{ "kind": "APIGroupDiscoveryList", "items": [{ "versions": [{ "version": "v1", "resources": [{ "resource": "foos" }] }] }] }"#).is_err());
+}
+
+#[test]
+fn cache() {
+	const CACHE_TTL: std::time::Duration = std::time::Duration::from_mins(1);
+
+	let discovery = discovery();
+
+	let cache = discovery.to_cache();
+	let cached_discovery = k8s_openapi::Discovery::from_cache(&cache, CACHE_TTL).unwrap().unwrap();
+	assert_eq!(cached_discovery.resources(), discovery.resources());
+	assert_eq!(cached_discovery.preferred_version("autoscaling"), Some("v2"));
+	assert_eq!(cached_discovery.resolve("hpa").unwrap().version, "v2");
+
+	// Cache files only have second precision
+	assert_eq!(
+		cached_discovery.fetched_at().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs(),
+		discovery.fetched_at().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs(),
+	);
+
+	// Expired
+	let expired_cache = br#"{ "fetchedAt": 1000, "preferredVersions": {}, "resources": [] }"#;
+	assert!(k8s_openapi::Discovery::from_cache(expired_cache, CACHE_TTL).unwrap().is_none());
+
+	assert!(k8s_openapi::Discovery::from_cache(b"{}", CACHE_TTL).is_err());
+
+	let cache_dir = std::env::temp_dir().join(format!("k8s-openapi-tests-discovery-{}", std::process::id()));
+	let cache_file = cache_dir.join("servers").join("discovery.json");
+	assert!(k8s_openapi::Discovery::read_cache_file(&cache_file, CACHE_TTL).unwrap().is_none());
+	discovery.write_cache_file(&cache_file).unwrap();
+	let cached_discovery = k8s_openapi::Discovery::read_cache_file(&cache_file, CACHE_TTL).unwrap().unwrap();
+	assert_eq!(cached_discovery.resources(), discovery.resources());
+	std::fs::remove_dir_all(&cache_dir).unwrap();
+}
+
+fn discovery() -> k8s_openapi::Discovery {
+	use k8s_openapi::apimachinery::pkg::apis::meta::v1::{APIGroup, APIGroupList, APIResource, APIResourceList, APIVersions, GroupVersionForDiscovery};
+
+	fn api_resource(name: &str, kind: &str, namespaced: bool, short_names: &[&str]) -> APIResource {
+		APIResource {
+			name: name.to_owned(),
+			kind: kind.to_owned(),
+			namespaced,
+			singular_name: String::new(),
+			verbs: vec!["get".to_owned(), "list".to_owned()],
+			short_names: Some(short_names.iter().copied().map(ToOwned::to_owned).collect()),
+			categories: Some(vec!["all".to_owned()]),
+			..Default::default()
+		}
+	}
+
+	fn group_version(group: &str, version: &str) -> GroupVersionForDiscovery {
+		GroupVersionForDiscovery {
+			group_version: format!("{group}/{version}"),
+			version: version.to_owned(),
+		}
+	}
+
+	let mut discovery = k8s_openapi::Discovery::new();
+
+	discovery.add_api_versions(&APIVersions {
+		versions: vec!["v1".to_owned()],
+		..Default::default()
+	});
+
+	discovery.add_api_group_list(&APIGroupList {
+		groups: vec![
+			APIGroup {
+				name: "apps".to_owned(),
+				preferred_version: Some(group_version("apps", "v1")),
+				versions: vec![group_version("apps", "v1")],
+				..Default::default()
+			},
+			APIGroup {
+				name: "autoscaling".to_owned(),
+				preferred_version: Some(group_version("autoscaling", "v2")),
+				versions: vec![group_version("autoscaling", "v2"), group_version("autoscaling", "v1")],
+				..Default::default()
+			},
+		],
+	});
+
+	discovery.add_api_resource_list(&APIResourceList {
+		group_version: "v1".to_owned(),
+		resources: vec![
+			api_resource("events", "Event", true, &["ev"]),
+			api_resource("pods", "Pod", true, &["po"]),
+			api_resource("pods/log", "Pod", true, &[]),
+		],
+	});
+
+	discovery.add_api_resource_list(&APIResourceList {
+		group_version: "apps/v1".to_owned(),
+		resources: vec![
+			api_resource("deployments", "Deployment", true, &["deploy"]),
+			api_resource("deployments/scale", "Scale", true, &[]),
+			api_resource("deployments/status", "Deployment", true, &[]),
+		],
+	});
+
+	// Deliberately added before v2 to test that the preferred version is preferred
+	discovery.add_api_resource_list(&APIResourceList {
+		group_version: "autoscaling/v1".to_owned(),
+		resources: vec![api_resource("horizontalpodautoscalers", "HorizontalPodAutoscaler", true, &["hpa"])],
+	});
+
+	discovery.add_api_resource_list(&APIResourceList {
+		group_version: "autoscaling/v2".to_owned(),
+		resources: vec![api_resource("horizontalpodautoscalers", "HorizontalPodAutoscaler", true, &["hpa"])],
+	});
+
+	discovery.add_api_resource_list(&APIResourceList {
+		group_version: "events.k8s.io/v1".to_owned(),
+		resources: vec![api_resource("events", "Event", true, &["ev"])],
+	});
+
+	discovery.add_api_resource_list(&APIResourceList {
+		group_version: "networking.k8s.io/v1".to_owned(),
+		resources: vec![api_resource("ingresses", "Ingress", true, &["ing"])],
+	});
+
+	discovery
+}
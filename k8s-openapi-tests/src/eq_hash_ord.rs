@@ -0,0 +1,58 @@
+#[test]
+fn hash_set() {
+	let container = k8s_openapi::api::core::v1::Container {
+		name: "container1".to_owned(),
+		image: Some("image1".to_owned()),
+		resources: Some(k8s_openapi::api::core::v1::ResourceRequirements {
+			limits: Some([("cpu".to_owned(), k8s_openapi::apimachinery::pkg::api::resource::Quantity("1".to_owned()))].into()),
+			..Default::default()
+		}),
+		..Default::default()
+	};
+
+	let containers: std::collections::HashSet<_> = [
+		container.clone(),
+		k8s_openapi::api::core::v1::Container {
+			image: Some("image2".to_owned()),
+			..container.clone()
+		},
+		container.clone(),
+	].into_iter().collect();
+	assert_eq!(containers.len(), 2);
+	assert!(containers.contains(&container));
+}
+
+#[test]
+fn btree_set() {
+	let tolerations: std::collections::BTreeSet<_> = [
+		k8s_openapi::api::core::v1::Toleration {
+			key: Some("b".to_owned()),
+			operator: Some("Exists".to_owned()),
+			..Default::default()
+		},
+		k8s_openapi::api::core::v1::Toleration {
+			key: Some("a".to_owned()),
+			operator: Some("Exists".to_owned()),
+			..Default::default()
+		},
+		k8s_openapi::api::core::v1::Toleration {
+			key: Some("a".to_owned()),
+			operator: Some("Exists".to_owned()),
+			..Default::default()
+		},
+	].into_iter().collect();
+	assert_eq!(
+		tolerations.iter().map(|toleration| toleration.key.as_deref()).collect::<Vec<_>>(),
+		[Some("a"), Some("b")],
+	);
+}
+
+#[test]
+fn time() {
+	let time = k8s_openapi::apimachinery::pkg::apis::meta::v1::Time(
+		k8s_openapi::chrono::DateTime::parse_from_rfc3339("2023-01-02T03:04:05Z")
+		.unwrap()
+		.with_timezone(&k8s_openapi::chrono::Utc));
+	let times: std::collections::HashSet<_> = [time.clone(), time].into_iter().collect();
+	assert_eq!(times.len(), 1);
+}
@@ -0,0 +1,87 @@
+#[test]
+fn protocol() {
+	assert_eq!(k8s_openapi::ExecProtocol::from_header_value("v4.channel.k8s.io"), Some(k8s_openapi::ExecProtocol::V4));
+	assert_eq!(k8s_openapi::ExecProtocol::from_header_value("v5.channel.k8s.io"), Some(k8s_openapi::ExecProtocol::V5));
+	assert_eq!(k8s_openapi::ExecProtocol::from_header_value("channel.k8s.io"), None);
+	assert_eq!(k8s_openapi::ExecProtocol::V5.as_str(), "v5.channel.k8s.io");
+}
+
+#[test]
+fn encode() {
+	let protocol = k8s_openapi::ExecProtocol::V5;
+
+	assert_eq!(protocol.encode_stdin(b"ls\n"), b"\x00ls\n");
+	assert_eq!(
+		protocol.encode_resize(k8s_openapi::TerminalSize { width: 80, height: 24 }),
+		b"\x04{\"Width\":80,\"Height\":24}",
+	);
+	assert_eq!(protocol.encode_close(k8s_openapi::ExecChannel::Stdin).as_deref(), Some(&b"\xff\x00"[..]));
+	assert_eq!(k8s_openapi::ExecProtocol::V4.encode_close(k8s_openapi::ExecChannel::Stdin), None);
+}
+
+#[test]
+fn decode() {
+	let protocol = k8s_openapi::ExecProtocol::V4;
+
+	match protocol.decode(b"\x01") {
+		Ok(k8s_openapi::ExecFrame::Empty(k8s_openapi::ExecChannel::Stdout)) => (),
+		result => panic!("expected Ok(Empty(Stdout)), but it returned {result:?}"),
+	}
+
+	match protocol.decode(b"\x01hello") {
+		Ok(k8s_openapi::ExecFrame::Stdout(b"hello")) => (),
+		result => panic!(r#"expected Ok(Stdout("hello")), but it returned {result:?}"#),
+	}
+
+	match protocol.decode(b"\x02oops") {
+		Ok(k8s_openapi::ExecFrame::Stderr(b"oops")) => (),
+		result => panic!(r#"expected Ok(Stderr("oops")), but it returned {result:?}"#),
+	}
+
+	match protocol.decode(b"\x04{}") {
+		Err(k8s_openapi::ExecFrameError::UnexpectedChannel(k8s_openapi::ExecChannel::Resize)) => (),
+		result => panic!("expected Err(UnexpectedChannel(Resize)), but it returned {result:?}"),
+	}
+
+	match protocol.decode(b"\x03{\"metadata\":{},\"status\":\"Success\"}") {
+		Ok(k8s_openapi::ExecFrame::Status(status)) if status == k8s_openapi::ExecStatus::Success && status.exit_code() == Some(0) => (),
+		result => panic!("expected Ok(Status(Success)), but it returned {result:?}"),
+	}
+
+	match protocol.decode(concat!(
+		"\x03",
+		r#"{"metadata":{},"status":"Failure","message":"command terminated with non-zero exit code: error executing command [false], exit code 1","#,
+		r#""reason":"NonZeroExitCode","details":{"causes":[{"reason":"ExitCode","message":"1"}]}}"#,
+	).as_bytes()) {
+		Ok(k8s_openapi::ExecFrame::Status(k8s_openapi::ExecStatus::ExitCode(1))) => (),
+		result => panic!("expected Ok(Status(ExitCode(1))), but it returned {result:?}"),
+	}
+
+	match protocol.decode(concat!(
+		"\x03",
+		r#"{"metadata":{},"status":"Failure","message":"container not found (\"foo\")","reason":"InternalError"}"#,
+	).as_bytes()) {
+		Ok(k8s_openapi::ExecFrame::Status(status @ k8s_openapi::ExecStatus::Failure(_))) if status.exit_code().is_none() => (),
+		result => panic!("expected Ok(Status(Failure)), but it returned {result:?}"),
+	}
+
+	match protocol.decode(b"\xff\x00") {
+		Err(k8s_openapi::ExecFrameError::UnexpectedChannel(k8s_openapi::ExecChannel::Close)) => (),
+		result => panic!("expected Err(UnexpectedChannel(Close)), but it returned {result:?}"),
+	}
+
+	match k8s_openapi::ExecProtocol::V5.decode(b"\xff\x01") {
+		Ok(k8s_openapi::ExecFrame::Close(k8s_openapi::ExecChannel::Stdout)) => (),
+		result => panic!("expected Ok(Close(Stdout)), but it returned {result:?}"),
+	}
+
+	match protocol.decode(b"\x07data") {
+		Err(k8s_openapi::ExecFrameError::UnknownChannel(7)) => (),
+		result => panic!("expected Err(UnknownChannel(7)), but it returned {result:?}"),
+	}
+
+	match protocol.decode(b"") {
+		Err(k8s_openapi::ExecFrameError::Empty) => (),
+		result => panic!("expected Err(Empty), but it returned {result:?}"),
+	}
+}
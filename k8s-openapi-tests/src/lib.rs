@@ -502,24 +502,48 @@ mod methodstring {
 
 mod api_versions;
 
+mod compat;
+
 mod custom_resource_definition;
 
 mod deserialize_leniency;
 
+mod discovery;
+
 mod deployment;
 
+mod eq_hash_ord;
+
+mod exec;
+
 mod job;
 
+mod list_stream;
+
 mod logs;
 
+mod optional;
+
+mod partial_object_metadata;
+
 mod patch;
 
 mod pod;
 
+mod port_forward;
+
 mod resource;
 
+mod response_body;
+
 mod special_idents;
 
+mod table;
+
 mod time;
 
+mod version_check;
+
+mod warning;
+
 mod watch_event;
@@ -0,0 +1,91 @@
+#[test]
+fn items() {
+	use k8s_openapi::api::core::v1 as api;
+
+	const BODY: &[u8] = br#"{
+		"kind": "PodList",
+		"apiVersion": "v1",
+		"metadata": { "resourceVersion": "12345", "remainingItemCount": 0 },
+		"items": [
+			{ "metadata": { "name": "foo", "labels": { "app": "a" } } },
+			{ "metadata": { "name": "bar" }, "spec": { "containers": [{ "name": "c", "args": ["[", "]", "{\"}"] }] } }
+		],
+		"unknown": 123
+	}"#;
+
+	// Feed the response one byte at a time to ensure every boundary is handled.
+	let mut list_stream = k8s_openapi::ListStream::new(k8s_openapi::ResponseBody::<k8s_openapi::ListResponse<api::Pod>>::new(k8s_openapi::http::StatusCode::OK));
+	let mut chunks = BODY.chunks(1);
+	let mut events = vec![];
+	loop {
+		match list_stream.parse() {
+			Ok(k8s_openapi::ListStreamEvent::End) => break,
+			Ok(event) => events.push(event),
+			Err(k8s_openapi::ResponseError::NeedMoreData) => {
+				let chunk = chunks.next().expect("list stream did not end");
+				list_stream.append_slice(chunk);
+			},
+			Err(err) => panic!("{err}"),
+		}
+	}
+
+	assert_eq!(events.len(), 3);
+
+	match &events[0] {
+		k8s_openapi::ListStreamEvent::Metadata(metadata) => assert_eq!(metadata.resource_version.as_deref(), Some("12345")),
+		event => panic!("unexpected event: {event:?}"),
+	}
+
+	match &events[1] {
+		k8s_openapi::ListStreamEvent::Item(pod) => {
+			assert_eq!(pod.metadata.name.as_deref(), Some("foo"));
+			assert_eq!(pod.metadata.labels.as_ref().and_then(|labels| labels.get("app")).map(String::as_str), Some("a"));
+		},
+		event => panic!("unexpected event: {event:?}"),
+	}
+
+	match &events[2] {
+		k8s_openapi::ListStreamEvent::Item(pod) => {
+			assert_eq!(pod.metadata.name.as_deref(), Some("bar"));
+			let args = pod.spec.as_ref().and_then(|spec| spec.containers[0].args.as_deref());
+			assert_eq!(args, Some(&["[".to_owned(), "]".to_owned(), r#"{"}"#.to_owned()][..]));
+		},
+		event => panic!("unexpected event: {event:?}"),
+	}
+
+	// All data has been consumed, and the stream stays ended.
+	assert!(list_stream.response_body().is_empty());
+	assert!(matches!(list_stream.parse(), Ok(k8s_openapi::ListStreamEvent::End)));
+}
+
+#[test]
+fn empty() {
+	use k8s_openapi::api::core::v1 as api;
+
+	let mut list_stream = k8s_openapi::ListStream::new(k8s_openapi::ResponseBody::<k8s_openapi::ListResponse<api::Pod>>::new(k8s_openapi::http::StatusCode::OK));
+	list_stream.append_slice(br#"{"kind":"PodList","apiVersion":"v1","metadata":{},"items":[]}"#);
+	assert!(matches!(list_stream.parse(), Ok(k8s_openapi::ListStreamEvent::Metadata(_))));
+	assert!(matches!(list_stream.parse(), Ok(k8s_openapi::ListStreamEvent::End)));
+}
+
+#[test]
+fn malformed() {
+	use k8s_openapi::api::core::v1 as api;
+
+	let mut list_stream = k8s_openapi::ListStream::new(k8s_openapi::ResponseBody::<k8s_openapi::ListResponse<api::Pod>>::new(k8s_openapi::http::StatusCode::OK));
+	list_stream.append_slice(br#"{"items":[{"metadata":{"name":"foo"}} {"metadata":{"name":"bar"}}]}"#);
+	assert!(matches!(list_stream.parse(), Ok(k8s_openapi::ListStreamEvent::Item(_))));
+	assert!(matches!(list_stream.parse(), Err(k8s_openapi::ResponseError::Json(_))));
+}
+
+#[test]
+fn other() {
+	use k8s_openapi::api::core::v1 as api;
+
+	let mut list_stream = k8s_openapi::ListStream::new(k8s_openapi::ResponseBody::<k8s_openapi::ListResponse<api::Pod>>::new(k8s_openapi::http::StatusCode::FORBIDDEN));
+	list_stream.append_slice(br#"{"kind":"Status","apiVersion":"v1","status":"Failure","code":403}"#);
+	match list_stream.parse() {
+		Ok(k8s_openapi::ListStreamEvent::Other(Ok(Some(value)))) => assert_eq!(value["code"], 403),
+		result => panic!("unexpected result: {result:?}"),
+	}
+}
@@ -169,3 +169,155 @@ fn partial_and_invalid_utf8_sequences() {
 		result => panic!("expected empty buffer to return Err(Utf8(0, Some(1))), but it returned {result:?}"),
 	}
 }
+
+#[test]
+fn lines() {
+	let mut lines: k8s_openapi::PodLogLineStream<false> = k8s_openapi::PodLogLineStream::new(k8s_openapi::ResponseBody::new(reqwest::StatusCode::OK));
+
+	lines.append_slice(b"first ");
+
+	// Line is incomplete
+	match lines.parse() {
+		Err(k8s_openapi::ResponseError::NeedMoreData) => (),
+		result => panic!("expected incomplete line to return Err(NeedMoreData), but it returned {result:?}"),
+	}
+
+	lines.append_slice(b"line\r\nsecond line\n\xe4\xb8");
+
+	match lines.parse() {
+		Ok(k8s_openapi::ReadPodLogLineResponse::Ok(k8s_openapi::PodLogLine { timestamp: None, text, truncated: false })) if text == "first line" => (),
+		result => panic!(r#"expected Ok("first line"), but it returned {result:?}"#),
+	}
+
+	match lines.parse() {
+		Ok(k8s_openapi::ReadPodLogLineResponse::Ok(k8s_openapi::PodLogLine { timestamp: None, text, truncated: false })) if text == "second line" => (),
+		result => panic!(r#"expected Ok("second line"), but it returned {result:?}"#),
+	}
+
+	// Remaining bytes are a partial char
+	assert_eq!(&**lines.response_body(), b"\xe4\xb8");
+	match lines.parse() {
+		Err(k8s_openapi::ResponseError::NeedMoreData) => (),
+		result => panic!("expected partial char to return Err(NeedMoreData), but it returned {result:?}"),
+	}
+
+	lines.append_slice(b"\x96\n");
+
+	match lines.parse() {
+		Ok(k8s_openapi::ReadPodLogLineResponse::Ok(k8s_openapi::PodLogLine { timestamp: None, text, truncated: false })) if text == "\u{4e16}" => (),
+		result => panic!(r#"expected Ok("\u{{4e16}}"), but it returned {result:?}"#),
+	}
+
+	assert!(lines.response_body().is_empty());
+
+	lines.append_slice(b"\xff\n");
+
+	match lines.parse() {
+		Err(k8s_openapi::ResponseError::Utf8(err)) if err.valid_up_to() == 0 && err.error_len() == Some(1) => (),
+		result => panic!("expected invalid line to return Err(Utf8(0, Some(1))), but it returned {result:?}"),
+	}
+}
+
+#[test]
+fn lines_with_timestamps() {
+	let mut lines: k8s_openapi::PodLogLineStream<true, 8> = k8s_openapi::PodLogLineStream::new(k8s_openapi::ResponseBody::new(reqwest::StatusCode::OK));
+
+	lines.append_slice(b"2023-01-02T03:04");
+
+	// Timestamp is incomplete
+	match lines.parse() {
+		Err(k8s_openapi::ResponseError::NeedMoreData) => (),
+		result => panic!("expected incomplete timestamp to return Err(NeedMoreData), but it returned {result:?}"),
+	}
+
+	lines.append_slice(b":05.123456789Z 2023-01-01 hello\n");
+
+	let expected_timestamp =
+		k8s_openapi::chrono::DateTime::parse_from_rfc3339("2023-01-02T03:04:05.123456789Z")
+		.expect("couldn't parse expected timestamp")
+		.with_timezone(&k8s_openapi::chrono::Utc);
+
+	// Line is longer than the maximum line length. The timestamp prefix does not count towards the length.
+	match lines.parse() {
+		Ok(k8s_openapi::ReadPodLogLineResponse::Ok(k8s_openapi::PodLogLine { timestamp: Some(timestamp), text, truncated: true }))
+			if timestamp == expected_timestamp && text == "2023-01-" => (),
+		result => panic!(r#"expected truncated Ok("2023-01-"), but it returned {result:?}"#),
+	}
+
+	// Continuation of the line has no timestamp
+	match lines.parse() {
+		Ok(k8s_openapi::ReadPodLogLineResponse::Ok(k8s_openapi::PodLogLine { timestamp: None, text, truncated: false })) if text == "01 hello" => (),
+		result => panic!(r#"expected Ok("01 hello"), but it returned {result:?}"#),
+	}
+
+	assert!(lines.response_body().is_empty());
+
+	// Line is cut at a char boundary
+	lines.append_slice(b"2023-01-02T03:04:06+01:00 abcdefg\xe4\xb8\x96\n");
+
+	match lines.parse() {
+		Ok(k8s_openapi::ReadPodLogLineResponse::Ok(k8s_openapi::PodLogLine { timestamp: Some(_), text, truncated: true })) if text == "abcdefg" => (),
+		result => panic!(r#"expected truncated Ok("abcdefg"), but it returned {result:?}"#),
+	}
+
+	match lines.parse() {
+		Ok(k8s_openapi::ReadPodLogLineResponse::Ok(k8s_openapi::PodLogLine { timestamp: None, text, truncated: false })) if text == "\u{4e16}" => (),
+		result => panic!(r#"expected Ok("\u{{4e16}}"), but it returned {result:?}"#),
+	}
+
+	// Continuation of a truncated line that starts with something that looks like a timestamp
+	lines.append_slice(b"2023-01-02T03:04:07Z abcdefgh2023-01-02T03:04:08Z x\n");
+
+	match lines.parse() {
+		Ok(k8s_openapi::ReadPodLogLineResponse::Ok(k8s_openapi::PodLogLine { timestamp: Some(_), text, truncated: true })) if text == "abcdefgh" => (),
+		result => panic!(r#"expected truncated Ok("abcdefgh"), but it returned {result:?}"#),
+	}
+
+	for expected_text in ["2023-01-", "02T03:04"] {
+		match lines.parse() {
+			Ok(k8s_openapi::ReadPodLogLineResponse::Ok(k8s_openapi::PodLogLine { timestamp: None, text, truncated: true })) if text == expected_text => (),
+			result => panic!(r#"expected truncated Ok({expected_text:?}), but it returned {result:?}"#),
+		}
+	}
+
+	match lines.parse() {
+		Ok(k8s_openapi::ReadPodLogLineResponse::Ok(k8s_openapi::PodLogLine { timestamp: None, text, truncated: false })) if text == ":08Z x" => (),
+		result => panic!(r#"expected Ok(":08Z x"), but it returned {result:?}"#),
+	}
+
+	assert!(lines.response_body().is_empty());
+
+	// Line of exactly the maximum length that ends with a \r\n
+	lines.append_slice(b"2023-01-02T03:04:09Z abcdefgh\r");
+
+	match lines.parse() {
+		Err(k8s_openapi::ResponseError::NeedMoreData) => (),
+		result => panic!("expected line ending with \\r to return Err(NeedMoreData), but it returned {result:?}"),
+	}
+
+	lines.append_slice(b"\n2023-01-02T03:04:10Z next\n");
+
+	match lines.parse() {
+		Ok(k8s_openapi::ReadPodLogLineResponse::Ok(k8s_openapi::PodLogLine { timestamp: Some(_), text, truncated: false })) if text == "abcdefgh" => (),
+		result => panic!(r#"expected Ok("abcdefgh"), but it returned {result:?}"#),
+	}
+
+	match lines.parse() {
+		Ok(k8s_openapi::ReadPodLogLineResponse::Ok(k8s_openapi::PodLogLine { timestamp: Some(_), text, truncated: false })) if text == "next" => (),
+		result => panic!(r#"expected Ok("next"), but it returned {result:?}"#),
+	}
+
+	assert!(lines.response_body().is_empty());
+}
+
+#[test]
+fn lines_error() {
+	let mut lines: k8s_openapi::PodLogLineStream<false> = k8s_openapi::PodLogLineStream::new(k8s_openapi::ResponseBody::new(reqwest::StatusCode::NOT_FOUND));
+
+	lines.append_slice(br#"{"kind":"Status","apiVersion":"v1","status":"Failure","reason":"NotFound","code":404}"#);
+
+	match lines.parse() {
+		Ok(k8s_openapi::ReadPodLogLineResponse::Other(Ok(Some(value)))) if value["reason"] == "NotFound" => (),
+		result => panic!("expected Other(Ok(Some(NotFound))), but it returned {result:?}"),
+	}
+}
@@ -0,0 +1,52 @@
+#[test]
+fn create() {
+	use k8s_openapi::api::core::v1 as api;
+
+	let (request, _) =
+		api::Pod::create("kube-system", &Default::default(), k8s_openapi::CreateOptional {
+			dry_run: Some(&[k8s_openapi::DryRun::All]),
+			field_manager: Some("manager"),
+			..Default::default()
+		})
+		.expect("couldn't create create request");
+	assert_eq!(request.uri(), "/api/v1/namespaces/kube-system/pods?&dryRun=All&fieldManager=manager");
+
+	k8s_openapi::k8s_if_ge_1_23! {
+		let (request, _) =
+			api::Pod::create("kube-system", &Default::default(), k8s_openapi::CreateOptional {
+				field_validation: Some(k8s_openapi::FieldValidation::Strict),
+				..Default::default()
+			})
+			.expect("couldn't create create request");
+		assert_eq!(request.uri(), "/api/v1/namespaces/kube-system/pods?&fieldValidation=Strict");
+	}
+}
+
+#[test]
+fn delete() {
+	use k8s_openapi::api::core::v1 as api;
+
+	let (request, _) =
+		api::Pod::delete("name", "kube-system", k8s_openapi::DeleteOptional {
+			dry_run: Some(&[k8s_openapi::DryRun::All]),
+			propagation_policy: Some(k8s_openapi::PropagationPolicy::Foreground),
+			..Default::default()
+		})
+		.expect("couldn't create delete request");
+	assert_eq!(request.uri(), "/api/v1/namespaces/kube-system/pods/name");
+	assert_eq!(request.body(), br#"{"dryRun":["All"],"propagationPolicy":"Foreground"}"#);
+}
+
+#[test]
+fn list() {
+	use k8s_openapi::api::core::v1 as api;
+
+	let (request, _) =
+		api::Pod::list("kube-system", k8s_openapi::ListOptional {
+			resource_version: Some("1234"),
+			resource_version_match: Some(k8s_openapi::ResourceVersionMatch::NotOlderThan),
+			..Default::default()
+		})
+		.expect("couldn't create list request");
+	assert_eq!(request.uri(), "/api/v1/namespaces/kube-system/pods?&resourceVersion=1234&resourceVersionMatch=NotOlderThan");
+}
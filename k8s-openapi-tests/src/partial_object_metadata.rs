@@ -0,0 +1,69 @@
+#[test]
+fn list() {
+	use k8s_openapi::api::core::v1 as api;
+
+	let (request, response_body) =
+		k8s_openapi::partial_object_metadata_list_request(api::Secret::list("kube-system", Default::default()).expect("couldn't create list request"));
+	assert_eq!(request.uri(), "/api/v1/namespaces/kube-system/secrets?");
+	assert_eq!(request.headers()[k8s_openapi::http::header::ACCEPT], "application/json;as=PartialObjectMetadataList;v=v1;g=meta.k8s.io");
+
+	let mut response_body = response_body(k8s_openapi::http::StatusCode::OK);
+	response_body.append_slice(br#"{
+		"kind": "PartialObjectMetadataList",
+		"apiVersion": "meta.k8s.io/v1",
+		"metadata": { "resourceVersion": "1234" },
+		"items": [
+			{
+				"kind": "PartialObjectMetadata",
+				"apiVersion": "meta.k8s.io/v1",
+				"metadata": { "name": "bootstrap-token-abcdef", "namespace": "kube-system", "labels": { "foo": "bar" } }
+			}
+		]
+	}"#);
+
+	let list = match response_body.parse() {
+		Ok(k8s_openapi::ListResponse::Ok(list)) => list,
+		result => panic!("expected Ok(List), but it returned {result:?}"),
+	};
+	assert_eq!(list.metadata.resource_version.as_deref(), Some("1234"));
+	assert_eq!(list.items.len(), 1);
+	assert_eq!(list.items[0].metadata.name.as_deref(), Some("bootstrap-token-abcdef"));
+	assert_eq!(list.items[0].metadata.labels.as_ref().and_then(|labels| labels.get("foo")).map(String::as_str), Some("bar"));
+}
+
+#[test]
+fn watch() {
+	use k8s_openapi::api::core::v1 as api;
+	use k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
+
+	let (request, response_body) =
+		k8s_openapi::partial_object_metadata_watch_request(api::ConfigMap::watch("kube-system", Default::default()).expect("couldn't create watch request"));
+	assert_eq!(request.uri(), "/api/v1/namespaces/kube-system/configmaps?&watch=true");
+	assert_eq!(request.headers()[k8s_openapi::http::header::ACCEPT], "application/json;as=PartialObjectMetadata;v=v1;g=meta.k8s.io");
+
+	let mut response_body = response_body(k8s_openapi::http::StatusCode::OK);
+	response_body.append_slice(br#"{"type":"ADDED","object":{"kind":"PartialObjectMetadata","apiVersion":"meta.k8s.io/v1","metadata":{"name":"coredns"}}}"#);
+
+	match response_body.parse() {
+		Ok(k8s_openapi::WatchResponse::Ok(meta::WatchEvent::Added(object))) if object.metadata.name.as_deref() == Some("coredns") => (),
+		result => panic!("expected Ok(Added(coredns)), but it returned {result:?}"),
+	}
+}
+
+#[test]
+fn read() {
+	use k8s_openapi::api::core::v1 as api;
+
+	let (request, response_body) =
+		k8s_openapi::partial_object_metadata_read_request(api::ConfigMap::read("coredns", "kube-system").expect("couldn't create read request"));
+	assert_eq!(request.uri(), "/api/v1/namespaces/kube-system/configmaps/coredns");
+	assert_eq!(request.headers()[k8s_openapi::http::header::ACCEPT], "application/json;as=PartialObjectMetadata;v=v1;g=meta.k8s.io");
+
+	let mut response_body = response_body(k8s_openapi::http::StatusCode::OK);
+	response_body.append_slice(br#"{"kind":"PartialObjectMetadata","apiVersion":"meta.k8s.io/v1","metadata":{"name":"coredns"}}"#);
+
+	match response_body.parse() {
+		Ok(k8s_openapi::PartialObjectMetadataResponse::Ok(object)) if object.metadata.name.as_deref() == Some("coredns") => (),
+		result => panic!("expected Ok(coredns), but it returned {result:?}"),
+	}
+}
@@ -0,0 +1,80 @@
+#[test]
+fn request_ports() {
+	use k8s_openapi::api::core::v1 as api;
+
+	let codec = k8s_openapi::PortForwardCodec::new(&[8080, 9090]);
+
+	let mut request = api::Pod::connect_get_portforward("name", "namespace", api::ConnectGetPodPortforwardOptional {
+		ports: Some(1234),
+	}).expect("couldn't create request");
+	codec.set_request_ports(&mut request).expect("couldn't set request ports");
+	assert_eq!(request.uri(), "/api/v1/namespaces/namespace/pods/name/portforward?ports=8080&ports=9090");
+}
+
+#[test]
+fn mux_demux() {
+	let mut codec = k8s_openapi::PortForwardCodec::new(&[8080, 9090]);
+
+	assert_eq!(codec.encode(0, b"hello").as_deref(), Some(&b"\x00hello"[..]));
+	assert_eq!(codec.encode(1, b"world").as_deref(), Some(&b"\x02world"[..]));
+	assert_eq!(codec.encode(2, b"!"), None);
+
+	// First message on each channel is the port number
+	match codec.decode(b"\x00\x90\x1f") {
+		Ok(k8s_openapi::PortForwardFrame::Data { port_index: 0, data: b"" }) => (),
+		result => panic!("expected Ok(Data(0, [])), but it returned {result:?}"),
+	}
+
+	match codec.decode(b"\x01\x90\x1f") {
+		Ok(k8s_openapi::PortForwardFrame::Error { port_index: 0, message }) if message.is_empty() => (),
+		result => panic!("expected Ok(Error(0, \"\")), but it returned {result:?}"),
+	}
+
+	// Subsequent messages are all data
+	match codec.decode(b"\x00\x90\x1f") {
+		Ok(k8s_openapi::PortForwardFrame::Data { port_index: 0, data: b"\x90\x1f" }) => (),
+		result => panic!("expected Ok(Data(0, [0x90, 0x1f])), but it returned {result:?}"),
+	}
+
+	match codec.decode(b"\x01connection refused") {
+		Ok(k8s_openapi::PortForwardFrame::Error { port_index: 0, message }) if message == "connection refused" => (),
+		result => panic!("expected Ok(Error(0, \"connection refused\")), but it returned {result:?}"),
+	}
+
+	// Port number header of the second port is followed by data in the same message
+	match codec.decode(b"\x02\x82\x23data") {
+		Ok(k8s_openapi::PortForwardFrame::Data { port_index: 1, data: b"data" }) => (),
+		result => panic!("expected Ok(Data(1, \"data\")), but it returned {result:?}"),
+	}
+
+	match codec.decode(b"\x03\x90\x1f") {
+		Err(k8s_openapi::PortForwardFrameError::PortMismatch { expected: 9090, actual: 8080 }) => (),
+		result => panic!("expected Err(PortMismatch(9090, 8080)), but it returned {result:?}"),
+	}
+
+	match codec.decode(b"\x03\x82") {
+		Err(k8s_openapi::PortForwardFrameError::MissingPortHeader(3)) => (),
+		result => panic!("expected Err(MissingPortHeader(3)), but it returned {result:?}"),
+	}
+
+	match codec.decode(b"\x04\x00\x00") {
+		Err(k8s_openapi::PortForwardFrameError::UnknownChannel(4)) => (),
+		result => panic!("expected Err(UnknownChannel(4)), but it returned {result:?}"),
+	}
+
+	match codec.decode(b"") {
+		Err(k8s_openapi::PortForwardFrameError::Empty) => (),
+		result => panic!("expected Err(Empty), but it returned {result:?}"),
+	}
+}
+
+#[test]
+fn spdy_stream_headers() {
+	let codec = k8s_openapi::PortForwardCodec::new(&[8080, 9090]);
+
+	assert_eq!(
+		codec.spdy_stream_headers(1, k8s_openapi::PortForwardStreamType::Error, 5),
+		Some([("streamType", "error".to_owned()), ("port", "9090".to_owned()), ("requestID", "5".to_owned())]),
+	);
+	assert_eq!(codec.spdy_stream_headers(2, k8s_openapi::PortForwardStreamType::Data, 5), None);
+}
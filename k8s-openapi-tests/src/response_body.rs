@@ -0,0 +1,69 @@
+// `{"apiVersion":"v1","kind":"PodList","metadata":{"resourceVersion":"1"},"items":[{"metadata":{"name":"foo"}}]}` compressed with gzip
+const GZIP_POD_LIST: &[u8] = &[
+	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0x55, 0x8b, 0xb1, 0x0a, 0x80, 0x30,
+	0x0c, 0x05, 0xff, 0xe5, 0xcd, 0x5d, 0xba, 0xf6, 0x1b, 0x1c, 0x9c, 0x5c, 0xc4, 0x21, 0xd8, 0x08,
+	0x41, 0xda, 0x48, 0x5b, 0x5d, 0x4a, 0xfe, 0xdd, 0x6e, 0xe2, 0x76, 0x1c, 0x77, 0x1d, 0x74, 0xc9,
+	0xc2, 0xa5, 0x8a, 0x66, 0x04, 0x3c, 0x1e, 0x0e, 0xa7, 0xe4, 0x38, 0x78, 0xd6, 0x38, 0x49, 0x6d,
+	0x43, 0x24, 0x6e, 0x14, 0xa9, 0x11, 0x42, 0x47, 0xe1, 0xaa, 0x77, 0xd9, 0xf9, 0x7b, 0x3c, 0xcc,
+	0x41, 0x1a, 0xa7, 0x8a, 0xb0, 0xf6, 0x5f, 0x9c, 0x29, 0xf1, 0x28, 0x0e, 0x55, 0x98, 0x6d, 0xf6,
+	0x02, 0x2e, 0x29, 0xf6, 0xba, 0x6d, 0x00, 0x00, 0x00,
+];
+
+fn new_response_body(content_type: &str, content_encoding: Option<&str>) -> k8s_openapi::ResponseBody<k8s_openapi::ListResponse<k8s_openapi::api::core::v1::Pod>> {
+	let mut response = k8s_openapi::http::Response::builder()
+		.status(k8s_openapi::http::StatusCode::OK)
+		.header(k8s_openapi::http::header::CONTENT_TYPE, content_type);
+	if let Some(content_encoding) = content_encoding {
+		response = response.header(k8s_openapi::http::header::CONTENT_ENCODING, content_encoding);
+	}
+	let (parts, ()) = response.body(()).expect("couldn't build response").into_parts();
+	k8s_openapi::ResponseBody::from_parts(&parts)
+}
+
+#[test]
+fn gzip() {
+	let mut response_body = new_response_body("application/json", Some("gzip"));
+
+	// Append the compressed response in small chunks to exercise the streaming decoder.
+	let mut chunks = GZIP_POD_LIST.chunks(7);
+	let list = loop {
+		match response_body.parse() {
+			Ok(k8s_openapi::ListResponse::Ok(list)) => break list,
+			Err(k8s_openapi::ResponseError::NeedMoreData) => {
+				let chunk = chunks.next().expect("response body is incomplete");
+				response_body.append_slice(chunk);
+			},
+			result => panic!("unexpected result: {result:?}"),
+		}
+	};
+	assert_eq!(list.metadata.resource_version.as_deref(), Some("1"));
+	assert_eq!(list.items.len(), 1);
+	assert_eq!(list.items[0].metadata.name.as_deref(), Some("foo"));
+	assert!(response_body.is_empty());
+
+	// Corrupt data
+	let mut response_body = new_response_body("application/json", Some("gzip"));
+	response_body.append_slice(b"this is not a gzip-encoded response");
+	assert!(matches!(response_body.parse(), Err(k8s_openapi::ResponseError::Decode(_))));
+}
+
+#[test]
+fn identity() {
+	let mut response_body = new_response_body("application/json; charset=utf-8", Some("identity"));
+	response_body.append_slice(br#"{"apiVersion":"v1","kind":"PodList","metadata":{},"items":[]}"#);
+	match response_body.parse() {
+		Ok(k8s_openapi::ListResponse::Ok(list)) => assert!(list.items.is_empty()),
+		result => panic!("unexpected result: {result:?}"),
+	}
+}
+
+#[test]
+fn unsupported() {
+	let mut response_body = new_response_body("application/json", Some("br"));
+	response_body.append_slice(b"\x00");
+	assert!(matches!(response_body.parse(), Err(k8s_openapi::ResponseError::Decode(_))));
+
+	let mut response_body = new_response_body("application/vnd.kubernetes.protobuf", None);
+	response_body.append_slice(b"k8s\x00");
+	assert!(matches!(response_body.parse(), Err(k8s_openapi::ResponseError::Decode(_))));
+}
@@ -0,0 +1,69 @@
+#[test]
+fn request() {
+	use k8s_openapi::api::core::v1 as api;
+
+	let (request, _) =
+		k8s_openapi::table_request(
+			api::Pod::list("kube-system", k8s_openapi::ListOptional {
+				limit: Some(5),
+				..Default::default()
+			}).expect("couldn't create list request"),
+			k8s_openapi::IncludeObjectPolicy::None,
+		)
+		.expect("couldn't create table request");
+	assert_eq!(request.uri(), "/api/v1/namespaces/kube-system/pods?limit=5&includeObject=None");
+	assert_eq!(request.headers()[k8s_openapi::http::header::ACCEPT], "application/json;as=Table;v=v1;g=meta.k8s.io");
+
+	let (request, _) =
+		k8s_openapi::table_request(
+			api::Pod::read("name", "kube-system").expect("couldn't create read request"),
+			Default::default(),
+		)
+		.expect("couldn't create table request");
+	assert_eq!(request.uri(), "/api/v1/namespaces/kube-system/pods/name?includeObject=Metadata");
+	assert_eq!(request.headers()[k8s_openapi::http::header::ACCEPT], "application/json;as=Table;v=v1;g=meta.k8s.io");
+}
+
+#[test]
+fn response() {
+	use k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
+
+	let mut response_body: k8s_openapi::ResponseBody<k8s_openapi::TableResponse> =
+		k8s_openapi::ResponseBody::new(k8s_openapi::http::StatusCode::OK);
+
+	response_body.append_slice(br#"{
+		"kind": "Table",
+		"apiVersion": "meta.k8s.io/v1",
+		"metadata": { "resourceVersion": "1234" },
+		"columnDefinitions": [
+			{ "name": "Name", "type": "string", "format": "name", "description": "Name must be unique within a namespace.", "priority": 0 },
+			{ "name": "Ready", "type": "string", "format": "", "description": "The aggregate readiness state of this pod for accepting traffic.", "priority": 0 }
+		],
+		"rows": [
+			{
+				"cells": ["kube-apiserver", "1/1"],
+				"object": {
+					"kind": "PartialObjectMetadata",
+					"apiVersion": "meta.k8s.io/v1",
+					"metadata": { "name": "kube-apiserver", "namespace": "kube-system" }
+				}
+			}
+		]
+	}"#);
+
+	let table = match response_body.parse() {
+		Ok(k8s_openapi::TableResponse::Ok(table)) => table,
+		result => panic!("expected Ok(Table), but it returned {result:?}"),
+	};
+
+	assert_eq!(table.metadata.resource_version.as_deref(), Some("1234"));
+	assert_eq!(table.column_definitions.iter().map(|column| &*column.name).collect::<Vec<_>>(), ["Name", "Ready"]);
+	assert_eq!(table.rows.len(), 1);
+	let row: &meta::TableRow = &table.rows[0];
+	assert_eq!(row.cells, [k8s_openapi::serde_json::json!("kube-apiserver"), k8s_openapi::serde_json::json!("1/1")]);
+	assert_eq!(row.conditions, None);
+	assert_eq!(
+		row.object.as_ref().and_then(|object| object.0.pointer("/metadata/name")),
+		Some(&k8s_openapi::serde_json::json!("kube-apiserver")),
+	);
+}
@@ -0,0 +1,105 @@
+#[test]
+fn kubernetes_version() {
+	fn info(major: &str, minor: &str, git_version: &str) -> k8s_openapi::apimachinery::pkg::version::Info {
+		k8s_openapi::apimachinery::pkg::version::Info {
+			major: major.to_owned(),
+			minor: minor.to_owned(),
+			git_version: git_version.to_owned(),
+			..Default::default()
+		}
+	}
+
+	for (info, expected) in [
+		(info("1", "26", "v1.26.1"), Some((1, 26))),
+		(info("1", "26+", "v1.26.3-gke.1000"), Some((1, 26))),
+		(info("", "", "v1.25.0-eks-1234"), Some((1, 25))),
+		(info("", "", ""), None),
+	] {
+		let actual = k8s_openapi::KubernetesVersion::from_info(&info);
+		assert_eq!(actual.map(|version| (version.major, version.minor)), expected, "{info:?}");
+	}
+
+	let server_version = k8s_openapi::KubernetesVersion { major: 1, minor: 19 };
+	assert!(server_version < k8s_openapi::KubernetesVersion::ENABLED);
+	assert_eq!(server_version.to_string(), "1.19");
+
+	assert_eq!(k8s_openapi::KubernetesVersion::ENABLED.major, 1);
+	k8s_openapi::k8s_if_1_26! {
+		assert_eq!(k8s_openapi::KubernetesVersion::ENABLED.minor, 26);
+	}
+}
+
+#[test]
+fn resource_operations() {
+	let find = |operation_id| k8s_openapi::RESOURCE_OPERATIONS.iter().find(|resource_operation| resource_operation.operation_id == operation_id).unwrap();
+
+	assert_eq!(*find("listCoreV1NamespacedPod"), k8s_openapi::ResourceOperation {
+		group: "",
+		version: "v1",
+		resource: "pods",
+		verb: "list",
+		operation_id: "listCoreV1NamespacedPod",
+	});
+
+	assert_eq!(*find("connectCoreV1PostNamespacedPodExec"), k8s_openapi::ResourceOperation {
+		group: "",
+		version: "v1",
+		resource: "pods/exec",
+		verb: "create",
+		operation_id: "connectCoreV1PostNamespacedPodExec",
+	});
+
+	assert_eq!(*find("replaceAppsV1NamespacedDeploymentScale"), k8s_openapi::ResourceOperation {
+		group: "apps",
+		version: "v1",
+		resource: "deployments/scale",
+		verb: "update",
+		operation_id: "replaceAppsV1NamespacedDeploymentScale",
+	});
+}
+
+#[test]
+fn unserved_operations() {
+	fn api_resource(name: &str, verbs: &[&str]) -> k8s_openapi::apimachinery::pkg::apis::meta::v1::APIResource {
+		k8s_openapi::apimachinery::pkg::apis::meta::v1::APIResource {
+			name: name.to_owned(),
+			verbs: verbs.iter().copied().map(ToOwned::to_owned).collect(),
+			..Default::default()
+		}
+	}
+
+	let api_resource_lists = [
+		k8s_openapi::apimachinery::pkg::apis::meta::v1::APIResourceList {
+			group_version: "v1".to_owned(),
+			resources: vec![
+				api_resource("pods", &["create", "delete", "deletecollection", "get", "list", "patch", "update", "watch"]),
+				api_resource("pods/log", &["get"]),
+			],
+		},
+		k8s_openapi::apimachinery::pkg::apis::meta::v1::APIResourceList {
+			group_version: "apps/v1".to_owned(),
+			resources: vec![
+				api_resource("deployments", &["get", "list"]),
+			],
+		},
+	];
+
+	let unserved_operations = k8s_openapi::unserved_operations(&api_resource_lists);
+	let is_unserved = |operation_id| unserved_operations.iter().any(|resource_operation| resource_operation.operation_id == operation_id);
+
+	assert!(!is_unserved("createCoreV1NamespacedPod"));
+	assert!(!is_unserved("listCoreV1PodForAllNamespaces"));
+	assert!(!is_unserved("readCoreV1NamespacedPodLog"));
+	assert!(is_unserved("connectCoreV1PostNamespacedPodExec"));
+	assert!(is_unserved("readCoreV1NamespacedPodStatus"));
+
+	assert!(!is_unserved("readAppsV1NamespacedDeployment"));
+	assert!(is_unserved("createAppsV1NamespacedDeployment"));
+	assert!(is_unserved("readAppsV1NamespacedDeploymentScale"));
+
+	// No APIResourceList for batch/v1
+	assert!(is_unserved("listBatchV1NamespacedJob"));
+
+	// Everything is unserved if there is no discovery information at all
+	assert_eq!(k8s_openapi::unserved_operations(&[]).len(), k8s_openapi::RESOURCE_OPERATIONS.len());
+}
@@ -0,0 +1,67 @@
+#[test]
+fn from_header_value() {
+	assert_eq!(
+		k8s_openapi::Warning::from_header_value(r#"299 - "policy/v1beta1 PodSecurityPolicy is deprecated in v1.21+, unavailable in v1.25+""#),
+		[k8s_openapi::Warning {
+			code: 299,
+			agent: "-".to_owned(),
+			text: "policy/v1beta1 PodSecurityPolicy is deprecated in v1.21+, unavailable in v1.25+".to_owned(),
+		}],
+	);
+
+	// Multiple warnings, escaped quotes and an optional date
+	assert_eq!(
+		k8s_openapi::Warning::from_header_value(
+			r#"299 - "unknown field \"spec.foo\"", 299 example.com:443 "second" "Sat, 25 Aug 2012 23:34:45 GMT""#,
+		),
+		[
+			k8s_openapi::Warning {
+				code: 299,
+				agent: "-".to_owned(),
+				text: r#"unknown field "spec.foo""#.to_owned(),
+			},
+			k8s_openapi::Warning {
+				code: 299,
+				agent: "example.com:443".to_owned(),
+				text: "second".to_owned(),
+			},
+		],
+	);
+
+	// Parsing stops at the first malformed warning
+	assert_eq!(
+		k8s_openapi::Warning::from_header_value(r#"299 - "first", 299 - unquoted, 299 - "third""#),
+		[k8s_openapi::Warning {
+			code: 299,
+			agent: "-".to_owned(),
+			text: "first".to_owned(),
+		}],
+	);
+	assert_eq!(k8s_openapi::Warning::from_header_value(r#"29 - "text""#), []);
+	assert_eq!(k8s_openapi::Warning::from_header_value(r#"299 - "unterminated"#), []);
+}
+
+#[test]
+fn response_body_from_parts() {
+	use k8s_openapi::api::core::v1 as api;
+
+	let (parts, ()) =
+		k8s_openapi::http::Response::builder()
+		.status(k8s_openapi::http::StatusCode::OK)
+		.header(k8s_openapi::http::header::WARNING, r#"299 - "first""#)
+		.header(k8s_openapi::http::header::WARNING, r#"299 - "second""#)
+		.body(())
+		.expect("couldn't build response")
+		.into_parts();
+
+	let response_body: k8s_openapi::ResponseBody<k8s_openapi::ListResponse<api::Pod>> = k8s_openapi::ResponseBody::from_parts(&parts);
+	assert_eq!(response_body.status_code, k8s_openapi::http::StatusCode::OK);
+	assert_eq!(
+		response_body.warnings.iter().map(|warning| &*warning.text).collect::<Vec<_>>(),
+		["first", "second"],
+	);
+
+	let (_, response_body) = api::Pod::list("kube-system", Default::default()).expect("couldn't create list request");
+	let response_body = response_body(k8s_openapi::http::StatusCode::OK);
+	assert!(response_body.warnings.is_empty());
+}
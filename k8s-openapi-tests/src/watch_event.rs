@@ -107,8 +107,10 @@ fn bookmark_events() {
 			watch_response => panic!("hard-coded test case did not deserialize as expected: {watch_response:?}"),
 		};
 		assert_eq!(watch_event, meta::WatchEvent::Bookmark {
+			annotations: Default::default(),
 			resource_version: "123".to_owned(),
 		});
+		assert!(!watch_event.is_initial_events_end());
 	}
 
 	for test_case in FAILURE_TEST_CASES {
@@ -124,3 +126,59 @@ fn bookmark_events() {
 		}
 	}
 }
+
+#[test]
+fn initial_events_end_bookmark() {
+	use k8s_openapi::api::core::v1 as api;
+	use k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
+
+	let (request, _) =
+		api::Pod::watch("kube-system", k8s_openapi::WatchOptional {
+			allow_watch_bookmarks: Some(true),
+			resource_version_match: Some(k8s_openapi::ResourceVersionMatch::NotOlderThan),
+			send_initial_events: Some(true),
+			..Default::default()
+		})
+		.expect("couldn't create watch request");
+	assert_eq!(
+		request.uri(),
+		"/api/v1/namespaces/kube-system/pods?&allowWatchBookmarks=true&resourceVersionMatch=NotOlderThan&sendInitialEvents=true&watch=true",
+	);
+
+	let test_case: &[u8] = br#"{
+		"type": "BOOKMARK",
+		"object": {
+			"kind": "Pod",
+			"apiVersion": "v1",
+			"metadata": {
+				"resourceVersion": "123",
+				"annotations": {
+					"k8s.io/initial-events-end": "true"
+				}
+			}
+		}
+	}"#;
+
+	let watch_event = match k8s_openapi::Response::try_from_parts(k8s_openapi::http::StatusCode::OK, test_case) {
+		Ok((k8s_openapi::WatchResponse::<api::Pod>::Ok(watch_event), read)) if read == test_case.len() => watch_event,
+		watch_response => panic!("hard-coded test case did not deserialize as expected: {watch_response:?}"),
+	};
+	assert_eq!(watch_event, meta::WatchEvent::Bookmark {
+		annotations: [("k8s.io/initial-events-end".to_owned(), "true".to_owned())].into(),
+		resource_version: "123".to_owned(),
+	});
+	assert!(watch_event.is_initial_events_end());
+
+	let serialized = k8s_openapi::serde_json::to_value(&watch_event).expect("couldn't serialize watch event");
+	assert_eq!(serialized, k8s_openapi::serde_json::json!({
+		"type": "BOOKMARK",
+		"object": {
+			"metadata": {
+				"annotations": {
+					"k8s.io/initial-events-end": "true",
+				},
+				"resourceVersion": "123",
+			},
+		},
+	}));
+}
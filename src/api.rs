@@ -53,12 +53,25 @@ pub trait Response: Sized {
 ///
 /// You do not *have* to use this type to parse the response, say if you want to manage your own byte buffers. You can use
 /// `<T as Response>::try_from_parts` directly instead.
+///
+/// If the `ResponseBody` is constructed with [`ResponseBody::from_parts`], it also holds the warnings that the API server returned
+/// in the `Warning` headers of the response, such as for the use of a deprecated API. It also decodes the response according to its
+/// `Content-Encoding` header, so that a gzip-encoded response (requested with `Accept-Encoding: gzip`) can be appended as-is.
+/// This requires the `gzip` feature.
 pub struct ResponseBody<T> {
     /// The HTTP status code of the response.
     pub status_code: http::StatusCode,
 
+    /// The warnings in the `Warning` headers of the response.
+    ///
+    /// This is only populated by [`ResponseBody::from_parts`]. If the `ResponseBody` was constructed with [`ResponseBody::new`],
+    /// such as by the constructor returned by an API function, set it from the headers of the response with [`crate::Warning::from_headers`].
+    pub warnings: Vec<crate::Warning>,
+
     buf: bytes::BytesMut,
 
+    decoder: BodyDecoder,
+
     _response: std::marker::PhantomData<fn() -> T>,
 }
 
@@ -67,18 +80,40 @@ impl<T> ResponseBody<T> where T: Response {
     pub fn new(status_code: http::StatusCode) -> Self {
         ResponseBody {
             status_code,
+            warnings: vec![],
             buf: Default::default(),
+            decoder: BodyDecoder::Identity,
+            _response: Default::default(),
+        }
+    }
+
+    /// Construct a value for a response with the specified HTTP status code and headers.
+    ///
+    /// The `Warning` headers are parsed into [`ResponseBody::warnings`].
+    ///
+    /// The data appended with [`ResponseBody::append_slice`] is decoded according to the `Content-Encoding` header before it is parsed.
+    /// `gzip` is supported if the `gzip` feature is enabled. If the response uses an unsupported `Content-Encoding`, or a `Content-Type`
+    /// that the response types cannot parse, such as `application/vnd.kubernetes.protobuf` or `application/yaml`,
+    /// [`ResponseBody::parse`] returns `Err(ResponseError::Decode)`.
+    pub fn from_parts(parts: &http::response::Parts) -> Self {
+        ResponseBody {
+            status_code: parts.status,
+            warnings: crate::Warning::from_headers(&parts.headers),
+            buf: Default::default(),
+            decoder: BodyDecoder::new(&parts.headers),
             _response: Default::default(),
         }
     }
 
     /// Append a slice of data from the HTTP response to this buffer.
     pub fn append_slice(&mut self, buf: &[u8]) {
-        self.buf.extend_from_slice(buf);
+        self.decoder.decode(buf, &mut self.buf);
     }
 
     /// Try to parse all the data buffered so far into a response type.
     pub fn parse(&mut self) -> Result<T, ResponseError> {
+        self.decode_result()?;
+
         match T::try_from_parts(self.status_code, &self.buf) {
             Ok((result, read)) => {
                 self.advance(read);
@@ -89,6 +124,15 @@ impl<T> ResponseBody<T> where T: Response {
         }
     }
 
+    /// Returns the error from decoding the appended data, if any.
+    pub(crate) fn decode_result(&self) -> Result<(), ResponseError> {
+        if let BodyDecoder::Failed(kind, message) = &self.decoder {
+            return Err(ResponseError::Decode(std::io::Error::new(*kind, message.clone())));
+        }
+
+        Ok(())
+    }
+
     /// Drop the first `cnt` bytes of this buffer.
     ///
     /// This is useful for skipping over malformed bytes, such as invalid utf-8 sequences when parsing streaming `String` responses
@@ -102,6 +146,77 @@ impl<T> ResponseBody<T> where T: Response {
     }
 }
 
+/// Decodes the data appended to a [`ResponseBody`] according to the `Content-Encoding` of the response.
+enum BodyDecoder {
+    Identity,
+
+    #[cfg(feature = "gzip")]
+    Gzip(Box<flate2::write::GzDecoder<Vec<u8>>>),
+
+    /// The response cannot be decoded. Stores the error to be returned by [`ResponseBody::parse`].
+    Failed(std::io::ErrorKind, String),
+}
+
+impl BodyDecoder {
+    fn new(headers: &http::HeaderMap) -> Self {
+        if let Some(content_type) = headers.get(http::header::CONTENT_TYPE) {
+            let content_type = String::from_utf8_lossy(content_type.as_bytes());
+            let media_type = content_type.split(';').next().unwrap_or_default().trim();
+            if
+                media_type.eq_ignore_ascii_case("application/vnd.kubernetes.protobuf") ||
+                media_type.eq_ignore_ascii_case("application/yaml")
+            {
+                return BodyDecoder::Failed(std::io::ErrorKind::Unsupported, format!("unsupported Content-Type {content_type:?}"));
+            }
+        }
+
+        let Some(content_encoding) = headers.get(http::header::CONTENT_ENCODING) else {
+            return BodyDecoder::Identity;
+        };
+        let content_encoding = String::from_utf8_lossy(content_encoding.as_bytes());
+        let content_encoding = content_encoding.trim();
+
+        if content_encoding.is_empty() || content_encoding.eq_ignore_ascii_case("identity") {
+            BodyDecoder::Identity
+        }
+        else if content_encoding.eq_ignore_ascii_case("gzip") || content_encoding.eq_ignore_ascii_case("x-gzip") {
+            #[cfg(feature = "gzip")]
+            {
+                BodyDecoder::Gzip(Box::new(flate2::write::GzDecoder::new(vec![])))
+            }
+
+            #[cfg(not(feature = "gzip"))]
+            {
+                BodyDecoder::Failed(std::io::ErrorKind::Unsupported, "gzip-encoded responses require the `gzip` feature".to_owned())
+            }
+        }
+        else {
+            BodyDecoder::Failed(std::io::ErrorKind::Unsupported, format!("unsupported Content-Encoding {content_encoding:?}"))
+        }
+    }
+
+    fn decode(&mut self, input: &[u8], output: &mut bytes::BytesMut) {
+        match self {
+            BodyDecoder::Identity => output.extend_from_slice(input),
+
+            #[cfg(feature = "gzip")]
+            BodyDecoder::Gzip(decoder) => {
+                let result =
+                    std::io::Write::write_all(&mut **decoder, input)
+                    .and_then(|()| std::io::Write::flush(&mut **decoder));
+                let buf = decoder.get_mut();
+                output.extend_from_slice(buf);
+                buf.clear();
+                if let Err(err) = result {
+                    *self = BodyDecoder::Failed(err.kind(), err.to_string());
+                }
+            },
+
+            BodyDecoder::Failed(_, _) => (),
+        }
+    }
+}
+
 impl<T> std::ops::Deref for ResponseBody<T> {
     type Target = [u8];
 
@@ -121,6 +236,10 @@ pub enum ResponseError {
 
     /// An error while deserializing the HTTP response as a string, indicating that the response data is not UTF-8.
     Utf8(std::str::Utf8Error),
+
+    /// An error while decoding the HTTP response according to its `Content-Encoding` and `Content-Type` headers,
+    /// such as a malformed gzip-encoded response or an unsupported encoding. See [`ResponseBody::from_parts`].
+    Decode(std::io::Error),
 }
 
 impl std::fmt::Display for ResponseError {
@@ -129,6 +248,7 @@ impl std::fmt::Display for ResponseError {
             ResponseError::NeedMoreData => f.write_str("need more response data"),
             ResponseError::Json(err) => write!(f, "{err}"),
             ResponseError::Utf8(err) => write!(f, "{err}"),
+            ResponseError::Decode(err) => write!(f, "{err}"),
         }
     }
 }
@@ -139,8 +259,69 @@ impl std::error::Error for ResponseError {
             ResponseError::NeedMoreData => None,
             ResponseError::Json(err) => Some(err),
             ResponseError::Utf8(err) => Some(err),
+            ResponseError::Decode(err) => Some(err),
+        }
+    }
+}
+
+/// Parses the body of a response whose `200 OK` body is a single JSON value, like the response of a read operation.
+///
+/// The value is constructed with `ok` for `200 OK` responses, and with `other` for any other status code.
+pub(crate) fn try_from_json_parts<T, R>(
+    status_code: http::StatusCode,
+    buf: &[u8],
+    ok: impl FnOnce(T) -> R,
+    other: impl FnOnce(Result<Option<serde_json::Value>, serde_json::Error>) -> R,
+) -> Result<(R, usize), ResponseError> where T: serde::de::DeserializeOwned {
+    match status_code {
+        http::StatusCode::OK => {
+            let result = match serde_json::from_slice(buf) {
+                Ok(value) => value,
+                Err(err) if err.is_eof() => return Err(ResponseError::NeedMoreData),
+                Err(err) => return Err(ResponseError::Json(err)),
+            };
+            Ok((ok(result), buf.len()))
+        },
+        _ => {
+            let (result, read) =
+                if buf.is_empty() {
+                    (Ok(None), 0)
+                }
+                else {
+                    match serde_json::from_slice(buf) {
+                        Ok(value) => (Ok(Some(value)), buf.len()),
+                        Err(err) if err.is_eof() => return Err(ResponseError::NeedMoreData),
+                        Err(err) => (Err(err), 0),
+                    }
+                };
+            Ok((other(result), read))
+        },
+    }
+}
+
+/// Replaces all values of the query parameter with the given name in the URI of the given request with the given values.
+pub(crate) fn set_query_parameter<B>(
+    request: &mut http::Request<B>,
+    name: &str,
+    values: impl IntoIterator<Item = impl AsRef<str>>,
+) -> Result<(), RequestError> {
+    let uri = request.uri();
+
+    let url = format!("{}?", uri.path());
+    let url_len = url.len();
+    let mut query_pairs = url::form_urlencoded::Serializer::for_suffix(url, url_len);
+    for (key, value) in url::form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes()) {
+        if key != name {
+            query_pairs.append_pair(&key, &value);
         }
     }
+    for value in values {
+        query_pairs.append_pair(name, value.as_ref());
+    }
+    let uri = query_pairs.finish();
+
+    *request.uri_mut() = uri.parse().map_err(|err: http::uri::InvalidUri| RequestError::Http(err.into()))?;
+    Ok(())
 }
 
 /// Extensions to the percent-encoding crate
@@ -2,6 +2,7 @@
 ///
 /// Used in Kubernetes types whose JSON representation uses a base64-encoded string for a list of bytes.
 #[derive(Clone, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Hash))]
 pub struct ByteString(pub Vec<u8>);
 
 impl<'de> serde::Deserialize<'de> for ByteString {
@@ -0,0 +1,37 @@
+// Generated from the types of Kubernetes 1.20 through 1.26
+
+/// The fields of the types that are common to Kubernetes 1.20 through 1.26.
+///
+/// This module is only available if the enabled version is 1.20 or later.
+#[cfg(any(k8s_openapi_enabled_version="1.20", k8s_openapi_enabled_version="1.21", k8s_openapi_enabled_version="1.22", k8s_openapi_enabled_version="1.23", k8s_openapi_enabled_version="1.24", k8s_openapi_enabled_version="1.25", k8s_openapi_enabled_version="1.26"))]
+pub mod v1_20;
+
+/// The fields of the types that are common to Kubernetes 1.21 through 1.26.
+///
+/// This module is only available if the enabled version is 1.21 or later.
+#[cfg(any(k8s_openapi_enabled_version="1.21", k8s_openapi_enabled_version="1.22", k8s_openapi_enabled_version="1.23", k8s_openapi_enabled_version="1.24", k8s_openapi_enabled_version="1.25", k8s_openapi_enabled_version="1.26"))]
+pub mod v1_21;
+
+/// The fields of the types that are common to Kubernetes 1.22 through 1.26.
+///
+/// This module is only available if the enabled version is 1.22 or later.
+#[cfg(any(k8s_openapi_enabled_version="1.22", k8s_openapi_enabled_version="1.23", k8s_openapi_enabled_version="1.24", k8s_openapi_enabled_version="1.25", k8s_openapi_enabled_version="1.26"))]
+pub mod v1_22;
+
+/// The fields of the types that are common to Kubernetes 1.23 through 1.26.
+///
+/// This module is only available if the enabled version is 1.23 or later.
+#[cfg(any(k8s_openapi_enabled_version="1.23", k8s_openapi_enabled_version="1.24", k8s_openapi_enabled_version="1.25", k8s_openapi_enabled_version="1.26"))]
+pub mod v1_23;
+
+/// The fields of the types that are common to Kubernetes 1.24 through 1.26.
+///
+/// This module is only available if the enabled version is 1.24 or later.
+#[cfg(any(k8s_openapi_enabled_version="1.24", k8s_openapi_enabled_version="1.25", k8s_openapi_enabled_version="1.26"))]
+pub mod v1_24;
+
+/// The fields of the types that are common to Kubernetes 1.25 through 1.26.
+///
+/// This module is only available if the enabled version is 1.25 or later.
+#[cfg(any(k8s_openapi_enabled_version="1.25", k8s_openapi_enabled_version="1.26"))]
+pub mod v1_25;
@@ -0,0 +1,533 @@
+/// The value of the `Accept` header that requests the aggregated discovery document from the `/api` and `/apis` endpoints,
+/// which can be parsed with [`Discovery::add_aggregated_discovery`].
+///
+/// The header falls back to `application/json`, so API servers that do not support aggregated discovery respond with
+/// [`APIVersions`](crate::apimachinery::pkg::apis::meta::v1::APIVersions) and [`APIGroupList`](crate::apimachinery::pkg::apis::meta::v1::APIGroupList) instead.
+pub const AGGREGATED_DISCOVERY_ACCEPT_HEADER_VALUE: &str = "application/json;g=apidiscovery.k8s.io;v=v2beta1;as=APIGroupDiscoveryList,application/json";
+
+/// The resources served by an API server, built from its discovery documents.
+///
+/// Add the discovery documents with [`Discovery::add_api_versions`], [`Discovery::add_api_group_list`] and [`Discovery::add_api_resource_list`],
+/// or with [`Discovery::add_aggregated_discovery`] if the API server supports aggregated discovery.
+/// Then use [`Discovery::get`] to look up a resource by its group, version and kind, or [`Discovery::resolve`] to resolve a resource
+/// the way `kubectl` resolves its resource arguments.
+///
+/// Since fetching discovery documents requires one request per group version, a `Discovery` can be cached on disk
+/// with [`Discovery::write_cache_file`] and [`Discovery::read_cache_file`].
+///
+/// ```rust,ignore
+/// let discovery = match k8s_openapi::Discovery::read_cache_file(cache_path, std::time::Duration::from_mins(10))? {
+///     Some(discovery) => discovery,
+///     None => {
+///         let mut discovery = k8s_openapi::Discovery::new();
+///         discovery.add_api_versions(&get_core_api_versions()?);
+///         discovery.add_api_resource_list(&get_core_v1_api_resources()?);
+///         // ...
+///         discovery.write_cache_file(cache_path)?;
+///         discovery
+///     },
+/// };
+///
+/// let deployments = discovery.resolve("deploy").ok_or("deployments are not served")?;
+/// assert_eq!((&*deployments.group, &*deployments.kind), ("apps", "Deployment"));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Discovery {
+    resources: Vec<DiscoveredResource>,
+    preferred_versions: std::collections::BTreeMap<String, String>,
+    fetched_at: std::time::SystemTime,
+}
+
+/// A resource served by an API server, as described by its discovery documents.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DiscoveredResource {
+    /// The API group of the resource, such as `apps`. This is empty for the core API group.
+    pub group: String,
+
+    /// The API version of the resource, such as `v1`.
+    pub version: String,
+
+    /// The kind of the resource, such as `Deployment`.
+    pub kind: String,
+
+    /// The plural name of the resource, such as `deployments`. This is the name used in URLs.
+    pub plural: String,
+
+    /// The singular name of the resource, such as `deployment`.
+    pub singular: String,
+
+    /// Whether the resource is namespaced or cluster-scoped.
+    pub namespaced: bool,
+
+    /// The verbs supported by the resource, such as `get` and `list`.
+    pub verbs: Vec<String>,
+
+    /// The short names of the resource, such as `deploy`.
+    pub short_names: Vec<String>,
+
+    /// The categories the resource belongs to, such as `all`.
+    pub categories: Vec<String>,
+
+    /// The subresources of the resource, such as `scale` and `status`.
+    pub subresources: Vec<String>,
+}
+
+impl Discovery {
+    /// Constructs an empty `Discovery`. Its fetch time, which is used to determine whether a cached `Discovery` has expired, is the current time.
+    pub fn new() -> Self {
+        Discovery {
+            resources: vec![],
+            preferred_versions: Default::default(),
+            fetched_at: std::time::SystemTime::now(),
+        }
+    }
+
+    /// The time at which this `Discovery` was constructed, ie when its discovery documents were fetched.
+    pub fn fetched_at(&self) -> std::time::SystemTime {
+        self.fetched_at
+    }
+
+    /// All the resources added so far, in the order they were added.
+    pub fn resources(&self) -> &[DiscoveredResource] {
+        &self.resources
+    }
+
+    /// The version of the given API group that is preferred by the API server, if known.
+    pub fn preferred_version(&self, group: &str) -> Option<&str> {
+        self.preferred_versions.get(group).map(String::as_str)
+    }
+
+    /// Adds the response of the `/api` endpoint, which lists the versions of the core API group.
+    pub fn add_api_versions(&mut self, api_versions: &crate::apimachinery::pkg::apis::meta::v1::APIVersions) {
+        if let Some(version) = api_versions.versions.first() {
+            self.preferred_versions.insert(String::new(), version.clone());
+        }
+    }
+
+    /// Adds the response of the `/apis` endpoint, which lists the API groups and their preferred versions.
+    pub fn add_api_group_list(&mut self, api_group_list: &crate::apimachinery::pkg::apis::meta::v1::APIGroupList) {
+        for api_group in &api_group_list.groups {
+            let preferred_version = api_group.preferred_version.as_ref().or_else(|| api_group.versions.first());
+            if let Some(preferred_version) = preferred_version {
+                self.preferred_versions.insert(api_group.name.clone(), preferred_version.version.clone());
+            }
+        }
+    }
+
+    /// Adds the response of the `/api/v1` or `/apis/<group>/<version>` endpoints, which lists the resources of a group version.
+    ///
+    /// Resources that were already added for the same group version are replaced.
+    pub fn add_api_resource_list(&mut self, api_resource_list: &crate::apimachinery::pkg::apis::meta::v1::APIResourceList) {
+        let (group, version) = api_resource_list.group_version.split_once('/').unwrap_or(("", &api_resource_list.group_version));
+
+        for api_resource in &api_resource_list.resources {
+            if api_resource.name.contains('/') {
+                continue;
+            }
+
+            self.insert(DiscoveredResource {
+                group: group.to_owned(),
+                version: version.to_owned(),
+                kind: api_resource.kind.clone(),
+                plural: api_resource.name.clone(),
+                singular:
+                    if api_resource.singular_name.is_empty() {
+                        api_resource.kind.to_lowercase()
+                    }
+                    else {
+                        api_resource.singular_name.clone()
+                    },
+                namespaced: api_resource.namespaced,
+                verbs: api_resource.verbs.clone(),
+                short_names: api_resource.short_names.clone().unwrap_or_default(),
+                categories: api_resource.categories.clone().unwrap_or_default(),
+                subresources: vec![],
+            });
+        }
+
+        for api_resource in &api_resource_list.resources {
+            let Some((plural, subresource)) = api_resource.name.split_once('/') else { continue; };
+            let resource =
+                self.resources.iter_mut()
+                .find(|resource| resource.group == group && resource.version == version && resource.plural == plural);
+            if let Some(resource) = resource {
+                if !resource.subresources.iter().any(|existing| existing == subresource) {
+                    resource.subresources.push(subresource.to_owned());
+                }
+            }
+        }
+    }
+
+    /// Adds an aggregated discovery document, ie the `APIGroupDiscoveryList` returned by the `/api` and `/apis` endpoints
+    /// when requested with the [`AGGREGATED_DISCOVERY_ACCEPT_HEADER_VALUE`] `Accept` header.
+    ///
+    /// An aggregated discovery document contains the groups, versions and resources that would otherwise require
+    /// [`Discovery::add_api_versions`], [`Discovery::add_api_group_list`] and one [`Discovery::add_api_resource_list`] per group version.
+    /// The first version of each group is its preferred version.
+    pub fn add_aggregated_discovery(&mut self, buf: &[u8]) -> Result<(), serde_json::Error> {
+        let api_group_discovery_list: APIGroupDiscoveryList = serde_json::from_slice(buf)?;
+
+        if api_group_discovery_list.kind != "APIGroupDiscoveryList" {
+            return Err(serde::de::Error::custom(format_args!("expected kind APIGroupDiscoveryList, found {:?}", api_group_discovery_list.kind)));
+        }
+
+        for api_group_discovery in api_group_discovery_list.items {
+            let group = api_group_discovery.metadata.name.unwrap_or_default();
+
+            for (i, api_version_discovery) in api_group_discovery.versions.into_iter().enumerate() {
+                if i == 0 {
+                    self.preferred_versions.insert(group.clone(), api_version_discovery.version.clone());
+                }
+
+                for api_resource_discovery in api_version_discovery.resources {
+                    // Resources without a responseKind can only be used through their subresources, eg the `bindings` of a pod.
+                    let kind = api_resource_discovery.response_kind.map(|response_kind| response_kind.kind).unwrap_or_default();
+
+                    let namespaced = match &*api_resource_discovery.scope {
+                        "Namespaced" => true,
+                        "Cluster" => false,
+                        scope => return Err(serde::de::Error::custom(format_args!("unknown scope {scope:?} of resource {}", api_resource_discovery.resource))),
+                    };
+
+                    self.insert(DiscoveredResource {
+                        group: group.clone(),
+                        version: api_version_discovery.version.clone(),
+                        singular:
+                            if api_resource_discovery.singular_resource.is_empty() {
+                                kind.to_lowercase()
+                            }
+                            else {
+                                api_resource_discovery.singular_resource
+                            },
+                        kind,
+                        plural: api_resource_discovery.resource,
+                        namespaced,
+                        verbs: api_resource_discovery.verbs,
+                        short_names: api_resource_discovery.short_names.unwrap_or_default(),
+                        categories: api_resource_discovery.categories.unwrap_or_default(),
+                        subresources:
+                            api_resource_discovery.subresources.unwrap_or_default().into_iter()
+                            .map(|api_subresource_discovery| api_subresource_discovery.subresource)
+                            .collect(),
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Looks up a resource by its group, version and kind.
+    pub fn get(&self, group: &str, version: &str, kind: &str) -> Option<&DiscoveredResource> {
+        self.resources.iter().find(|resource| resource.group == group && resource.version == version && resource.kind == kind)
+    }
+
+    /// Resolves a resource argument the way `kubectl` does, such as `deploy`, `deployments`, `deployment.apps` or `deployments.v1.apps`.
+    ///
+    /// The resource part of the argument is matched case-insensitively against the plural name, singular name, short names and kind of each resource.
+    /// If the argument also specifies a group, or a version and a group, only resources of that group version are considered.
+    ///
+    /// If several resources match, matches of the plural or singular name are preferred over matches of a short name or kind,
+    /// then resources in the preferred version of their group, then resources of the core group, then resources that were added earlier.
+    pub fn resolve(&self, input: &str) -> Option<&DiscoveredResource> {
+        let input = input.to_lowercase();
+
+        // Try `resource.version.group`, then `resource.group`, then the whole input as the resource.
+        let mut candidates = vec![];
+        if let Some((resource, rest)) = input.split_once('.') {
+            if let Some((version, group)) = rest.split_once('.') {
+                candidates.push((resource, Some(version), Some(group)));
+            }
+            candidates.push((resource, None, Some(rest)));
+        }
+        candidates.push((&*input, None, None));
+
+        for (name, version, group) in candidates {
+            let best =
+                self.resources.iter()
+                .enumerate()
+                .filter(|(_, resource)| group.is_none_or(|group| resource.group == group))
+                .filter(|(_, resource)| version.is_none_or(|version| resource.version == version))
+                .filter_map(|(i, resource)| {
+                    let name_priority =
+                        if resource.plural == name || resource.singular == name {
+                            0
+                        }
+                        else if resource.short_names.iter().any(|short_name| short_name.eq_ignore_ascii_case(name)) {
+                            1
+                        }
+                        else if resource.kind.eq_ignore_ascii_case(name) {
+                            2
+                        }
+                        else {
+                            return None;
+                        };
+                    let is_not_preferred = self.preferred_version(&resource.group) != Some(&*resource.version);
+                    let is_not_core = !resource.group.is_empty();
+                    Some(((name_priority, is_not_preferred, is_not_core, i), resource))
+                })
+                .min_by_key(|&(priority, _)| priority);
+            if let Some((_, resource)) = best {
+                return Some(resource);
+            }
+        }
+
+        None
+    }
+
+    /// Serializes this `Discovery` for caching, such as to a file on disk. Use [`Discovery::from_cache`] to deserialize it.
+    pub fn to_cache(&self) -> Vec<u8> {
+        let fetched_at = self.fetched_at.duration_since(std::time::UNIX_EPOCH).unwrap_or_default().as_secs();
+
+        let resources: Vec<_> =
+            self.resources.iter()
+            .map(|resource| serde_json::json!({
+                "group": resource.group,
+                "version": resource.version,
+                "kind": resource.kind,
+                "plural": resource.plural,
+                "singular": resource.singular,
+                "namespaced": resource.namespaced,
+                "verbs": resource.verbs,
+                "shortNames": resource.short_names,
+                "categories": resource.categories,
+                "subresources": resource.subresources,
+            }))
+            .collect();
+
+        let value = serde_json::json!({
+            "fetchedAt": fetched_at,
+            "preferredVersions": self.preferred_versions,
+            "resources": resources,
+        });
+
+        value.to_string().into_bytes()
+    }
+
+    /// Deserializes a `Discovery` serialized by [`Discovery::to_cache`].
+    ///
+    /// Returns `Ok(None)` if the `Discovery` was fetched more than `ttl` ago, in which case the discovery documents should be fetched again.
+    pub fn from_cache(buf: &[u8], ttl: std::time::Duration) -> Result<Option<Self>, serde_json::Error> {
+        let value: serde_json::Value = serde_json::from_slice(buf)?;
+
+        let fetched_at =
+            value.get("fetchedAt").and_then(serde_json::Value::as_u64)
+            .ok_or_else(|| serde::de::Error::missing_field("fetchedAt"))?;
+        let fetched_at = std::time::UNIX_EPOCH + std::time::Duration::from_secs(fetched_at);
+        match std::time::SystemTime::now().duration_since(fetched_at) {
+            Ok(age) if age > ttl => return Ok(None),
+            _ => (),
+        }
+
+        let preferred_versions =
+            value.get("preferredVersions").and_then(serde_json::Value::as_object)
+            .ok_or_else(|| serde::de::Error::missing_field("preferredVersions"))?
+            .iter()
+            .map(|(group, version)| Ok((group.clone(), version.as_str().ok_or_else(|| serde::de::Error::custom("preferred version is not a string"))?.to_owned())))
+            .collect::<Result<_, serde_json::Error>>()?;
+
+        let resources =
+            array(&value, "resources")?
+            .iter()
+            .map(|resource| Ok(DiscoveredResource {
+                group: string(resource, "group")?.to_owned(),
+                version: string(resource, "version")?.to_owned(),
+                kind: string(resource, "kind")?.to_owned(),
+                plural: string(resource, "plural")?.to_owned(),
+                singular: string(resource, "singular")?.to_owned(),
+                namespaced: resource.get("namespaced").and_then(serde_json::Value::as_bool).ok_or_else(|| serde::de::Error::missing_field("namespaced"))?,
+                verbs: strings(resource, "verbs")?,
+                short_names: strings(resource, "shortNames")?,
+                categories: strings(resource, "categories")?,
+                subresources: strings(resource, "subresources")?,
+            }))
+            .collect::<Result<_, serde_json::Error>>()?;
+
+        Ok(Some(Discovery {
+            resources,
+            preferred_versions,
+            fetched_at,
+        }))
+    }
+
+    /// Writes this `Discovery` to the given cache file, creating its parent directories if necessary.
+    pub fn write_cache_file(&self, path: &std::path::Path) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, self.to_cache())
+    }
+
+    /// Reads a `Discovery` from the given cache file.
+    ///
+    /// Returns `Ok(None)` if the file does not exist, or if the `Discovery` in it was fetched more than `ttl` ago.
+    pub fn read_cache_file(path: &std::path::Path, ttl: std::time::Duration) -> std::io::Result<Option<Self>> {
+        let buf = match std::fs::read(path) {
+            Ok(buf) => buf,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        Self::from_cache(&buf, ttl).map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+    }
+
+    /// Adds the given resource, replacing any resource with the same group, version and plural name.
+    fn insert(&mut self, resource: DiscoveredResource) {
+        let existing =
+            self.resources.iter_mut()
+            .find(|existing| existing.group == resource.group && existing.version == resource.version && existing.plural == resource.plural);
+        match existing {
+            Some(existing) => *existing = resource,
+            None => self.resources.push(resource),
+        }
+    }
+}
+
+impl Default for Discovery {
+    fn default() -> Self {
+        Discovery::new()
+    }
+}
+
+/// `apidiscovery.k8s.io/v2beta1` `APIGroupDiscoveryList`, the aggregated discovery document.
+///
+/// Only the fields that are used by [`Discovery::add_aggregated_discovery`] are deserialized.
+#[derive(Default)]
+struct APIGroupDiscoveryList {
+    kind: String,
+    items: Vec<APIGroupDiscovery>,
+}
+
+/// `apidiscovery.k8s.io/v2beta1` `APIGroupDiscovery`, the versions of an API group in an [`APIGroupDiscoveryList`].
+#[derive(Default)]
+struct APIGroupDiscovery {
+    metadata: crate::apimachinery::pkg::apis::meta::v1::ObjectMeta,
+    versions: Vec<APIVersionDiscovery>,
+}
+
+/// `apidiscovery.k8s.io/v2beta1` `APIVersionDiscovery`, the resources of a version of an API group.
+#[derive(Default)]
+struct APIVersionDiscovery {
+    version: String,
+    resources: Vec<APIResourceDiscovery>,
+}
+
+/// `apidiscovery.k8s.io/v2beta1` `APIResourceDiscovery`, a resource of a version of an API group.
+#[derive(Default)]
+struct APIResourceDiscovery {
+    resource: String,
+    response_kind: Option<ResponseKind>,
+    scope: String,
+    singular_resource: String,
+    verbs: Vec<String>,
+    short_names: Option<Vec<String>>,
+    categories: Option<Vec<String>>,
+    subresources: Option<Vec<APISubresourceDiscovery>>,
+}
+
+/// The `responseKind` of an [`APIResourceDiscovery`]. Only its kind is used, since the group and version are those of the resource.
+#[derive(Default)]
+struct ResponseKind {
+    kind: String,
+}
+
+/// `apidiscovery.k8s.io/v2beta1` `APISubresourceDiscovery`, a subresource of an [`APIResourceDiscovery`].
+#[derive(Default)]
+struct APISubresourceDiscovery {
+    subresource: String,
+}
+
+/// Implements `serde::Deserialize` for a struct from a JSON object with the given keys for its fields.
+///
+/// Missing fields are left at their default values, and unknown keys are ignored.
+macro_rules! impl_deserialize {
+    ($($ty:ident { $($field:ident: $key:literal,)* })*) => {
+        $(
+            impl<'de> serde::Deserialize<'de> for $ty {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+                    struct Visitor;
+
+                    impl<'de> serde::de::Visitor<'de> for Visitor {
+                        type Value = $ty;
+
+                        fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                            f.write_str(stringify!($ty))
+                        }
+
+                        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de> {
+                            let mut result = $ty::default();
+
+                            while let Some(key) = serde::de::MapAccess::next_key::<String>(&mut map)? {
+                                match &*key {
+                                    $($key => result.$field = serde::de::MapAccess::next_value(&mut map)?,)*
+                                    _ => { let _: serde::de::IgnoredAny = serde::de::MapAccess::next_value(&mut map)?; },
+                                }
+                            }
+
+                            Ok(result)
+                        }
+                    }
+
+                    deserializer.deserialize_struct(stringify!($ty), &[$($key),*], Visitor)
+                }
+            }
+        )*
+    };
+}
+
+impl_deserialize! {
+    APIGroupDiscoveryList {
+        kind: "kind",
+        items: "items",
+    }
+
+    APIGroupDiscovery {
+        metadata: "metadata",
+        versions: "versions",
+    }
+
+    APIVersionDiscovery {
+        version: "version",
+        resources: "resources",
+    }
+
+    APIResourceDiscovery {
+        resource: "resource",
+        response_kind: "responseKind",
+        scope: "scope",
+        singular_resource: "singularResource",
+        verbs: "verbs",
+        short_names: "shortNames",
+        categories: "categories",
+        subresources: "subresources",
+    }
+
+    ResponseKind {
+        kind: "kind",
+    }
+
+    APISubresourceDiscovery {
+        subresource: "subresource",
+    }
+}
+
+fn array<'a>(value: &'a serde_json::Value, field: &'static str) -> Result<&'a [serde_json::Value], serde_json::Error> {
+    value.get(field).and_then(serde_json::Value::as_array).map(Vec::as_slice).ok_or_else(|| serde::de::Error::missing_field(field))
+}
+
+fn optional_array<'a>(value: &'a serde_json::Value, field: &'static str) -> Result<&'a [serde_json::Value], serde_json::Error> {
+    match value.get(field) {
+        None | Some(serde_json::Value::Null) => Ok(&[]),
+        Some(_) => array(value, field),
+    }
+}
+
+fn string<'a>(value: &'a serde_json::Value, field: &'static str) -> Result<&'a str, serde_json::Error> {
+    value.get(field).and_then(serde_json::Value::as_str).ok_or_else(|| serde::de::Error::missing_field(field))
+}
+
+fn strings(value: &serde_json::Value, field: &'static str) -> Result<Vec<String>, serde_json::Error> {
+    optional_array(value, field)?
+        .iter()
+        .map(|value| value.as_str().map(ToOwned::to_owned).ok_or_else(|| serde::de::Error::custom(format_args!("{field} contains a non-string value"))))
+        .collect()
+}
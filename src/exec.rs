@@ -0,0 +1,242 @@
+/// The WebSocket subprotocols of the streams opened by `Pod::connect_get_exec`, `Pod::connect_post_exec` and `Pod::connect_get_attach`.
+///
+/// Send the protocols you support in the `Sec-WebSocket-Protocol` header of the upgrade request, then use [`ExecProtocol::from_header_value`]
+/// on the same header of the response to find out which one the API server picked.
+///
+/// Every WebSocket binary message of the stream starts with a byte that identifies its [`ExecChannel`], followed by the data of that channel.
+/// Use [`ExecProtocol::decode`] to decode the messages received from the API server, and the `encode_*` functions to construct the messages to send to it.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ExecProtocol {
+    /// `v4.channel.k8s.io`
+    V4,
+
+    /// `v5.channel.k8s.io`, which adds the ability to close a channel, such as to signal the end of stdin. Supported by Kubernetes 1.29 and later.
+    V5,
+}
+
+impl ExecProtocol {
+    /// The value of the `Sec-WebSocket-Protocol` header that identifies this protocol.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ExecProtocol::V4 => "v4.channel.k8s.io",
+            ExecProtocol::V5 => "v5.channel.k8s.io",
+        }
+    }
+
+    /// Parses the value of the `Sec-WebSocket-Protocol` header of the upgrade response.
+    ///
+    /// Returns `None` if the API server picked a protocol that is not supported by this type.
+    pub fn from_header_value(value: &str) -> Option<Self> {
+        match value.trim() {
+            "v4.channel.k8s.io" => Some(ExecProtocol::V4),
+            "v5.channel.k8s.io" => Some(ExecProtocol::V5),
+            _ => None,
+        }
+    }
+
+    /// Decodes a WebSocket binary message received from the API server.
+    pub fn decode(self, message: &[u8]) -> Result<ExecFrame<'_>, ExecFrameError> {
+        let (&channel, data) = message.split_first().ok_or(ExecFrameError::Empty)?;
+
+        // The API server opens every channel with an empty message.
+        if data.is_empty() {
+            return match ExecChannel::from_u8(channel) {
+                Some(channel @ (ExecChannel::Stdout | ExecChannel::Stderr | ExecChannel::Error)) => Ok(ExecFrame::Empty(channel)),
+                Some(channel) => Err(ExecFrameError::UnexpectedChannel(channel)),
+                None => Err(ExecFrameError::UnknownChannel(channel)),
+            };
+        }
+
+        match ExecChannel::from_u8(channel) {
+            Some(ExecChannel::Stdout) => Ok(ExecFrame::Stdout(data)),
+            Some(ExecChannel::Stderr) => Ok(ExecFrame::Stderr(data)),
+            Some(ExecChannel::Error) => {
+                let status: crate::apimachinery::pkg::apis::meta::v1::Status = serde_json::from_slice(data).map_err(ExecFrameError::Json)?;
+                Ok(ExecFrame::Status(ExecStatus::new(status)))
+            },
+            Some(ExecChannel::Close) if self == ExecProtocol::V5 => {
+                let &[closed_channel] = data else { return Err(ExecFrameError::InvalidClose); };
+                let closed_channel = ExecChannel::from_u8(closed_channel).ok_or(ExecFrameError::UnknownChannel(closed_channel))?;
+                Ok(ExecFrame::Close(closed_channel))
+            },
+            Some(channel @ (ExecChannel::Stdin | ExecChannel::Resize | ExecChannel::Close)) => Err(ExecFrameError::UnexpectedChannel(channel)),
+            None => Err(ExecFrameError::UnknownChannel(channel)),
+        }
+    }
+
+    /// Encodes the given data to be written to the stdin of the container.
+    pub fn encode_stdin(self, data: &[u8]) -> Vec<u8> {
+        let mut message = Vec::with_capacity(1 + data.len());
+        message.push(ExecChannel::Stdin as u8);
+        message.extend_from_slice(data);
+        message
+    }
+
+    /// Encodes a message that resizes the terminal of the container. This requires the stream to have been opened with `tty` set to `true`.
+    pub fn encode_resize(self, size: TerminalSize) -> Vec<u8> {
+        let TerminalSize { width, height } = size;
+        let mut message = vec![ExecChannel::Resize as u8];
+        message.extend_from_slice(format!(r#"{{"Width":{width},"Height":{height}}}"#).as_bytes());
+        message
+    }
+
+    /// Encodes a message that closes the given channel, such as [`ExecChannel::Stdin`] to signal the end of the input of the container.
+    ///
+    /// Returns `None` for [`ExecProtocol::V4`] since it does not support closing channels. The only way to signal the end of stdin
+    /// with that protocol is to close the whole WebSocket.
+    pub fn encode_close(self, channel: ExecChannel) -> Option<Vec<u8>> {
+        match self {
+            ExecProtocol::V4 => None,
+            ExecProtocol::V5 => Some(vec![ExecChannel::Close as u8, channel as u8]),
+        }
+    }
+}
+
+/// The channels of an [`ExecProtocol`] stream.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(u8)]
+pub enum ExecChannel {
+    /// The stdin of the container. Written by the client.
+    Stdin = 0,
+
+    /// The stdout of the container. Written by the API server.
+    Stdout = 1,
+
+    /// The stderr of the container. Written by the API server.
+    Stderr = 2,
+
+    /// The status of the command, written by the API server once the command exits.
+    Error = 3,
+
+    /// Resize messages for the terminal of the container. Written by the client.
+    Resize = 4,
+
+    /// Messages that close another channel. Only supported by [`ExecProtocol::V5`].
+    Close = 255,
+}
+
+impl ExecChannel {
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(ExecChannel::Stdin),
+            1 => Some(ExecChannel::Stdout),
+            2 => Some(ExecChannel::Stderr),
+            3 => Some(ExecChannel::Error),
+            4 => Some(ExecChannel::Resize),
+            255 => Some(ExecChannel::Close),
+            _ => None,
+        }
+    }
+}
+
+/// A message received from the API server, as decoded by [`ExecProtocol::decode`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExecFrame<'a> {
+    /// Data written by the container to its stdout.
+    Stdout(&'a [u8]),
+
+    /// Data written by the container to its stderr.
+    Stderr(&'a [u8]),
+
+    /// The status of the command. This is the last message of the stream.
+    Status(ExecStatus),
+
+    /// The API server closed the given channel.
+    Close(ExecChannel),
+
+    /// An empty message on the given channel. The API server sends one of these on each channel when it opens the stream.
+    Empty(ExecChannel),
+}
+
+/// The status of a command, sent by the API server on the [`ExecChannel::Error`] channel.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExecStatus {
+    /// The command exited with exit code 0.
+    Success,
+
+    /// The command exited with the given non-zero exit code.
+    ExitCode(i32),
+
+    /// The command could not be run, or failed for a reason other than a non-zero exit code.
+    Failure(crate::apimachinery::pkg::apis::meta::v1::Status),
+}
+
+impl ExecStatus {
+    fn new(status: crate::apimachinery::pkg::apis::meta::v1::Status) -> Self {
+        if status.status.as_deref() == Some("Success") {
+            return ExecStatus::Success;
+        }
+
+        if status.reason.as_deref() == Some("NonZeroExitCode") {
+            let exit_code =
+                status.details.as_ref()
+                .and_then(|details| details.causes.as_ref())
+                .and_then(|causes| causes.iter().find(|cause| cause.reason.as_deref() == Some("ExitCode")))
+                .and_then(|cause| cause.message.as_ref()?.parse().ok());
+            if let Some(exit_code) = exit_code {
+                return ExecStatus::ExitCode(exit_code);
+            }
+        }
+
+        ExecStatus::Failure(status)
+    }
+
+    /// The exit code of the command, if it ran to completion.
+    pub fn exit_code(&self) -> Option<i32> {
+        match self {
+            ExecStatus::Success => Some(0),
+            ExecStatus::ExitCode(exit_code) => Some(*exit_code),
+            ExecStatus::Failure(_) => None,
+        }
+    }
+}
+
+/// The error returned by [`ExecProtocol::decode`].
+#[derive(Debug)]
+pub enum ExecFrameError {
+    /// The message is empty, so it does not even have a channel byte.
+    Empty,
+
+    /// A close message does not contain exactly one channel byte.
+    InvalidClose,
+
+    /// The status on the [`ExecChannel::Error`] channel could not be deserialized.
+    Json(serde_json::Error),
+
+    /// The message is on a channel that the API server does not write to with this protocol.
+    UnexpectedChannel(ExecChannel),
+
+    /// The message is on an unknown channel.
+    UnknownChannel(u8),
+}
+
+impl std::fmt::Display for ExecFrameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExecFrameError::Empty => f.write_str("message is empty"),
+            ExecFrameError::InvalidClose => f.write_str("close message does not contain exactly one channel"),
+            ExecFrameError::Json(err) => write!(f, "{err}"),
+            ExecFrameError::UnexpectedChannel(channel) => write!(f, "unexpected message on channel {channel:?}"),
+            ExecFrameError::UnknownChannel(channel) => write!(f, "message on unknown channel {channel}"),
+        }
+    }
+}
+
+impl std::error::Error for ExecFrameError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ExecFrameError::Json(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// The size of the terminal of the container, as sent by [`ExecProtocol::encode_resize`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct TerminalSize {
+    /// The width of the terminal in columns.
+    pub width: u16,
+
+    /// The height of the terminal in rows.
+    pub height: u16,
+}
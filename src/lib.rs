@@ -230,6 +230,22 @@ The `api` feature has been disabled, so the client API is not available. See ["C
 //!
 //!   This feature is enabled by default, but can be disabled if your crate does not need the operation functions to save on compile time and resources.
 //!
+//! - The `compat` feature enables the `compat` module. For each supported version `v1_<>`, `compat::v1_<>` contains a `<Type>Compat` trait
+//!   for each type that exists in that version and all later versions, with accessors for the fields that have the same name and type in all those versions,
+//!   and `with_<field>` builder methods to set them.
+//!   This lets a library that supports a range of versions, such as 1.22 and later, work with those fields using `compat::v1_22` without conditional compilation.
+//!   Values of those types can be constructed with `Default::default()` followed by the builder methods, since a struct literal would have to name
+//!   the fields that only exist in some versions.
+//!   This feature is disabled by default.
+//!
+//! - The `eq_hash_ord` feature derives `Eq`, `Hash`, `Ord` and `PartialOrd` for the types whose fields all implement these traits,
+//!   such as `api::core::v1::Container` and `api::core::v1::Toleration`, so that they can be used in a `HashSet` or `BTreeSet`.
+//!   Types with a field that transitively contains a `serde_json::Value` or an `f64`, such as `apimachinery::pkg::apis::meta::v1::ObjectMeta`
+//!   and therefore all resource types, do not get these derives. This feature is disabled by default since the additional derives increase compile time.
+//!
+//! - The `gzip` feature enables `ResponseBody` to decode responses with `Content-Encoding: gzip`. It implies the `api` feature.
+//!   This feature is disabled by default.
+//!
 //! One and only one of the `v1_*` features must be enabled at the same time, otherwise the crate will not compile. This ensures that all crates in the crate graph
 //! use the same types. If it was possible for one library crate to use `api::core::v1::Pod` corresponding to v1.50 and another to use the type
 //! corresponding to v1.51, an application would not be able to use the same `Pod` value with both.
@@ -426,10 +442,70 @@ pub use _api::{
 mod _byte_string;
 pub use _byte_string::ByteString;
 
+#[cfg(feature = "compat")]
+pub mod compat;
+
 #[path = "deep_merge.rs"]
 mod _deep_merge;
 pub use self::_deep_merge::DeepMerge;
 
+#[cfg(feature = "api")]
+#[path = "discovery.rs"]
+mod _discovery;
+#[cfg(feature = "api")]
+pub use _discovery::{
+    AGGREGATED_DISCOVERY_ACCEPT_HEADER_VALUE,
+    DiscoveredResource,
+    Discovery,
+};
+
+#[cfg(feature = "api")]
+#[path = "exec.rs"]
+mod _exec;
+#[cfg(feature = "api")]
+pub use _exec::{
+    ExecChannel, ExecFrame, ExecFrameError, ExecProtocol, ExecStatus,
+    TerminalSize,
+};
+
+#[cfg(feature = "api")]
+#[path = "list_stream.rs"]
+mod _list_stream;
+#[cfg(feature = "api")]
+pub use _list_stream::{
+    ListStream, ListStreamEvent,
+};
+
+#[cfg(feature = "api")]
+#[path = "partial_object_metadata.rs"]
+mod _partial_object_metadata;
+#[cfg(feature = "api")]
+pub use _partial_object_metadata::{
+    PARTIAL_OBJECT_METADATA_ACCEPT_HEADER_VALUE, PARTIAL_OBJECT_METADATA_LIST_ACCEPT_HEADER_VALUE,
+    PartialObjectMetadataResponse,
+    partial_object_metadata_list_request, partial_object_metadata_read_request, partial_object_metadata_watch_request,
+};
+
+#[cfg(feature = "api")]
+#[path = "pod_log.rs"]
+mod _pod_log;
+#[cfg(feature = "api")]
+pub use _pod_log::{
+    PodLogLine,
+    PodLogLineStream,
+    ReadPodLogLineResponse,
+};
+
+#[cfg(feature = "api")]
+#[path = "port_forward.rs"]
+mod _port_forward;
+#[cfg(feature = "api")]
+pub use _port_forward::{
+    PortForwardCodec,
+    PortForwardFrame, PortForwardFrameError,
+    PortForwardStreamType,
+};
+
 #[path = "resource.rs"]
 mod _resource;
 pub use _resource::{
@@ -440,6 +516,42 @@ pub use _resource::{
     api_version, group, kind, version,
 };
 
+#[cfg(feature = "schemars")]
+#[path = "structural_schema.rs"]
+mod _structural_schema;
+#[cfg(feature = "schemars")]
+pub use _structural_schema::{
+    make_structural, structural_schema_for,
+    StructuralSchemaError,
+};
+
+#[cfg(feature = "api")]
+#[path = "table.rs"]
+mod _table;
+#[cfg(feature = "api")]
+pub use _table::{
+    IncludeObjectPolicy,
+    TABLE_ACCEPT_HEADER_VALUE,
+    TableResponse,
+    table_request,
+};
+
+#[cfg(feature = "api")]
+#[path = "version_check.rs"]
+mod _version_check;
+#[cfg(feature = "api")]
+pub use _version_check::{
+    KubernetesVersion,
+    ResourceOperation,
+    unserved_operations,
+};
+
+#[cfg(feature = "api")]
+#[path = "warning.rs"]
+mod _warning;
+#[cfg(feature = "api")]
+pub use _warning::Warning;
+
 #[cfg(k8s_openapi_enabled_version="1.20")] mod v1_20;
 #[cfg(k8s_openapi_enabled_version="1.20")] pub use self::v1_20::*;
 
@@ -0,0 +1,216 @@
+/// Parses the response of a list operation like [`ListResponse`](crate::ListResponse) does, except that it yields the elements of the list's `items`
+/// one at a time as soon as they have been received, instead of buffering the whole [`List`](crate::List).
+///
+/// This lets callers process and drop each object incrementally, so that listing a large number of objects does not require holding all of them
+/// in memory at the same time.
+///
+/// Construct a `ListStream` from the [`ResponseBody`](crate::ResponseBody) of a list operation, then use [`ListStream::append_slice`]
+/// and [`ListStream::parse`] like with the `ResponseBody`.
+///
+/// ```rust,ignore
+/// let (request, response_body) = k8s_openapi::api::core::v1::Pod::list("kube-system", Default::default())?;
+/// let response = client.execute(request)?;
+///
+/// let mut list_stream = k8s_openapi::ListStream::new(response_body(response.status()));
+/// loop {
+///     match list_stream.parse() {
+///         Ok(k8s_openapi::ListStreamEvent::Item(pod)) => println!("{pod:?}"),
+///         Ok(k8s_openapi::ListStreamEvent::Metadata(metadata)) => println!("{:?}", metadata.resource_version),
+///         Ok(k8s_openapi::ListStreamEvent::End) => break,
+///         Ok(k8s_openapi::ListStreamEvent::Other(result)) => return Err(format!("{result:?}").into()),
+///         Err(k8s_openapi::ResponseError::NeedMoreData) => {
+///             let chunk = response.next_chunk()?;
+///             list_stream.append_slice(&chunk);
+///         },
+///         Err(err) => return Err(err.into()),
+///     }
+/// }
+/// ```
+///
+/// Unlike [`ListResponse`](crate::ListResponse), the `apiVersion` and `kind` fields of the list are not validated.
+pub struct ListStream<T> where T: serde::de::DeserializeOwned + crate::ListableResource {
+    response_body: crate::ResponseBody<crate::ListResponse<T>>,
+    state: ListStreamState,
+}
+
+/// An event parsed by [`ListStream::parse`].
+#[derive(Debug)]
+pub enum ListStreamEvent<T> {
+    /// An element of the list's `items`.
+    Item(T),
+
+    /// The list's `metadata`.
+    ///
+    /// The API server sends the metadata before the items, but this is not guaranteed.
+    Metadata(crate::apimachinery::pkg::apis::meta::v1::ListMeta),
+
+    /// The end of the list. All subsequent calls to [`ListStream::parse`] will also return this.
+    End,
+
+    /// The response had a status code other than `200 OK`. This is the same as [`ListResponse::Other`](crate::ListResponse::Other).
+    Other(Result<Option<serde_json::Value>, serde_json::Error>),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum ListStreamState {
+    /// Expecting the opening brace of the list object.
+    Start,
+
+    /// Expecting the next field of the list object, or its closing brace.
+    Field { first: bool },
+
+    /// Expecting the next element of the `items` array, or its closing bracket.
+    Item { first: bool },
+
+    /// The list object has been parsed.
+    End,
+}
+
+impl<T> ListStream<T> where T: serde::de::DeserializeOwned + crate::ListableResource {
+    /// Construct a value from the response body of a list operation.
+    pub fn new(response_body: crate::ResponseBody<crate::ListResponse<T>>) -> Self {
+        ListStream {
+            response_body,
+            state: ListStreamState::Start,
+        }
+    }
+
+    /// The response body that this value parses. This holds the HTTP status code and the warnings of the response.
+    pub fn response_body(&self) -> &crate::ResponseBody<crate::ListResponse<T>> {
+        &self.response_body
+    }
+
+    /// Append a slice of data from the HTTP response to the buffer.
+    pub fn append_slice(&mut self, buf: &[u8]) {
+        self.response_body.append_slice(buf);
+    }
+
+    /// Try to parse the next event from the data buffered so far.
+    ///
+    /// If the buffer does not contain enough bytes to parse the next event, this returns `Err(ResponseError::NeedMoreData)`.
+    /// Append more bytes with [`ListStream::append_slice`], then call this function again.
+    pub fn parse(&mut self) -> Result<ListStreamEvent<T>, crate::ResponseError> {
+        if self.response_body.status_code != http::StatusCode::OK {
+            return match self.response_body.parse()? {
+                crate::ListResponse::Other(result) => Ok(ListStreamEvent::Other(result)),
+                crate::ListResponse::Ok(_) => unreachable!("ListResponse::Ok is only parsed from 200 OK responses"),
+            };
+        }
+
+        self.response_body.decode_result()?;
+
+        loop {
+            let (read, state, event) = self.step()?;
+            self.response_body.advance(read);
+            self.state = state;
+            if let Some(event) = event {
+                return Ok(event);
+            }
+        }
+    }
+
+    /// Parses the next token or value from the buffer.
+    ///
+    /// Returns the number of bytes read, the next state, and the event that was parsed, if any.
+    fn step(&self) -> Result<(usize, ListStreamState, Option<ListStreamEvent<T>>), crate::ResponseError> {
+        let buf: &[u8] = &self.response_body;
+
+        match self.state {
+            ListStreamState::Start => {
+                let pos = skip_whitespace(buf, 0);
+                expect_byte(buf, pos, b'{')?;
+                Ok((pos + 1, ListStreamState::Field { first: true }, None))
+            },
+
+            ListStreamState::Field { first } => {
+                let mut pos = skip_whitespace(buf, 0);
+                if buf.get(pos) == Some(&b'}') {
+                    return Ok((pos + 1, ListStreamState::End, Some(ListStreamEvent::End)));
+                }
+
+                if !first {
+                    expect_byte(buf, pos, b',')?;
+                    pos = skip_whitespace(buf, pos + 1);
+                }
+
+                let (key, read): (String, _) = next_value(&buf[pos..])?;
+                pos = skip_whitespace(buf, pos + read);
+                expect_byte(buf, pos, b':')?;
+                pos = skip_whitespace(buf, pos + 1);
+
+                match (&*key, buf.get(pos)) {
+                    (_, None) => Err(crate::ResponseError::NeedMoreData),
+
+                    ("items", Some(b'[')) => Ok((pos + 1, ListStreamState::Item { first: true }, None)),
+
+                    ("metadata", _) => {
+                        let (metadata, read) = next_value(&buf[pos..])?;
+                        Ok((pos + read, ListStreamState::Field { first: false }, Some(ListStreamEvent::Metadata(metadata))))
+                    },
+
+                    _ => {
+                        let (serde::de::IgnoredAny, read) = next_value(&buf[pos..])?;
+                        Ok((pos + read, ListStreamState::Field { first: false }, None))
+                    },
+                }
+            },
+
+            ListStreamState::Item { first } => {
+                let mut pos = skip_whitespace(buf, 0);
+                if buf.get(pos) == Some(&b']') {
+                    return Ok((pos + 1, ListStreamState::Field { first: false }, None));
+                }
+
+                if !first {
+                    expect_byte(buf, pos, b',')?;
+                    pos = skip_whitespace(buf, pos + 1);
+                }
+
+                let (item, read) = next_value(&buf[pos..])?;
+                Ok((pos + read, ListStreamState::Item { first: false }, Some(ListStreamEvent::Item(item))))
+            },
+
+            ListStreamState::End => Ok((0, ListStreamState::End, Some(ListStreamEvent::End))),
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for ListStream<T> where T: serde::de::DeserializeOwned + crate::ListableResource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ListStream")
+            .field("status_code", &self.response_body.status_code)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
+fn skip_whitespace(buf: &[u8], mut pos: usize) -> usize {
+    while buf.get(pos).is_some_and(u8::is_ascii_whitespace) {
+        pos += 1;
+    }
+    pos
+}
+
+fn expect_byte(buf: &[u8], pos: usize, expected: u8) -> Result<(), crate::ResponseError> {
+    match buf.get(pos) {
+        Some(&b) if b == expected => Ok(()),
+        Some(&b) => Err(crate::ResponseError::Json(serde::de::Error::custom(format_args!(
+            "expected {:?}, found {:?} while parsing list",
+            char::from(expected), char::from(b),
+        )))),
+        None => Err(crate::ResponseError::NeedMoreData),
+    }
+}
+
+/// Deserializes a JSON value from the start of the buffer, and returns it along with the number of bytes read.
+///
+/// The value must be followed by at least one more byte, so that a number at the end of the buffer is not mistaken for a complete value.
+fn next_value<V>(buf: &[u8]) -> Result<(V, usize), crate::ResponseError> where V: serde::de::DeserializeOwned {
+    let mut deserializer = serde_json::Deserializer::from_slice(buf).into_iter();
+    match deserializer.next() {
+        Some(Ok(value)) if deserializer.byte_offset() < buf.len() => Ok((value, deserializer.byte_offset())),
+        Some(Ok(_)) | None => Err(crate::ResponseError::NeedMoreData),
+        Some(Err(err)) if err.is_eof() => Err(crate::ResponseError::NeedMoreData),
+        Some(Err(err)) => Err(crate::ResponseError::Json(err)),
+    }
+}
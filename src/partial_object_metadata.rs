@@ -0,0 +1,67 @@
+/// The value of the `Accept` header that requests only the metadata of the objects of a list operation, as a list of
+/// [`PartialObjectMetadata`](crate::apimachinery::pkg::apis::meta::v1::PartialObjectMetadata).
+pub const PARTIAL_OBJECT_METADATA_LIST_ACCEPT_HEADER_VALUE: &str = "application/json;as=PartialObjectMetadataList;v=v1;g=meta.k8s.io";
+
+/// The value of the `Accept` header that requests only the metadata of the objects of a watch or read operation, as
+/// [`PartialObjectMetadata`](crate::apimachinery::pkg::apis::meta::v1::PartialObjectMetadata).
+pub const PARTIAL_OBJECT_METADATA_ACCEPT_HEADER_VALUE: &str = "application/json;as=PartialObjectMetadata;v=v1;g=meta.k8s.io";
+
+/// Converts the request of a list operation into one that requests only the metadata of the objects.
+///
+/// This sets the `Accept` header of the request to [`PARTIAL_OBJECT_METADATA_LIST_ACCEPT_HEADER_VALUE`], and replaces the response type
+/// of the operation with a [`ListResponse`](crate::ListResponse) of [`PartialObjectMetadata`](crate::apimachinery::pkg::apis::meta::v1::PartialObjectMetadata).
+///
+/// ```rust,ignore
+/// let (request, response_body) = k8s_openapi::partial_object_metadata_list_request(
+///     k8s_openapi::api::core::v1::Secret::list("kube-system", Default::default())?,
+/// );
+/// ```
+pub fn partial_object_metadata_list_request<T>(
+    (request, _): (http::Request<Vec<u8>>, fn(http::StatusCode) -> crate::ResponseBody<crate::ListResponse<T>>),
+) -> (http::Request<Vec<u8>>, fn(http::StatusCode) -> crate::ResponseBody<crate::ListResponse<crate::apimachinery::pkg::apis::meta::v1::PartialObjectMetadata>>)
+where
+    T: serde::de::DeserializeOwned + crate::ListableResource,
+{
+    (with_accept_header(request, PARTIAL_OBJECT_METADATA_LIST_ACCEPT_HEADER_VALUE), crate::ResponseBody::new)
+}
+
+/// Converts the request of a watch operation into one that requests only the metadata of the objects.
+///
+/// This sets the `Accept` header of the request to [`PARTIAL_OBJECT_METADATA_ACCEPT_HEADER_VALUE`], and replaces the response type
+/// of the operation with a [`WatchResponse`](crate::WatchResponse) of [`PartialObjectMetadata`](crate::apimachinery::pkg::apis::meta::v1::PartialObjectMetadata).
+pub fn partial_object_metadata_watch_request<T>(
+    (request, _): (http::Request<Vec<u8>>, fn(http::StatusCode) -> crate::ResponseBody<crate::WatchResponse<T>>),
+) -> (http::Request<Vec<u8>>, fn(http::StatusCode) -> crate::ResponseBody<crate::WatchResponse<crate::apimachinery::pkg::apis::meta::v1::PartialObjectMetadata>>)
+where
+    T: serde::de::DeserializeOwned,
+{
+    (with_accept_header(request, PARTIAL_OBJECT_METADATA_ACCEPT_HEADER_VALUE), crate::ResponseBody::new)
+}
+
+/// Converts the request of a read operation into one that requests only the metadata of the object.
+///
+/// This sets the `Accept` header of the request to [`PARTIAL_OBJECT_METADATA_ACCEPT_HEADER_VALUE`], and replaces the response type
+/// of the operation with [`PartialObjectMetadataResponse`].
+pub fn partial_object_metadata_read_request<T>(
+    (request, _): (http::Request<Vec<u8>>, fn(http::StatusCode) -> crate::ResponseBody<T>),
+) -> (http::Request<Vec<u8>>, fn(http::StatusCode) -> crate::ResponseBody<PartialObjectMetadataResponse>) {
+    (with_accept_header(request, PARTIAL_OBJECT_METADATA_ACCEPT_HEADER_VALUE), crate::ResponseBody::new)
+}
+
+fn with_accept_header(mut request: http::Request<Vec<u8>>, value: &'static str) -> http::Request<Vec<u8>> {
+    let _ = request.headers_mut().insert(http::header::ACCEPT, http::HeaderValue::from_static(value));
+    request
+}
+
+/// Use `<PartialObjectMetadataResponse as Response>::try_from_parts` to parse the HTTP response body of a request returned by [`partial_object_metadata_read_request`]
+#[derive(Debug)]
+pub enum PartialObjectMetadataResponse {
+    Ok(crate::apimachinery::pkg::apis::meta::v1::PartialObjectMetadata),
+    Other(Result<Option<serde_json::Value>, serde_json::Error>),
+}
+
+impl crate::Response for PartialObjectMetadataResponse {
+    fn try_from_parts(status_code: http::StatusCode, buf: &[u8]) -> Result<(Self, usize), crate::ResponseError> {
+        crate::_api::try_from_json_parts(status_code, buf, PartialObjectMetadataResponse::Ok, PartialObjectMetadataResponse::Other)
+    }
+}
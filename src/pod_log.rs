@@ -0,0 +1,193 @@
+/// A single line of a container's logs, as returned by [`PodLogLineStream`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PodLogLine {
+    /// The timestamp that the API server prefixed the line with.
+    ///
+    /// This is only set if the `TIMESTAMPS` parameter of [`PodLogLineStream`] is `true`, ie the logs were requested with
+    /// `ReadPodLogOptional::timestamps` set to `Some(true)`. It is `None` for the continuations of a line that was longer than
+    /// the maximum line length, since those do not have a timestamp prefix.
+    pub timestamp: Option<crate::chrono::DateTime<crate::chrono::Utc>>,
+
+    /// The text of the line, without the timestamp prefix and without the trailing `\n` or `\r\n`.
+    pub text: String,
+
+    /// Whether the line was longer than the maximum line length and was cut short.
+    ///
+    /// The rest of the line is returned as one or more subsequent `PodLogLine`s.
+    pub truncated: bool,
+}
+
+/// Parses the logs returned by `Pod::read_log` into complete lines, as an alternative to parsing them with `ReadPodLogResponse`.
+///
+/// `ReadPodLogResponse` returns the logs in arbitrary chunks of UTF-8 text that do not necessarily end at line boundaries.
+/// This type instead returns one [`PodLogLine`] at a time, and returns `Err(ResponseError::NeedMoreData)` until a whole line is available.
+///
+/// - `TIMESTAMPS` must be set to `true` if the logs were requested with `ReadPodLogOptional::timestamps` set to `Some(true)`.
+///   The RFC 3339 timestamp that the API server prefixes each line with is then parsed into [`PodLogLine::timestamp`].
+///
+/// - `MAX_LINE_LENGTH` is the maximum length of a line in bytes, not including the timestamp prefix and the trailing newline.
+///   Longer lines are split into multiple `PodLogLine`s with [`PodLogLine::truncated`] set on all but the last of them,
+///   so that a container that never writes a newline cannot make the response buffer grow without bound.
+///
+/// Construct a `PodLogLineStream` from the [`ResponseBody`](crate::ResponseBody) of `Pod::read_log`, then use [`PodLogLineStream::append_slice`]
+/// and [`PodLogLineStream::parse`] like with the `ResponseBody`.
+///
+/// ```rust,ignore
+/// let (request, response_body) = k8s_openapi::api::core::v1::Pod::read_log("name", "namespace", k8s_openapi::api::core::v1::ReadPodLogOptional {
+///     follow: Some(true),
+///     timestamps: Some(true),
+///     ..Default::default()
+/// })?;
+/// let response = client.execute(request)?;
+///
+/// let mut lines: k8s_openapi::PodLogLineStream<true> = k8s_openapi::PodLogLineStream::new(response_body(response.status()));
+/// loop {
+///     match lines.parse() {
+///         Ok(k8s_openapi::ReadPodLogLineResponse::Ok(line)) => println!("{:?} {}", line.timestamp, line.text),
+///         Ok(k8s_openapi::ReadPodLogLineResponse::Other(result)) => return Err(format!("{result:?}").into()),
+///         Err(k8s_openapi::ResponseError::NeedMoreData) => {
+///             let chunk = response.next_chunk()?;
+///             lines.append_slice(&chunk);
+///         },
+///         Err(err) => return Err(err.into()),
+///     }
+/// }
+/// ```
+///
+/// Note that if the logs do not end with a newline, the last line remains in the buffer when the response ends.
+pub struct PodLogLineStream<const TIMESTAMPS: bool, const MAX_LINE_LENGTH: usize = 65536> {
+    response_body: crate::ResponseBody<crate::api::core::v1::ReadPodLogResponse>,
+
+    /// Whether the last line that was returned was truncated, so the next line is its continuation and does not have a timestamp prefix.
+    mid_line: bool,
+}
+
+/// A response parsed by [`PodLogLineStream::parse`].
+#[derive(Debug)]
+pub enum ReadPodLogLineResponse {
+    /// A line of the logs.
+    Ok(PodLogLine),
+
+    /// The response had a status code other than `200 OK`. This is the same as `ReadPodLogResponse::Other`.
+    Other(Result<Option<serde_json::Value>, serde_json::Error>),
+}
+
+impl<const TIMESTAMPS: bool, const MAX_LINE_LENGTH: usize> PodLogLineStream<TIMESTAMPS, MAX_LINE_LENGTH> {
+    /// Construct a value from the response body of `Pod::read_log`.
+    pub fn new(response_body: crate::ResponseBody<crate::api::core::v1::ReadPodLogResponse>) -> Self {
+        PodLogLineStream {
+            response_body,
+            mid_line: false,
+        }
+    }
+
+    /// The response body that this value parses. This holds the HTTP status code and the warnings of the response.
+    pub fn response_body(&self) -> &crate::ResponseBody<crate::api::core::v1::ReadPodLogResponse> {
+        &self.response_body
+    }
+
+    /// Append a slice of data from the HTTP response to the buffer.
+    pub fn append_slice(&mut self, buf: &[u8]) {
+        self.response_body.append_slice(buf);
+    }
+
+    /// Try to parse the next line from the data buffered so far.
+    ///
+    /// If the buffer does not contain a whole line, this returns `Err(ResponseError::NeedMoreData)`.
+    /// Append more bytes with [`PodLogLineStream::append_slice`], then call this function again.
+    pub fn parse(&mut self) -> Result<ReadPodLogLineResponse, crate::ResponseError> {
+        if self.response_body.status_code != http::StatusCode::OK {
+            return match self.response_body.parse()? {
+                crate::api::core::v1::ReadPodLogResponse::Other(result) => Ok(ReadPodLogLineResponse::Other(result)),
+                crate::api::core::v1::ReadPodLogResponse::Ok(_) => unreachable!("ReadPodLogResponse::Ok is only parsed from 200 OK responses"),
+            };
+        }
+
+        self.response_body.decode_result()?;
+
+        // The continuation of a truncated line does not have a timestamp prefix.
+        let (line, read) = parse_line(&self.response_body, TIMESTAMPS && !self.mid_line, MAX_LINE_LENGTH)?;
+        self.response_body.advance(read);
+        self.mid_line = line.truncated;
+        Ok(ReadPodLogLineResponse::Ok(line))
+    }
+}
+
+fn parse_line(buf: &[u8], timestamps: bool, max_line_length: usize) -> Result<(PodLogLine, usize), crate::ResponseError> {
+    // The timestamp prefix does not count towards the line length.
+    let (timestamp, prefix_len) =
+        if timestamps {
+            match parse_timestamp(buf) {
+                Some((timestamp, prefix_len)) => (Some(timestamp), prefix_len),
+                // Not enough data to tell if the line starts with a timestamp.
+                None if !buf.is_empty() && !buf.contains(&b'\n') && buf.len() <= MAX_TIMESTAMP_PREFIX_LEN => return Err(crate::ResponseError::NeedMoreData),
+                None => (None, 0),
+            }
+        }
+        else {
+            (None, 0)
+        };
+
+    let rest = &buf[prefix_len..];
+    let max_line_length = max_line_length.max(1);
+
+    // A line of exactly the maximum length can end with a `\r\n`, so look one byte further for the `\n` of the pair.
+    let newline_pos =
+        rest.iter().take(max_line_length + 2).position(|&b| b == b'\n')
+        .filter(|&newline_pos| newline_pos <= max_line_length || rest[max_line_length] == b'\r');
+
+    let (line, read, truncated) =
+        if let Some(newline_pos) = newline_pos {
+            let line = &rest[..newline_pos];
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
+            (line, newline_pos + 1, false)
+        }
+        else if rest.len() == max_line_length + 1 && rest[max_line_length] == b'\r' {
+            // Not enough data to tell if the `\r` is the start of the `\r\n` that ends the line.
+            return Err(crate::ResponseError::NeedMoreData);
+        }
+        else if rest.len() > max_line_length {
+            // Cut the line at the last char boundary at or before the maximum length, but always return at least one char.
+            let mut cut = max_line_length;
+            while cut > 0 && is_utf8_continuation_byte(rest[cut]) {
+                cut -= 1;
+            }
+            if cut == 0 {
+                cut = max_line_length;
+                while cut < rest.len() && is_utf8_continuation_byte(rest[cut]) {
+                    cut += 1;
+                }
+            }
+            (&rest[..cut], cut, true)
+        }
+        else {
+            return Err(crate::ResponseError::NeedMoreData);
+        };
+
+    let text = std::str::from_utf8(line).map_err(crate::ResponseError::Utf8)?;
+
+    Ok((
+        PodLogLine {
+            timestamp,
+            text: text.to_owned(),
+            truncated,
+        },
+        prefix_len + read,
+    ))
+}
+
+/// The longest timestamp prefix that the API server writes, eg `2006-01-02T15:04:05.999999999+07:00 `
+const MAX_TIMESTAMP_PREFIX_LEN: usize = 36;
+
+/// Parses the RFC 3339 timestamp and the space that follow it at the start of the given buffer,
+/// and returns the timestamp and the length of the prefix.
+fn parse_timestamp(buf: &[u8]) -> Option<(crate::chrono::DateTime<crate::chrono::Utc>, usize)> {
+    let space_pos = buf.iter().take(MAX_TIMESTAMP_PREFIX_LEN).position(|&b| b == b' ')?;
+    let timestamp = std::str::from_utf8(&buf[..space_pos]).ok()?;
+    let timestamp = crate::chrono::DateTime::parse_from_rfc3339(timestamp).ok()?;
+    Some((timestamp.with_timezone(&crate::chrono::Utc), space_pos + 1))
+}
+
+fn is_utf8_continuation_byte(b: u8) -> bool {
+    b & 0b1100_0000 == 0b1000_0000
+}
@@ -0,0 +1,171 @@
+/// A multiplexer and demultiplexer for the streams opened by `Pod::connect_get_portforward` and `Pod::connect_post_portforward`.
+///
+/// A single port-forward connection can forward multiple ports of the pod. The ports are identified by their index in the list
+/// of ports that the codec was constructed with, and that list must also be the `ports` query parameters of the upgrade request.
+/// Use [`PortForwardCodec::set_request_ports`] to set them.
+///
+/// # WebSocket
+///
+/// Request the [`PortForwardCodec::WEBSOCKET_PROTOCOL`] subprotocol. Each port then has a data channel and an error channel,
+/// and every WebSocket binary message starts with a byte that identifies the channel, followed by the data of that channel.
+/// The first message that the API server sends on each channel starts with the port number that the channel belongs to.
+///
+/// Use [`PortForwardCodec::encode`] to construct the messages to send to the API server, and [`PortForwardCodec::decode`] to decode
+/// the messages received from it. The codec keeps track of which channels have sent their port number header.
+///
+/// # SPDY
+///
+/// Request the [`PortForwardCodec::SPDY_PROTOCOL`] protocol. Each port then has a separate data stream and error stream,
+/// which the client creates with the headers from [`PortForwardCodec::spdy_stream_headers`]. The data on these streams is not framed.
+#[derive(Clone, Debug)]
+pub struct PortForwardCodec {
+    ports: Vec<u16>,
+    received_port_headers: Vec<bool>,
+}
+
+impl PortForwardCodec {
+    /// The WebSocket subprotocol of port-forward streams.
+    pub const WEBSOCKET_PROTOCOL: &'static str = "v4.channel.k8s.io";
+
+    /// The SPDY protocol of port-forward streams.
+    pub const SPDY_PROTOCOL: &'static str = "portforward.k8s.io";
+
+    /// Constructs a codec that forwards the given ports of the pod.
+    pub fn new(ports: &[u16]) -> Self {
+        PortForwardCodec {
+            ports: ports.to_owned(),
+            received_port_headers: vec![false; ports.len() * 2],
+        }
+    }
+
+    /// The ports that this codec forwards.
+    pub fn ports(&self) -> &[u16] {
+        &self.ports
+    }
+
+    /// Sets the `ports` query parameters of the given upgrade request, such as the one returned by `Pod::connect_get_portforward`,
+    /// to the ports that this codec forwards. Any `ports` query parameters that the request already has are replaced.
+    pub fn set_request_ports<B>(&self, request: &mut crate::http::Request<B>) -> Result<(), crate::RequestError> {
+        crate::_api::set_query_parameter(request, "ports", self.ports.iter().map(ToString::to_string))
+    }
+
+    /// Encodes a WebSocket binary message that sends the given data to the port with the given index.
+    ///
+    /// Returns `None` if the index is out of range.
+    pub fn encode(&self, port_index: usize, data: &[u8]) -> Option<Vec<u8>> {
+        if port_index >= self.ports.len() {
+            return None;
+        }
+
+        let channel = u8::try_from(port_index * 2).ok()?;
+
+        let mut message = Vec::with_capacity(1 + data.len());
+        message.push(channel);
+        message.extend_from_slice(data);
+        Some(message)
+    }
+
+    /// Decodes a WebSocket binary message received from the API server.
+    pub fn decode<'a>(&mut self, message: &'a [u8]) -> Result<PortForwardFrame<'a>, PortForwardFrameError> {
+        let (&channel, mut data) = message.split_first().ok_or(PortForwardFrameError::Empty)?;
+
+        let port_index = usize::from(channel / 2);
+        let port = *self.ports.get(port_index).ok_or(PortForwardFrameError::UnknownChannel(channel))?;
+
+        let received_port_header = &mut self.received_port_headers[usize::from(channel)];
+        if !*received_port_header {
+            let (&[port_lo, port_hi], rest) = data.split_at(data.len().min(2)) else {
+                return Err(PortForwardFrameError::MissingPortHeader(channel));
+            };
+            let actual = u16::from_le_bytes([port_lo, port_hi]);
+            if actual != port {
+                return Err(PortForwardFrameError::PortMismatch { expected: port, actual });
+            }
+            *received_port_header = true;
+            data = rest;
+        }
+
+        if channel % 2 == 0 {
+            Ok(PortForwardFrame::Data { port_index, data })
+        }
+        else {
+            Ok(PortForwardFrame::Error { port_index, message: String::from_utf8_lossy(data) })
+        }
+    }
+
+    /// The headers of a SPDY stream for the port with the given index.
+    ///
+    /// The data stream and the error stream of a port must use the same `request_id`, and it must be unique among the streams of the connection.
+    ///
+    /// Returns `None` if the index is out of range.
+    pub fn spdy_stream_headers(&self, port_index: usize, stream_type: PortForwardStreamType, request_id: u32) -> Option<[(&'static str, String); 3]> {
+        let port = self.ports.get(port_index)?;
+        let stream_type = match stream_type {
+            PortForwardStreamType::Data => "data",
+            PortForwardStreamType::Error => "error",
+        };
+        Some([
+            ("streamType", stream_type.to_owned()),
+            ("port", port.to_string()),
+            ("requestID", request_id.to_string()),
+        ])
+    }
+}
+
+/// The type of a SPDY stream of a port-forward connection.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PortForwardStreamType {
+    /// The stream that carries the data of the port in both directions.
+    Data,
+
+    /// The stream on which the API server reports errors of the port.
+    Error,
+}
+
+/// A message received from the API server, as decoded by [`PortForwardCodec::decode`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PortForwardFrame<'a> {
+    /// Data received from the port with the given index. This may be empty, such as for the message that only contains the port number header.
+    Data {
+        port_index: usize,
+        data: &'a [u8],
+    },
+
+    /// An error that occurred while forwarding the port with the given index. This may be empty, such as for the message that only contains the port number header.
+    Error {
+        port_index: usize,
+        message: std::borrow::Cow<'a, str>,
+    },
+}
+
+/// The error returned by [`PortForwardCodec::decode`].
+#[derive(Debug)]
+pub enum PortForwardFrameError {
+    /// The message is empty, so it does not even have a channel byte.
+    Empty,
+
+    /// The first message on the given channel is too short to contain the port number header.
+    MissingPortHeader(u8),
+
+    /// The port number header of a channel does not match the port that the channel was expected to belong to.
+    PortMismatch {
+        expected: u16,
+        actual: u16,
+    },
+
+    /// The message is on a channel that does not belong to any of the forwarded ports.
+    UnknownChannel(u8),
+}
+
+impl std::fmt::Display for PortForwardFrameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PortForwardFrameError::Empty => f.write_str("message is empty"),
+            PortForwardFrameError::MissingPortHeader(channel) => write!(f, "first message on channel {channel} does not contain the port number"),
+            PortForwardFrameError::PortMismatch { expected, actual } => write!(f, "expected channel of port {expected} but it belongs to port {actual}"),
+            PortForwardFrameError::UnknownChannel(channel) => write!(f, "message on unknown channel {channel}"),
+        }
+    }
+}
+
+impl std::error::Error for PortForwardFrameError {}
@@ -0,0 +1,535 @@
+//! Post-processing of `schemars` schemas into Kubernetes structural schemas.
+//!
+//! Ref: <https://kubernetes.io/docs/tasks/extend-kubernetes/custom-resources/custom-resource-definitions/#specifying-a-structural-schema>
+
+/// Generates the schema of `T` with `schemars` and converts it into a structural schema with [`make_structural`].
+///
+/// This is convenient for asserting in a test that the schema of a custom resource's spec type is suitable for
+/// use as the `openAPIV3Schema` of a `CustomResourceDefinition`.
+pub fn structural_schema_for<T>() -> Result<crate::schemars::schema::SchemaObject, StructuralSchemaError> where T: crate::schemars::JsonSchema {
+    let generator = crate::schemars::gen::SchemaSettings::draft07().into_generator();
+    make_structural(generator.into_root_schema_for::<T>())
+}
+
+/// Converts the given schema into a structural schema, as required for the `openAPIV3Schema` of a `CustomResourceDefinition`.
+///
+/// - All `$ref`s are inlined from the root schema's definitions. Recursive schemas cannot be expressed structurally and are rejected.
+///
+/// - `Option<T>` (`anyOf: [T, { type: null }]` or `type: [T, null]`) is rewritten to the schema of `T` with `nullable: true`.
+///
+/// - Enums of unit variants (`oneOf` of string enums) are merged into a single string schema with all the variants in `enum`.
+///
+/// - Unions whose branches are all objects (externally, internally or adjacently tagged enums, untagged enums of structs)
+///   are rewritten to a single object schema with the union of all branches' properties. The branches are retained
+///   with only their `required` lists, since structural schemas only allow value validations inside `anyOf` / `oneOf`.
+///
+/// - Unions of an integer and a string, and strings with `format: int-or-string`, are rewritten to use `x-kubernetes-int-or-string`.
+///
+/// - Schemas that accept any value are rewritten to use `x-kubernetes-preserve-unknown-fields`.
+///
+/// Any other construct that cannot be expressed structurally, such as a union of a string and an object, causes an error
+/// with the path of the offending schema.
+pub fn make_structural(root: crate::schemars::schema::RootSchema) -> Result<crate::schemars::schema::SchemaObject, StructuralSchemaError> {
+    let mut structural = Structural {
+        definitions: &root.definitions,
+        references: vec![],
+    };
+    structural.normalize(&crate::schemars::schema::Schema::Object(root.schema), &mut String::new())
+}
+
+/// The error returned by [`make_structural`] when a schema cannot be converted into a structural schema.
+#[derive(Debug)]
+pub struct StructuralSchemaError {
+    path: String,
+    message: String,
+}
+
+impl StructuralSchemaError {
+    #[doc(hidden)]
+    /// Creates an error for the schema at the given path.
+    ///
+    /// This function is only exposed for use by the `k8s-openapi-derive` crate and is not part of the stable public API.
+    pub fn __new(path: impl Into<String>, message: impl Into<String>) -> Self {
+        StructuralSchemaError {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+
+    /// The path of the offending schema, like `.properties[spec].properties[foo].items`. The root schema has the path `.`
+    pub fn path(&self) -> &str {
+        if self.path.is_empty() { "." } else { &self.path }
+    }
+
+    /// The reason the schema is not structural.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl std::fmt::Display for StructuralSchemaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "schema at {} is not structural: {}", self.path(), self.message)
+    }
+}
+
+impl std::error::Error for StructuralSchemaError {}
+
+struct Structural<'a> {
+    definitions: &'a crate::schemars::Map<String, crate::schemars::schema::Schema>,
+
+    /// The definitions currently being inlined, to detect recursive schemas.
+    references: Vec<&'a str>,
+}
+
+impl Structural<'_> {
+    fn normalize(&mut self, schema: &crate::schemars::schema::Schema, path: &mut String) -> Result<crate::schemars::schema::SchemaObject, StructuralSchemaError> {
+        let mut schema = match schema {
+            crate::schemars::schema::Schema::Bool(true) => return Ok(preserve_unknown_fields(crate::schemars::schema::SchemaObject::default())),
+            crate::schemars::schema::Schema::Bool(false) => return Err(error(path, "the `false` schema cannot be satisfied by any value")),
+            crate::schemars::schema::Schema::Object(schema) => schema.clone(),
+        };
+
+        let referenced = schema.reference.take().map(|reference| self.inline(&reference, path)).transpose()?;
+
+        let subschemas = schema.subschemas.take();
+
+        let mut result = self.normalize_plain(schema, path)?;
+
+        if let Some(referenced) = referenced {
+            merge(&mut result, referenced, path)?;
+        }
+
+        if let Some(subschemas) = subschemas {
+            let crate::schemars::schema::SubschemaValidation { all_of, any_of, one_of, not, if_schema, then_schema, else_schema } = *subschemas;
+
+            if not.is_some() {
+                return Err(error(path, "`not` is not supported"));
+            }
+
+            if if_schema.is_some() || then_schema.is_some() || else_schema.is_some() {
+                return Err(error(path, "`if` / `then` / `else` is not supported"));
+            }
+
+            for (i, subschema) in all_of.iter().flatten().enumerate() {
+                let subschema = with_path(path, format_args!(".allOf[{i}]"), |path| self.normalize(subschema, path))?;
+                merge(&mut result, subschema, path)?;
+            }
+
+            if let Some(any_of) = any_of {
+                let union = self.union(&any_of, "anyOf", path)?;
+                merge(&mut result, union, path)?;
+            }
+
+            if let Some(one_of) = one_of {
+                let union = self.union(&one_of, "oneOf", path)?;
+                merge(&mut result, union, path)?;
+            }
+        }
+
+        if result.instance_type.is_none() && !is_int_or_string(&result) && !is_preserve_unknown_fields(&result) {
+            if result.object.is_some() {
+                result.instance_type = Some(crate::schemars::schema::InstanceType::Object.into());
+            }
+            else if result.array.is_some() {
+                result.instance_type = Some(crate::schemars::schema::InstanceType::Array.into());
+            }
+            else if result.enum_values.is_none() && result.string.is_none() && result.number.is_none() {
+                // No type and no validations, so any value is accepted.
+                result = preserve_unknown_fields(result);
+            }
+            else {
+                return Err(error(path, "schema does not specify a type"));
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn inline(&mut self, reference: &str, path: &mut String) -> Result<crate::schemars::schema::SchemaObject, StructuralSchemaError> {
+        let name = reference.rsplit_once('/').map_or(reference, |(_, name)| name);
+
+        let Some((name, definition)) = self.definitions.get_key_value(name) else {
+            return Err(error(path, format_args!("reference {reference:?} could not be resolved")));
+        };
+
+        if self.references.contains(&&**name) {
+            return Err(error(path, format_args!("reference {reference:?} is recursive")));
+        }
+
+        self.references.push(name);
+        let result = self.normalize(definition, path);
+        self.references.pop();
+        result
+    }
+
+    /// Normalizes everything except `$ref` and subschemas, which are handled by the caller.
+    fn normalize_plain(&mut self, mut schema: crate::schemars::schema::SchemaObject, path: &mut String) -> Result<crate::schemars::schema::SchemaObject, StructuralSchemaError> {
+        if let Some(metadata) = &mut schema.metadata {
+            // Not supported by JSONSchemaProps
+            metadata.id = None;
+            metadata.deprecated = false;
+            metadata.read_only = false;
+            metadata.write_only = false;
+            metadata.examples = vec![];
+        }
+
+        if let Some(const_value) = schema.const_value.take() {
+            schema.enum_values = Some(vec![const_value]);
+        }
+
+        match schema.instance_type.take() {
+            Some(crate::schemars::schema::SingleOrVec::Single(instance_type)) if *instance_type == crate::schemars::schema::InstanceType::Null =>
+                return Err(error(path, "`null` type is only supported as a variant of a union")),
+
+            Some(crate::schemars::schema::SingleOrVec::Single(instance_type)) => schema.instance_type = Some(crate::schemars::schema::SingleOrVec::Single(instance_type)),
+
+            Some(crate::schemars::schema::SingleOrVec::Vec(instance_types)) => {
+                let num_instance_types = instance_types.len();
+                let mut instance_types: Vec<_> = instance_types.into_iter().filter(|instance_type| *instance_type != crate::schemars::schema::InstanceType::Null).collect();
+                if instance_types.len() != num_instance_types {
+                    set_nullable(&mut schema);
+                }
+
+                instance_types.sort();
+                match &*instance_types {
+                    [] => return Err(error(path, "`null` type is only supported as a variant of a union")),
+                    [instance_type] => schema.instance_type = Some((*instance_type).into()),
+                    [crate::schemars::schema::InstanceType::Integer, crate::schemars::schema::InstanceType::String] => schema = int_or_string(schema),
+                    instance_types => return Err(error(path, format_args!("multiple types {instance_types:?} are not supported"))),
+                }
+            },
+
+            None => (),
+        }
+
+        if schema.format.as_deref() == Some("int-or-string") {
+            schema.format = None;
+            schema = int_or_string(schema);
+        }
+
+        if let Some(array) = &mut schema.array {
+            if array.additional_items.is_some() {
+                return Err(error(path, "`additionalItems` is not supported"));
+            }
+
+            if array.contains.is_some() {
+                return Err(error(path, "`contains` is not supported"));
+            }
+
+            match &array.items {
+                Some(crate::schemars::schema::SingleOrVec::Single(items)) => {
+                    let items = with_path(path, ".items", |path| self.normalize(items, path))?;
+                    array.items = Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::Schema::Object(items))));
+                },
+
+                Some(crate::schemars::schema::SingleOrVec::Vec(_)) => return Err(error(path, "tuples are not supported, `items` must be a single schema")),
+
+                None => return Err(error(path, "arrays must specify `items`")),
+            }
+        }
+
+        if let Some(object) = &mut schema.object {
+            if !object.pattern_properties.is_empty() {
+                return Err(error(path, "`patternProperties` is not supported"));
+            }
+
+            if object.property_names.is_some() {
+                return Err(error(path, "`propertyNames` is not supported"));
+            }
+
+            for (name, property) in &mut object.properties {
+                let normalized = with_path(path, format_args!(".properties[{name}]"), |path| self.normalize(property, path))?;
+                *property = crate::schemars::schema::Schema::Object(normalized);
+            }
+
+            match object.additional_properties.take().map(|additional_properties| *additional_properties) {
+                // Unknown fields are pruned anyway.
+                Some(crate::schemars::schema::Schema::Bool(false)) | None => (),
+
+                Some(crate::schemars::schema::Schema::Bool(true)) =>
+                    if object.properties.is_empty() {
+                        schema.extensions.insert("x-kubernetes-preserve-unknown-fields".to_owned(), true.into());
+                    }
+                    else {
+                        return Err(error(path, "`properties` and `additionalProperties` are mutually exclusive"));
+                    },
+
+                Some(additional_properties) =>
+                    if object.properties.is_empty() {
+                        let normalized = with_path(path, ".additionalProperties", |path| self.normalize(&additional_properties, path))?;
+                        object.additional_properties = Some(Box::new(crate::schemars::schema::Schema::Object(normalized)));
+                    }
+                    else {
+                        return Err(error(path, "`properties` and `additionalProperties` are mutually exclusive"));
+                    },
+            }
+        }
+
+        Ok(schema)
+    }
+
+    fn union(&mut self, branches: &[crate::schemars::schema::Schema], keyword: &str, path: &mut String) -> Result<crate::schemars::schema::SchemaObject, StructuralSchemaError> {
+        let mut nullable = false;
+        let mut normalized = vec![];
+
+        for (i, branch) in branches.iter().enumerate() {
+            if let crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject { instance_type: Some(crate::schemars::schema::SingleOrVec::Single(instance_type)), .. }) = branch {
+                if **instance_type == crate::schemars::schema::InstanceType::Null {
+                    nullable = true;
+                    continue;
+                }
+            }
+
+            let branch = with_path(path, format_args!(".{keyword}[{i}]"), |path| self.normalize(branch, path))?;
+            nullable |= is_nullable(&branch);
+            normalized.push(branch);
+        }
+
+        let mut result = match normalized.len() {
+            0 => return Err(error(path, format_args!("`{keyword}` must have at least one non-null variant"))),
+
+            1 => normalized.pop().unwrap(),
+
+            _ if normalized.iter().all(is_string_enum) => {
+                let mut enum_values = vec![];
+                for branch in normalized {
+                    for value in branch.enum_values.into_iter().flatten() {
+                        if !enum_values.contains(&value) {
+                            enum_values.push(value);
+                        }
+                    }
+                }
+
+                crate::schemars::schema::SchemaObject {
+                    instance_type: Some(crate::schemars::schema::InstanceType::String.into()),
+                    enum_values: Some(enum_values),
+                    ..Default::default()
+                }
+            },
+
+            _ if normalized.iter().all(|branch| branch.instance_type == Some(crate::schemars::schema::InstanceType::Object.into())) => {
+                let mut properties = crate::schemars::Map::<String, crate::schemars::schema::Schema>::new();
+                let mut required = vec![];
+                let mut all_branches_have_required = true;
+
+                for (i, branch) in normalized.into_iter().enumerate() {
+                    let object = branch.object.map(|object| *object).unwrap_or_default();
+
+                    if object.additional_properties.is_some() {
+                        return Err(error(path, format_args!("`{keyword}[{i}]` is a map, which cannot be combined with other objects")));
+                    }
+
+                    for (name, property) in object.properties {
+                        match properties.get_mut(&name) {
+                            Some(existing) => with_path(path, format_args!(".properties[{name}]"), |path| merge_union_property(existing, property, keyword, path))?,
+                            None => { properties.insert(name, property); },
+                        }
+                    }
+
+                    all_branches_have_required &= !object.required.is_empty();
+                    required.push(crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                        object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                            required: object.required,
+                            ..Default::default()
+                        })),
+                        ..Default::default()
+                    }));
+                }
+
+                // A branch with no required properties matches any object, so the union does not validate anything.
+                let subschemas = all_branches_have_required.then(|| {
+                    let mut subschemas = crate::schemars::schema::SubschemaValidation::default();
+                    if keyword == "oneOf" {
+                        subschemas.one_of = Some(required);
+                    }
+                    else {
+                        subschemas.any_of = Some(required);
+                    }
+                    Box::new(subschemas)
+                });
+
+                crate::schemars::schema::SchemaObject {
+                    instance_type: Some(crate::schemars::schema::InstanceType::Object.into()),
+                    object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                        properties,
+                        ..Default::default()
+                    })),
+                    subschemas,
+                    ..Default::default()
+                }
+            },
+
+            _ if normalized.iter().all(|branch| is_int_or_string(branch) || is_single_type(branch, &[crate::schemars::schema::InstanceType::Integer, crate::schemars::schema::InstanceType::String])) =>
+                int_or_string(crate::schemars::schema::SchemaObject::default()),
+
+            _ => {
+                let instance_types: Vec<_> =
+                    normalized.iter()
+                    .map(|branch| match &branch.instance_type {
+                        Some(crate::schemars::schema::SingleOrVec::Single(instance_type)) => format!("{instance_type:?}"),
+                        _ => "(untyped)".to_owned(),
+                    })
+                    .collect();
+                return Err(error(path, format_args!(
+                    "`{keyword}` variants of types [{}] cannot be combined into a single structural schema",
+                    instance_types.join(", "),
+                )));
+            },
+        };
+
+        if nullable {
+            set_nullable(&mut result);
+        }
+
+        Ok(result)
+    }
+}
+
+/// Merges `other` into `schema`. Fields that are already set on `schema` take precedence, except for properties and
+/// required properties of objects which are combined.
+fn merge(schema: &mut crate::schemars::schema::SchemaObject, other: crate::schemars::schema::SchemaObject, path: &str) -> Result<(), StructuralSchemaError> {
+    let crate::schemars::schema::SchemaObject { metadata, instance_type, format, enum_values, const_value: _, subschemas, number, string, array, object, reference: _, extensions } = other;
+
+    match (&mut schema.metadata, metadata) {
+        (Some(schema_metadata), Some(metadata)) => {
+            let schema_metadata = &mut **schema_metadata;
+            if schema_metadata.title.is_none() { schema_metadata.title = metadata.title; }
+            if schema_metadata.description.is_none() { schema_metadata.description = metadata.description; }
+            if schema_metadata.default.is_none() { schema_metadata.default = metadata.default; }
+        },
+        (schema_metadata @ None, metadata) => *schema_metadata = metadata,
+        (Some(_), None) => (),
+    }
+
+    match (&schema.instance_type, instance_type) {
+        (Some(schema_instance_type), Some(instance_type)) if *schema_instance_type != instance_type =>
+            return Err(error(path, format_args!("conflicting types {schema_instance_type:?} and {instance_type:?}"))),
+        (None, instance_type) if !is_int_or_string(schema) && !is_preserve_unknown_fields(schema) => schema.instance_type = instance_type,
+        _ => (),
+    }
+
+    if schema.format.is_none() { schema.format = format; }
+    if schema.enum_values.is_none() { schema.enum_values = enum_values; }
+    if schema.number.is_none() { schema.number = number; }
+    if schema.string.is_none() { schema.string = string; }
+    if schema.array.is_none() { schema.array = array; }
+
+    match (&mut schema.object, object) {
+        (Some(schema_object), Some(object)) => {
+            for (name, property) in object.properties {
+                match schema_object.properties.get(&name) {
+                    Some(existing) if *existing != property =>
+                        return Err(error(path, format_args!("conflicting schemas for property {name:?}"))),
+                    Some(_) => (),
+                    None => { schema_object.properties.insert(name, property); },
+                }
+            }
+            schema_object.required.extend(object.required);
+            if schema_object.additional_properties.is_none() { schema_object.additional_properties = object.additional_properties; }
+        },
+        (schema_object @ None, object) => *schema_object = object,
+        (Some(_), None) => (),
+    }
+
+    schema.subschemas = match (schema.subschemas.take(), subschemas) {
+        (Some(schema_subschemas), Some(subschemas)) => Some(Box::new(crate::schemars::schema::SubschemaValidation {
+            all_of: Some(vec![
+                crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject { subschemas: Some(schema_subschemas), ..Default::default() }),
+                crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject { subschemas: Some(subschemas), ..Default::default() }),
+            ]),
+            ..Default::default()
+        })),
+        (schema_subschemas, subschemas) => schema_subschemas.or(subschemas),
+    };
+
+    for (name, value) in extensions {
+        schema.extensions.entry(name).or_insert(value);
+    }
+
+    Ok(())
+}
+
+/// Merges a property that appears in more than one variant of a union. The schemas must be identical, except for string enums
+/// which are combined. The latter allows the tag property of internally and adjacently tagged enums.
+fn merge_union_property(existing: &mut crate::schemars::schema::Schema, property: crate::schemars::schema::Schema, keyword: &str, path: &str) -> Result<(), StructuralSchemaError> {
+    if *existing == property {
+        return Ok(());
+    }
+
+    if let (crate::schemars::schema::Schema::Object(existing), crate::schemars::schema::Schema::Object(property)) = (&mut *existing, property) {
+        if is_string_enum(existing) && is_string_enum(&property) {
+            let enum_values = existing.enum_values.get_or_insert_with(Default::default);
+            for value in property.enum_values.into_iter().flatten() {
+                if !enum_values.contains(&value) {
+                    enum_values.push(value);
+                }
+            }
+            return Ok(());
+        }
+    }
+
+    Err(error(path, format_args!("property has different schemas in different `{keyword}` variants")))
+}
+
+fn is_nullable(schema: &crate::schemars::schema::SchemaObject) -> bool {
+    schema.extensions.get("nullable") == Some(&true.into())
+}
+
+fn set_nullable(schema: &mut crate::schemars::schema::SchemaObject) {
+    schema.extensions.insert("nullable".to_owned(), true.into());
+}
+
+fn is_int_or_string(schema: &crate::schemars::schema::SchemaObject) -> bool {
+    schema.extensions.get("x-kubernetes-int-or-string") == Some(&true.into())
+}
+
+fn int_or_string(mut schema: crate::schemars::schema::SchemaObject) -> crate::schemars::schema::SchemaObject {
+    schema.instance_type = None;
+    schema.extensions.insert("x-kubernetes-int-or-string".to_owned(), true.into());
+    schema.subschemas = Some(Box::new(crate::schemars::schema::SubschemaValidation {
+        any_of: Some(vec![
+            crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject { instance_type: Some(crate::schemars::schema::InstanceType::Integer.into()), ..Default::default() }),
+            crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject { instance_type: Some(crate::schemars::schema::InstanceType::String.into()), ..Default::default() }),
+        ]),
+        ..Default::default()
+    }));
+    schema
+}
+
+fn is_preserve_unknown_fields(schema: &crate::schemars::schema::SchemaObject) -> bool {
+    schema.extensions.get("x-kubernetes-preserve-unknown-fields") == Some(&true.into())
+}
+
+fn preserve_unknown_fields(mut schema: crate::schemars::schema::SchemaObject) -> crate::schemars::schema::SchemaObject {
+    schema.extensions.insert("x-kubernetes-preserve-unknown-fields".to_owned(), true.into());
+    schema
+}
+
+fn is_single_type(schema: &crate::schemars::schema::SchemaObject, instance_types: &[crate::schemars::schema::InstanceType]) -> bool {
+    matches!(&schema.instance_type, Some(crate::schemars::schema::SingleOrVec::Single(instance_type)) if instance_types.contains(instance_type))
+}
+
+fn is_string_enum(schema: &crate::schemars::schema::SchemaObject) -> bool {
+    schema.instance_type == Some(crate::schemars::schema::InstanceType::String.into()) &&
+        schema.enum_values.is_some() &&
+        schema.object.is_none() &&
+        schema.array.is_none()
+}
+
+fn with_path<T>(path: &mut String, segment: impl std::fmt::Display, f: impl FnOnce(&mut String) -> T) -> T {
+    use std::fmt::Write;
+
+    let len = path.len();
+    let _ = write!(path, "{segment}");
+    let result = f(path);
+    path.truncate(len);
+    result
+}
+
+fn error(path: &str, message: impl std::fmt::Display) -> StructuralSchemaError {
+    StructuralSchemaError {
+        path: path.to_owned(),
+        message: message.to_string(),
+    }
+}
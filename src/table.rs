@@ -0,0 +1,62 @@
+/// The value of the `Accept` header that requests server-side printing of the response as a [`Table`](crate::apimachinery::pkg::apis::meta::v1::Table).
+pub const TABLE_ACCEPT_HEADER_VALUE: &str = "application/json;as=Table;v=v1;g=meta.k8s.io";
+
+/// Which objects the API server includes in the [`TableRow::object`](crate::apimachinery::pkg::apis::meta::v1::TableRow::object) field
+/// of the rows of a [`Table`](crate::apimachinery::pkg::apis::meta::v1::Table), as requested by [`table_request`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum IncludeObjectPolicy {
+    /// The rows do not include the object.
+    None,
+
+    /// The rows include the metadata of the object as a [`PartialObjectMetadata`](crate::apimachinery::pkg::apis::meta::v1::PartialObjectMetadata). This is the default.
+    #[default]
+    Metadata,
+
+    /// The rows include the whole object.
+    Object,
+}
+
+impl IncludeObjectPolicy {
+    /// The value of the `includeObject` query parameter for this policy.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            IncludeObjectPolicy::None => "None",
+            IncludeObjectPolicy::Metadata => "Metadata",
+            IncludeObjectPolicy::Object => "Object",
+        }
+    }
+}
+
+/// Converts the request of a list or read operation into one that requests server-side printing of the response as a
+/// [`Table`](crate::apimachinery::pkg::apis::meta::v1::Table), like `kubectl get` does.
+///
+/// This sets the `Accept` header of the request to [`TABLE_ACCEPT_HEADER_VALUE`] and its `includeObject` query parameter to the given policy,
+/// and replaces the response type of the operation with [`TableResponse`].
+///
+/// ```rust,ignore
+/// let (request, response_body) = k8s_openapi::table_request(
+///     k8s_openapi::api::core::v1::Pod::list("kube-system", Default::default())?,
+///     k8s_openapi::IncludeObjectPolicy::None,
+/// )?;
+/// ```
+pub fn table_request<T>(
+    (mut request, _): (http::Request<Vec<u8>>, fn(http::StatusCode) -> crate::ResponseBody<T>),
+    include_object: IncludeObjectPolicy,
+) -> Result<(http::Request<Vec<u8>>, fn(http::StatusCode) -> crate::ResponseBody<TableResponse>), crate::RequestError> {
+    crate::_api::set_query_parameter(&mut request, "includeObject", [include_object.as_str()])?;
+    let _ = request.headers_mut().insert(http::header::ACCEPT, http::HeaderValue::from_static(TABLE_ACCEPT_HEADER_VALUE));
+    Ok((request, crate::ResponseBody::new))
+}
+
+/// Use `<TableResponse as Response>::try_from_parts` to parse the HTTP response body of a request returned by [`table_request`]
+#[derive(Debug)]
+pub enum TableResponse {
+    Ok(crate::apimachinery::pkg::apis::meta::v1::Table),
+    Other(Result<Option<serde_json::Value>, serde_json::Error>),
+}
+
+impl crate::Response for TableResponse {
+    fn try_from_parts(status_code: http::StatusCode, buf: &[u8]) -> Result<(Self, usize), crate::ResponseError> {
+        crate::_api::try_from_json_parts(status_code, buf, TableResponse::Ok, TableResponse::Other)
+    }
+}
@@ -2,6 +2,7 @@
 
 /// API server instances report the versions they can decode and the version they encode objects to when persisting objects in the backend.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct StorageVersionStatus {
     /// If all API server instances agree on the same encoding storage version, then this field is set to that version. Otherwise this field is left empty. API servers should finish updating its storageVersionStatus entry before serving write operations, so that this field will be in sync with the reality.
     pub common_encoding_version: Option<String>,
@@ -2,6 +2,7 @@
 
 /// DaemonSetStatus represents the current status of a daemon set.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct DaemonSetStatus {
     /// Count of hash collisions for the DaemonSet. The DaemonSet controller uses this field as a collision avoidance mechanism when it needs to create the name for the newest ControllerRevision.
     pub collision_count: Option<i32>,
@@ -1,6 +1,7 @@
 // Generated from definition io.k8s.api.certificates.v1beta1.CertificateSigningRequestCondition
 
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct CertificateSigningRequestCondition {
     /// lastTransitionTime is the time the condition last transitioned from one status to another. If unset, when a new condition type is added or an existing condition's status is changed, the server defaults this to the current time.
     pub last_transition_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
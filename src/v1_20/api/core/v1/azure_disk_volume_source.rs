@@ -2,6 +2,7 @@
 
 /// AzureDisk represents an Azure Data Disk mount on the host and bind mount to the pod.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct AzureDiskVolumeSource {
     /// Host Caching mode: None, Read Only, Read Write.
     pub caching_mode: Option<String>,
@@ -2,6 +2,7 @@
 
 /// ContainerStateRunning is a running state of a container.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ContainerStateRunning {
     /// Time at which the container was last (re-)started
     pub started_at: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
@@ -2,6 +2,7 @@
 
 /// Represents downward API info for projecting into a projected volume. Note that this is identical to a downwardAPI volume source without the default mode.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct DownwardAPIProjection {
     /// Items is a list of DownwardAPIVolume file
     pub items: Option<Vec<crate::api::core::v1::DownwardAPIVolumeFile>>,
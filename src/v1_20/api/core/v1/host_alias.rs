@@ -2,6 +2,7 @@
 
 /// HostAlias holds the mapping between IP and hostnames that will be injected as an entry in the pod's hosts file.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct HostAlias {
     /// Hostnames for the above IP address.
     pub hostnames: Option<Vec<String>>,
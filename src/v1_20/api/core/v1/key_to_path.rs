@@ -2,6 +2,7 @@
 
 /// Maps a string key to a path within a volume.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct KeyToPath {
     /// The key to project.
     pub key: String,
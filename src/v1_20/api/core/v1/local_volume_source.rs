@@ -2,6 +2,7 @@
 
 /// Local represents directly-attached storage with node affinity (Beta feature)
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct LocalVolumeSource {
     /// Filesystem type to mount. It applies only when the Path is a block device. Must be a filesystem type supported by the host operating system. Ex. "ext4", "xfs", "ntfs". The default value is to auto-select a fileystem if unspecified.
     pub fs_type: Option<String>,
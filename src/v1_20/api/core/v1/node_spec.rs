@@ -2,6 +2,7 @@
 
 /// NodeSpec describes the attributes that a node is created with.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct NodeSpec {
     /// If specified, the source to get node configuration from The DynamicKubeletConfig feature gate must be enabled for the Kubelet to use this field
     pub config_source: Option<crate::api::core::v1::NodeConfigSource>,
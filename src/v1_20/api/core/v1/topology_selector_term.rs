@@ -2,6 +2,7 @@
 
 /// A topology selector term represents the result of label queries. A null or empty topology selector term matches no objects. The requirements of them are ANDed. It provides a subset of functionality as NodeSelectorTerm. This is an alpha feature and may change in the future.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct TopologySelectorTerm {
     /// A list of topology selector requirements by labels.
     pub match_label_expressions: Option<Vec<crate::api::core::v1::TopologySelectorLabelRequirement>>,
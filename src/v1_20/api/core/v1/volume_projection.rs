@@ -2,6 +2,7 @@
 
 /// Projection that may be projected along with other supported volume types
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct VolumeProjection {
     /// information about the configMap data to project
     pub config_map: Option<crate::api::core::v1::ConfigMapProjection>,
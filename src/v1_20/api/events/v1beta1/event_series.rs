@@ -2,6 +2,7 @@
 
 /// EventSeries contain information on series of events, i.e. thing that was/is happening continuously for some time.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct EventSeries {
     /// count is the number of occurrences in this series up to the last heartbeat time.
     pub count: i32,
@@ -2,6 +2,7 @@
 
 /// PriorityLevelConfigurationSpec specifies the configuration of a priority level.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct PriorityLevelConfigurationSpec {
     /// `limited` specifies how requests are handled for a Limited priority level. This field must be non-empty if and only if `type` is `"Limited"`.
     pub limited: Option<crate::api::flowcontrol::v1alpha1::LimitedPriorityLevelConfiguration>,
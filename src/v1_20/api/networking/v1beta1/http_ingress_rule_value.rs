@@ -2,6 +2,7 @@
 
 /// HTTPIngressRuleValue is a list of http selectors pointing to backends. In the example: http://\<host\>/\<path\>?\<searchpart\> -\> backend where where parts of the url correspond to RFC 3986, this resource will be used to match against everything after the last '/' and before the first '?' or '#'.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct HTTPIngressRuleValue {
     /// A collection of paths that map requests to backends.
     pub paths: Vec<crate::api::networking::v1beta1::HTTPIngressPath>,
@@ -2,6 +2,7 @@
 
 /// RunAsUserStrategyOptions defines the strategy type and any options used to create the strategy.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct RunAsUserStrategyOptions {
     /// ranges are the allowed ranges of uids that may be used. If you would like to force a single uid then supply a single range with the same start and end. Required for MustRunAs.
     pub ranges: Option<Vec<crate::api::policy::v1beta1::IDRange>>,
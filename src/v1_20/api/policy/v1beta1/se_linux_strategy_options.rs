@@ -2,6 +2,7 @@
 
 /// SELinuxStrategyOptions defines the strategy type and any options used to create the strategy.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct SELinuxStrategyOptions {
     /// rule is the strategy that will dictate the allowable labels that may be set.
     pub rule: String,
@@ -2,6 +2,7 @@
 
 /// APIResourceList is a list of APIResource, it is used to expose the name of the resources supported in a specific group and version, and if the resource is namespaced.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct APIResourceList {
     /// groupVersion is the group and version this APIResourceList is for.
     pub group_version: String,
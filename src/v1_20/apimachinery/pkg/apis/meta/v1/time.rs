@@ -2,6 +2,7 @@
 
 /// Time is a wrapper around time.Time which supports correct marshaling to YAML and JSON.  Wrappers are provided for many of the factory methods that the time package offers.
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Hash))]
 pub struct Time(pub crate::chrono::DateTime<crate::chrono::Utc>);
 
 impl crate::DeepMerge for Time {
@@ -12,11 +12,29 @@ pub enum WatchEvent<T> {
     Added(T),
     Deleted(T),
     Modified(T),
-    Bookmark { resource_version: String },
+    /// A bookmark event. It only contains the resource version and the annotations of the watched resource type's metadata, not a whole object.
+    ///
+    /// Note that the `annotations` field was added to this variant to support [`WatchEvent::is_initial_events_end`].
+    /// Patterns that match this variant exhaustively, like `Bookmark { resource_version }`, need to match it as `Bookmark { resource_version, .. }` instead.
+    Bookmark { annotations: std::collections::BTreeMap<String, String>, resource_version: String },
     ErrorStatus(crate::apimachinery::pkg::apis::meta::v1::Status),
     ErrorOther(crate::apimachinery::pkg::runtime::RawExtension),
 }
 
+impl<T> WatchEvent<T> {
+    /// Whether this is the bookmark that the API server sends after the initial events of a watch that was requested with `sendInitialEvents` set to `true`,
+    /// ie a bookmark with the `k8s.io/initial-events-end` annotation set to `"true"`.
+    ///
+    /// The events before this bookmark describe the state of the watched objects as of the resource version of the bookmark,
+    /// so such a watch can be used in place of a list followed by a watch.
+    pub fn is_initial_events_end(&self) -> bool {
+        matches!(
+            self,
+            WatchEvent::Bookmark { annotations, .. } if annotations.get("k8s.io/initial-events-end").map(String::as_str) == Some("true"),
+        )
+    }
+}
+
 impl<'de, T> crate::serde::Deserialize<'de> for WatchEvent<T> where T: crate::serde::Deserialize<'de> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -129,6 +147,7 @@ impl<'de, T> crate::serde::Deserialize<'de> for WatchEvent<T> where T: crate::se
                         let value_object = crate::serde_value::ValueDeserializer::new(value_object);
                         let value: BookmarkObject<'static> = crate::serde::Deserialize::deserialize(value_object)?;
                         WatchEvent::Bookmark {
+                            annotations: value.metadata.annotations.into_owned(),
                             resource_version: value.metadata.resource_version.into_owned(),
                         }
                     },
@@ -182,10 +201,11 @@ impl<T> crate::serde::Serialize for WatchEvent<T> where T: crate::serde::Seriali
                 crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", "MODIFIED")?;
                 crate::serde::ser::SerializeStruct::serialize_field(&mut state, "object", &object)?;
             },
-            WatchEvent::Bookmark { resource_version } => {
+            WatchEvent::Bookmark { annotations, resource_version } => {
                 crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", "BOOKMARK")?;
                 let object = BookmarkObject {
                     metadata: BookmarkObjectMeta {
+                        annotations: std::borrow::Cow::Borrowed(annotations),
                         resource_version: std::borrow::Cow::Borrowed(&**resource_version),
                     },
                 };
@@ -211,6 +231,7 @@ struct BookmarkObject<'a> {
 
 #[derive(Debug, PartialEq)]
 struct BookmarkObjectMeta<'a> {
+    annotations: std::borrow::Cow<'a, std::collections::BTreeMap<String, String>>,
     resource_version: std::borrow::Cow<'a, str>,
 }
 
@@ -284,6 +305,7 @@ impl<'de> crate::serde::Deserialize<'de> for BookmarkObjectMeta<'static> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
         enum Field {
+            Key_annotations,
             Key_resource_version,
             Other,
         }
@@ -301,6 +323,7 @@ impl<'de> crate::serde::Deserialize<'de> for BookmarkObjectMeta<'static> {
 
                     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
                         Ok(match v {
+                            "annotations" => Field::Key_annotations,
                             "resourceVersion" => Field::Key_resource_version,
                             _ => Field::Other,
                         })
@@ -321,16 +344,19 @@ impl<'de> crate::serde::Deserialize<'de> for BookmarkObjectMeta<'static> {
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_annotations: Option<std::collections::BTreeMap<String, String>> = None;
                 let mut value_resource_version: Option<String> = None;
 
                 while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
                     match key {
+                        Field::Key_annotations => value_annotations = crate::serde::de::MapAccess::next_value(&mut map)?,
                         Field::Key_resource_version => value_resource_version = crate::serde::de::MapAccess::next_value(&mut map)?,
                         Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(BookmarkObjectMeta {
+                    annotations: std::borrow::Cow::Owned(value_annotations.unwrap_or_default()),
                     resource_version: std::borrow::Cow::Owned(value_resource_version.ok_or_else(|| crate::serde::de::Error::missing_field("resourceVersion"))?),
                 })
             }
@@ -339,6 +365,7 @@ impl<'de> crate::serde::Deserialize<'de> for BookmarkObjectMeta<'static> {
         deserializer.deserialize_struct(
             "ObjectMeta",
             &[
+                "annotations",
                 "resourceVersion",
             ],
             Visitor,
@@ -361,8 +388,12 @@ impl<'a> crate::serde::Serialize for BookmarkObjectMeta<'a> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
         let mut state = serializer.serialize_struct(
             "ObjectMeta",
-            1,
+            1 +
+            usize::from(!self.annotations.is_empty()),
         )?;
+        if !self.annotations.is_empty() {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "annotations", &self.annotations)?;
+        }
         crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceVersion", &self.resource_version)?;
         crate::serde::ser::SerializeStruct::end(state)
     }
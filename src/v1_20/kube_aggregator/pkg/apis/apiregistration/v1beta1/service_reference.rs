@@ -2,6 +2,7 @@
 
 /// ServiceReference holds a reference to Service.legacy.k8s.io
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ServiceReference {
     /// Name is the name of the service
     pub name: Option<String>,
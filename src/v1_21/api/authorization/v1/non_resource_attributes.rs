@@ -2,6 +2,7 @@
 
 /// NonResourceAttributes includes the authorization attributes available for non-resource requests to the Authorizer interface
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct NonResourceAttributes {
     /// Path is the URL path of the request
     pub path: Option<String>,
@@ -1,6 +1,7 @@
 // Generated from definition io.k8s.api.authorization.v1.SelfSubjectRulesReviewSpec
 
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct SelfSubjectRulesReviewSpec {
     /// Namespace to evaluate rules for. Required.
     pub namespace: Option<String>,
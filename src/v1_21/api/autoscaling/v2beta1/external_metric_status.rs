@@ -2,6 +2,7 @@
 
 /// ExternalMetricStatus indicates the current value of a global metric not associated with any Kubernetes object.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ExternalMetricStatus {
     /// currentAverageValue is the current value of metric averaged over autoscaled pods.
     pub current_average_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
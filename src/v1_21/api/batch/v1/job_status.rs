@@ -2,6 +2,7 @@
 
 /// JobStatus represents the current state of a Job.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct JobStatus {
     /// The number of actively running pods.
     pub active: Option<i32>,
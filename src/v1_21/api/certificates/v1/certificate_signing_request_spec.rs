@@ -2,6 +2,7 @@
 
 /// CertificateSigningRequestSpec contains the certificate request.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct CertificateSigningRequestSpec {
     /// extra contains extra attributes of the user that created the CertificateSigningRequest. Populated by the API server on creation and immutable.
     pub extra: Option<std::collections::BTreeMap<String, Vec<String>>>,
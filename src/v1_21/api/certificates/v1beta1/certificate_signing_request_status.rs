@@ -1,6 +1,7 @@
 // Generated from definition io.k8s.api.certificates.v1beta1.CertificateSigningRequestStatus
 
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct CertificateSigningRequestStatus {
     /// If request was approved, the controller will place the issued certificate here.
     pub certificate: Option<crate::ByteString>,
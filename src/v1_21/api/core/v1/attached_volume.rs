@@ -2,6 +2,7 @@
 
 /// AttachedVolume describes a volume attached to a node
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct AttachedVolume {
     /// DevicePath represents the device path where the volume should be available
     pub device_path: String,
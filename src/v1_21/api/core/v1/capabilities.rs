@@ -2,6 +2,7 @@
 
 /// Adds and removes POSIX capabilities from running containers.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct Capabilities {
     /// Added capabilities
     pub add: Option<Vec<String>>,
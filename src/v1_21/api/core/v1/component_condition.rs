@@ -2,6 +2,7 @@
 
 /// Information about the condition of a component.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ComponentCondition {
     /// Condition error code for a component. For example, a health check error code.
     pub error: Option<String>,
@@ -2,6 +2,7 @@
 
 /// EnvVar represents an environment variable present in a Container.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct EnvVar {
     /// Name of the environment variable. Must be a C_IDENTIFIER.
     pub name: String,
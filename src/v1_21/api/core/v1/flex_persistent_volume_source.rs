@@ -2,6 +2,7 @@
 
 /// FlexPersistentVolumeSource represents a generic persistent volume resource that is provisioned/attached using an exec based plugin.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct FlexPersistentVolumeSource {
     /// Driver is the name of the driver to use for this volume.
     pub driver: String,
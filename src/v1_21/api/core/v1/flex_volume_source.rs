@@ -2,6 +2,7 @@
 
 /// FlexVolume represents a generic volume resource that is provisioned/attached using an exec based plugin.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct FlexVolumeSource {
     /// Driver is the name of the driver to use for this volume.
     pub driver: String,
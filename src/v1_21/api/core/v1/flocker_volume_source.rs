@@ -2,6 +2,7 @@
 
 /// Represents a Flocker volume mounted by the Flocker agent. One and only one of datasetName and datasetUUID should be set. Flocker volumes do not support ownership management or SELinux relabeling.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct FlockerVolumeSource {
     /// Name of the dataset stored as metadata -\> name on the dataset for Flocker should be considered as deprecated
     pub dataset_name: Option<String>,
@@ -2,6 +2,7 @@
 
 /// A scope selector represents the AND of the selectors represented by the scoped-resource selector requirements.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ScopeSelector {
     /// A list of scope selector requirements by scope of the resources.
     pub match_expressions: Option<Vec<crate::api::core::v1::ScopedResourceSelectorRequirement>>,
@@ -2,6 +2,7 @@
 
 /// VolumeNodeAffinity defines constraints that limit what nodes this volume can be accessed from.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct VolumeNodeAffinity {
     /// Required specifies hard node constraints that must be met.
     pub required: Option<crate::api::core::v1::NodeSelector>,
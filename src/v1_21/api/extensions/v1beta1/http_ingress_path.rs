@@ -2,6 +2,7 @@
 
 /// HTTPIngressPath associates a path with a backend. Incoming urls matching the path are forwarded to the backend.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct HTTPIngressPath {
     /// Backend defines the referenced service endpoint to which the traffic will be forwarded to.
     pub backend: crate::api::extensions::v1beta1::IngressBackend,
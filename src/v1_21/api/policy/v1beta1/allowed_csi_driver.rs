@@ -2,6 +2,7 @@
 
 /// AllowedCSIDriver represents a single inline CSI Driver that is allowed to be used.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct AllowedCSIDriver {
     /// Name is the registered name of the CSI driver
     pub name: String,
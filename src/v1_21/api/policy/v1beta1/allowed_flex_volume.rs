@@ -2,6 +2,7 @@
 
 /// AllowedFlexVolume represents a single Flexvolume that is allowed to be used.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct AllowedFlexVolume {
     /// driver is the name of the Flexvolume driver.
     pub driver: String,
@@ -2,6 +2,7 @@
 
 /// RoleRef contains information that points to the role being used
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct RoleRef {
     /// APIGroup is the group for the resource being referenced
     pub api_group: String,
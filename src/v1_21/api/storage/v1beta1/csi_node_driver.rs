@@ -2,6 +2,7 @@
 
 /// CSINodeDriver holds information about the specification of one CSI driver installed on a node
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct CSINodeDriver {
     /// allocatable represents the volume resources of a node that are available for scheduling.
     pub allocatable: Option<crate::api::storage::v1beta1::VolumeNodeResources>,
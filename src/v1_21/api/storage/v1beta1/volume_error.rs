@@ -2,6 +2,7 @@
 
 /// VolumeError captures an error encountered during a volume operation.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct VolumeError {
     /// String detailing the error encountered during Attach or Detach operation. This string may be logged, so it should not contain sensitive information.
     pub message: Option<String>,
@@ -2,6 +2,7 @@
 
 /// CustomResourceColumnDefinition specifies a column for server side printing.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct CustomResourceColumnDefinition {
     /// description is a human readable description of this column.
     pub description: Option<String>,
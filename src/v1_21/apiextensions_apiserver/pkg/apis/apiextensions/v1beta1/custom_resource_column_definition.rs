@@ -2,6 +2,7 @@
 
 /// CustomResourceColumnDefinition specifies a column for server side printing.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct CustomResourceColumnDefinition {
     /// JSONPath is a simple JSON path (i.e. with array notation) which is evaluated against each custom resource to produce the value for this column.
     pub json_path: String,
@@ -2,6 +2,7 @@
 
 /// Condition contains details for one aspect of the current state of this API Resource.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct Condition {
     /// lastTransitionTime is the last time the condition transitioned from one status to another. This should be when the underlying condition changed.  If that is not known, then using the time when the API field changed is acceptable.
     pub last_transition_time: crate::apimachinery::pkg::apis::meta::v1::Time,
@@ -2,6 +2,7 @@
 
 /// MicroTime is version of Time with microsecond level precision.
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Hash))]
 pub struct MicroTime(pub crate::chrono::DateTime<crate::chrono::Utc>);
 
 impl crate::DeepMerge for MicroTime {
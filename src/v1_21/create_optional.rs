@@ -3,9 +3,10 @@
 /// Common parameters for all create operations.
 #[cfg(feature = "api")]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct CreateOptional<'a> {
     /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
-    pub dry_run: Option<&'a str>,
+    pub dry_run: Option<&'a [crate::DryRun]>,
 
     /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint.
     pub field_manager: Option<&'a str>,
@@ -22,7 +23,9 @@ impl<'a> CreateOptional<'a> {
         __query_pairs: &mut crate::url::form_urlencoded::Serializer<'_, T>,
     ) where T: crate::url::form_urlencoded::Target {
         if let Some(value) = self.dry_run {
-            __query_pairs.append_pair("dryRun", value);
+            for value in value {
+                __query_pairs.append_pair("dryRun", &value.to_string());
+            }
         }
         if let Some(value) = self.field_manager {
             __query_pairs.append_pair("fieldManager", value);
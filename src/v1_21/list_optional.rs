@@ -3,6 +3,7 @@
 /// Common parameters for all list operations.
 #[cfg(feature = "api")]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ListOptional<'a> {
     /// The continue option should be set when retrieving more results from the server. Since this value is server defined, clients may only use the continue value from a previous query result with identical query parameters (except for the value of continue) and the server may reject a continue value it does not recognize. If the specified continue value is no longer valid whether due to expiration (generally five to fifteen minutes) or a configuration change on the server, the server will respond with a 410 ResourceExpired error together with a continue token. If the client needs a consistent list, it must restart their list without the continue field. Otherwise, the client may send another list request with the token received with the 410 error, the server will respond with a list starting from the next key, but from the latest snapshot, which is inconsistent from the previous list results - objects that are created, modified, or deleted after the first list request will be included in the response, as long as their keys are after the "next key".
     ///
@@ -28,7 +29,7 @@ pub struct ListOptional<'a> {
     /// resourceVersionMatch determines how resourceVersion is applied to list calls. It is highly recommended that resourceVersionMatch be set for list calls where resourceVersion is set See https://kubernetes.io/docs/reference/using-api/api-concepts/#resource-versions for details.
     ///
     /// Defaults to unset
-    pub resource_version_match: Option<&'a str>,
+    pub resource_version_match: Option<crate::ResourceVersionMatch>,
 
     /// Timeout for the list/watch call. This limits the duration of the call, regardless of any activity or inactivity.
     pub timeout_seconds: Option<i64>,
@@ -60,7 +61,7 @@ impl<'a> ListOptional<'a> {
             __query_pairs.append_pair("resourceVersion", value);
         }
         if let Some(value) = self.resource_version_match {
-            __query_pairs.append_pair("resourceVersionMatch", value);
+            __query_pairs.append_pair("resourceVersionMatch", &value.to_string());
         }
         if let Some(value) = self.timeout_seconds {
             __query_pairs.append_pair("timeoutSeconds", &value.to_string());
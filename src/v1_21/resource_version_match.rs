@@ -0,0 +1,37 @@
+// Generated from definition io.k8s.ResourceVersionMatch
+
+/// How the `resourceVersion` parameter is applied to list calls.
+#[cfg(feature = "api")]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ResourceVersionMatch {
+    /// Return data at the exact resource version provided.
+    Exact,
+
+    /// Return data at least as new as the resource version provided.
+    NotOlderThan,
+}
+
+#[cfg(feature = "api")]
+impl ResourceVersionMatch {
+    /// The value of this parameter as it is sent to the API server.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ResourceVersionMatch::Exact => "Exact",
+            ResourceVersionMatch::NotOlderThan => "NotOlderThan",
+        }
+    }
+}
+
+#[cfg(feature = "api")]
+impl std::fmt::Display for ResourceVersionMatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "api")]
+impl crate::serde::Serialize for ResourceVersionMatch {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        serializer.serialize_str(self.as_str())
+    }
+}
@@ -3,6 +3,7 @@
 /// Common parameters for all watch operations.
 #[cfg(feature = "api")]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct WatchOptional<'a> {
     /// allowWatchBookmarks requests watch events with type "BOOKMARK". Servers that do not implement bookmarks may ignore this flag and bookmarks are sent at the server's discretion. Clients should not assume bookmarks are returned at any specific interval, nor may they assume the server will send any BOOKMARK event during a session. If this is not a watch, this field is ignored. If the feature gate WatchBookmarks is not enabled in apiserver, this field is ignored.
     pub allow_watch_bookmarks: Option<bool>,
@@ -18,6 +19,18 @@ pub struct WatchOptional<'a> {
     /// Defaults to unset
     pub resource_version: Option<&'a str>,
 
+    /// resourceVersionMatch determines how resourceVersion is applied to list calls. It is highly recommended that resourceVersionMatch be set for list calls where resourceVersion is set See https://kubernetes.io/docs/reference/using-api/api-concepts/#resource-versions for details.
+    ///
+    /// Defaults to unset
+    pub resource_version_match: Option<crate::ResourceVersionMatch>,
+
+    /// `sendInitialEvents=true` may be set together with `watch=true`. In that case, the watch stream will begin with synthetic events to produce the current state of objects in the collection. Once all such events have been sent, a synthetic "Bookmark" event will be sent. The bookmark will report the ResourceVersion (RV) corresponding to the set of objects, and be marked with `"k8s.io/initial-events-end": "true"` annotation. Afterwards, the watch stream will proceed as usual, sending watch events corresponding to changes (subsequent to the RV) to objects watched.
+    ///
+    /// When `sendInitialEvents` option is set, we require `resourceVersionMatch` option to also be set to `NotOlderThan`.
+    ///
+    /// Requires Kubernetes 1.27 or later with the `WatchList` feature gate enabled.
+    pub send_initial_events: Option<bool>,
+
     /// Timeout for the list/watch call. This limits the duration of the call, regardless of any activity or inactivity.
     pub timeout_seconds: Option<i64>,
 }
@@ -44,6 +57,12 @@ impl<'a> WatchOptional<'a> {
         if let Some(value) = self.resource_version {
             __query_pairs.append_pair("resourceVersion", value);
         }
+        if let Some(value) = self.resource_version_match {
+            __query_pairs.append_pair("resourceVersionMatch", &value.to_string());
+        }
+        if let Some(value) = self.send_initial_events {
+            __query_pairs.append_pair("sendInitialEvents", if value { "true" } else { "false" });
+        }
         if let Some(value) = self.timeout_seconds {
             __query_pairs.append_pair("timeoutSeconds", &value.to_string());
         }
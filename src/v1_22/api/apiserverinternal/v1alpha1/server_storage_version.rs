@@ -2,6 +2,7 @@
 
 /// An API server instance reports the version it can decode and the version it encodes objects to when persisting objects in the backend.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ServerStorageVersion {
     /// The ID of the reporting API server.
     pub api_server_id: Option<String>,
@@ -2,6 +2,7 @@
 
 /// ResourceAttributes includes the authorization attributes available for resource requests to the Authorizer interface
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ResourceAttributes {
     /// Group is the API Group of the Resource.  "*" means all.
     pub group: Option<String>,
@@ -2,6 +2,7 @@
 
 /// ObjectMetricSource indicates how to scale on a metric describing a kubernetes object (for example, hits-per-second on an Ingress object).
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ObjectMetricSource {
     /// averageValue is the target value of the average of the metric across all relevant pods (as a quantity)
     pub average_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
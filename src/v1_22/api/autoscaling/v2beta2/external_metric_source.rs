@@ -2,6 +2,7 @@
 
 /// ExternalMetricSource indicates how to scale on a metric not associated with any Kubernetes object (for example length of queue in cloud messaging service, or QPS from loadbalancer running outside of cluster).
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ExternalMetricSource {
     /// metric identifies the target metric by name and selector
     pub metric: crate::api::autoscaling::v2beta2::MetricIdentifier,
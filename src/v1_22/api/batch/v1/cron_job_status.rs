@@ -2,6 +2,7 @@
 
 /// CronJobStatus represents the current state of a cron job.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct CronJobStatus {
     /// A list of pointers to currently running jobs.
     pub active: Option<Vec<crate::api::core::v1::ObjectReference>>,
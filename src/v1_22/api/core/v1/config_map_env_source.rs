@@ -4,6 +4,7 @@
 ///
 /// The contents of the target ConfigMap's Data field will represent the key-value pairs as environment variables.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ConfigMapEnvSource {
     /// Name of the referent. More info: https://kubernetes.io/docs/concepts/overview/working-with-objects/names/#names
     pub name: Option<String>,
@@ -2,6 +2,7 @@
 
 /// NodeSystemInfo is a set of ids/uuids to uniquely identify the node.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct NodeSystemInfo {
     /// The Architecture reported by the node
     pub architecture: String,
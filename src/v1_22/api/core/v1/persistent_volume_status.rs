@@ -2,6 +2,7 @@
 
 /// PersistentVolumeStatus is the current status of a persistent volume.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct PersistentVolumeStatus {
     /// A human-readable message indicating details about why the volume is in this state.
     pub message: Option<String>,
@@ -2,6 +2,7 @@
 
 /// EndpointHints provides hints describing how an endpoint should be consumed.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct EndpointHints {
     /// forZones indicates the zone(s) this endpoint should be consumed by to enable topology aware routing.
     pub for_zones: Option<Vec<crate::api::discovery::v1::ForZone>>,
@@ -2,6 +2,7 @@
 
 /// IPBlock describes a particular CIDR (Ex. "192.168.1.1/24","2001:db9::/64") that is allowed to the pods matched by a NetworkPolicySpec's podSelector. The except entry describes CIDRs that should not be included within this rule.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct IPBlock {
     /// CIDR is a string representing the IP Block Valid examples are "192.168.1.1/24" or "2001:db9::/64"
     pub cidr: String,
@@ -2,6 +2,7 @@
 
 /// Info contains versioning information. how we'll want to distribute that information.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct Info {
     pub build_date: String,
 
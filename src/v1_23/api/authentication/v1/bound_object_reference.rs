@@ -2,6 +2,7 @@
 
 /// BoundObjectReference is a reference to an object that a token is bound to.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct BoundObjectReference {
     /// API version of the referent.
     pub api_version: Option<String>,
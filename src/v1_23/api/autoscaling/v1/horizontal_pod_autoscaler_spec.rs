@@ -2,6 +2,7 @@
 
 /// specification of a horizontal pod autoscaler.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct HorizontalPodAutoscalerSpec {
     /// upper limit for the number of pods that can be set by the autoscaler; cannot be smaller than MinReplicas.
     pub max_replicas: i32,
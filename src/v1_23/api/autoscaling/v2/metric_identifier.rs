@@ -2,6 +2,7 @@
 
 /// MetricIdentifier defines the name and optionally selector for a metric
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct MetricIdentifier {
     /// name is the name of the given metric
     pub name: String,
@@ -2,6 +2,7 @@
 
 /// MetricSpec specifies how to scale based on a single metric (only `type` and one other matching field should be set at once).
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct MetricSpec {
     /// container resource refers to a resource metric (such as those specified in requests and limits) known to Kubernetes describing a single container in each pod of the current scale target (e.g. CPU or memory). Such metrics are built in to Kubernetes, and have special scaling options on top of those available to normal per-pod metrics using the "pods" source. This is an alpha feature and can be enabled by the HPAContainerMetrics feature flag.
     pub container_resource: Option<crate::api::autoscaling::v2beta1::ContainerResourceMetricSource>,
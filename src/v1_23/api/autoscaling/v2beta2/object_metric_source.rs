@@ -2,6 +2,7 @@
 
 /// ObjectMetricSource indicates how to scale on a metric describing a kubernetes object (for example, hits-per-second on an Ingress object).
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ObjectMetricSource {
     pub described_object: crate::api::autoscaling::v2beta2::CrossVersionObjectReference,
 
@@ -2,6 +2,7 @@
 
 /// JobCondition describes current state of a job.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct JobCondition {
     /// Last time the condition was checked.
     pub last_probe_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
@@ -2,6 +2,7 @@
 
 /// Selects a key from a ConfigMap.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ConfigMapKeySelector {
     /// The key to select.
     pub key: String,
@@ -2,6 +2,7 @@
 
 /// Represents storage that is managed by an external CSI volume driver (Beta feature)
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct CSIPersistentVolumeSource {
     /// ControllerExpandSecretRef is a reference to the secret object containing sensitive information to pass to the CSI driver to complete the CSI ControllerExpandVolume call. This is an alpha field and requires enabling ExpandCSIVolumes feature gate. This field is optional, and may be empty if no secret is required. If the secret object contains more than one secret, all secrets are passed.
     pub controller_expand_secret_ref: Option<crate::api::core::v1::SecretReference>,
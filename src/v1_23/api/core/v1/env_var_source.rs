@@ -2,6 +2,7 @@
 
 /// EnvVarSource represents a source for the value of an EnvVar.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct EnvVarSource {
     /// Selects a key of a ConfigMap.
     pub config_map_key_ref: Option<crate::api::core::v1::ConfigMapKeySelector>,
@@ -2,6 +2,7 @@
 
 /// ISCSIPersistentVolumeSource represents an ISCSI disk. ISCSI volumes can only be mounted as read/write once. ISCSI volumes support ownership management and SELinux relabeling.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ISCSIPersistentVolumeSource {
     /// whether support iSCSI Discovery CHAP authentication
     pub chap_auth_discovery: Option<bool>,
@@ -2,6 +2,7 @@
 
 /// LifecycleHandler defines a specific action that should be taken in a lifecycle hook. One and only one of the fields, except TCPSocket must be specified.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct LifecycleHandler {
     /// Exec specifies the action to take.
     pub exec: Option<crate::api::core::v1::ExecAction>,
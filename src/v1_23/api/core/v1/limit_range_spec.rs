@@ -2,6 +2,7 @@
 
 /// LimitRangeSpec defines a min/max usage limit for resources that match on kind.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct LimitRangeSpec {
     /// Limits is the list of LimitRangeItem objects that are enforced.
     pub limits: Vec<crate::api::core::v1::LimitRangeItem>,
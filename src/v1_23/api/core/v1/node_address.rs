@@ -2,6 +2,7 @@
 
 /// NodeAddress contains information for the node's address.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct NodeAddress {
     /// The node address.
     pub address: String,
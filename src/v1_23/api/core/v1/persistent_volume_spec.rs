@@ -2,6 +2,7 @@
 
 /// PersistentVolumeSpec is the specification of a persistent volume.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct PersistentVolumeSpec {
     /// AccessModes contains all ways the volume can be mounted. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#access-modes
     pub access_modes: Option<Vec<String>>,
@@ -2,6 +2,7 @@
 
 /// Probe describes a health check to be performed against a container to determine whether it is alive or ready to receive traffic.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct Probe {
     /// Exec specifies the action to take.
     pub exec: Option<crate::api::core::v1::ExecAction>,
@@ -2,6 +2,7 @@
 
 /// ReplicationControllerCondition describes the state of a replication controller at a certain point.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ReplicationControllerCondition {
     /// The last time the condition transitioned from one status to another.
     pub last_transition_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
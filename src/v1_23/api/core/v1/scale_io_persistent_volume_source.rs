@@ -2,6 +2,7 @@
 
 /// ScaleIOPersistentVolumeSource represents a persistent ScaleIO volume
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ScaleIOPersistentVolumeSource {
     /// Filesystem type to mount. Must be a filesystem type supported by the host operating system. Ex. "ext4", "xfs", "ntfs". Default is "xfs"
     pub fs_type: Option<String>,
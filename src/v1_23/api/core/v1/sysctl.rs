@@ -2,6 +2,7 @@
 
 /// Sysctl defines a kernel parameter to be set
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct Sysctl {
     /// Name of a property to set
     pub name: String,
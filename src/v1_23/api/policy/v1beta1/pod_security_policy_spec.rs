@@ -2,6 +2,7 @@
 
 /// PodSecurityPolicySpec defines the policy enforced.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct PodSecurityPolicySpec {
     /// allowPrivilegeEscalation determines if a pod can request to allow privilege escalation. If unspecified, defaults to true.
     pub allow_privilege_escalation: Option<bool>,
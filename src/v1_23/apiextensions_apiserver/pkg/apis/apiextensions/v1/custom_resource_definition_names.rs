@@ -2,6 +2,7 @@
 
 /// CustomResourceDefinitionNames indicates the names to serve this CustomResourceDefinition
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct CustomResourceDefinitionNames {
     /// categories is a list of grouped resources this custom resource belongs to (e.g. 'all'). This is published in API discovery documents, and used by clients to support invocations like `kubectl get all`.
     pub categories: Option<Vec<String>>,
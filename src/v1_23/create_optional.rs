@@ -3,15 +3,16 @@
 /// Common parameters for all create operations.
 #[cfg(feature = "api")]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct CreateOptional<'a> {
     /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
-    pub dry_run: Option<&'a str>,
+    pub dry_run: Option<&'a [crate::DryRun]>,
 
     /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint.
     pub field_manager: Option<&'a str>,
 
     /// fieldValidation determines how the server should respond to unknown/duplicate fields in the object in the request. Introduced as alpha in 1.23, older servers or servers with the `ServerSideFieldValidation` feature disabled will discard valid values specified in  this param and not perform any server side field validation. Valid values are: - Ignore: ignores unknown/duplicate fields. - Warn: responds with a warning for each unknown/duplicate field, but successfully serves the request. - Strict: fails the request on unknown/duplicate fields.
-    pub field_validation: Option<&'a str>,
+    pub field_validation: Option<crate::FieldValidation>,
 }
 
 #[cfg(feature = "api")]
@@ -25,13 +26,15 @@ impl<'a> CreateOptional<'a> {
         __query_pairs: &mut crate::url::form_urlencoded::Serializer<'_, T>,
     ) where T: crate::url::form_urlencoded::Target {
         if let Some(value) = self.dry_run {
-            __query_pairs.append_pair("dryRun", value);
+            for value in value {
+                __query_pairs.append_pair("dryRun", &value.to_string());
+            }
         }
         if let Some(value) = self.field_manager {
             __query_pairs.append_pair("fieldManager", value);
         }
         if let Some(value) = self.field_validation {
-            __query_pairs.append_pair("fieldValidation", value);
+            __query_pairs.append_pair("fieldValidation", &value.to_string());
         }
     }
 }
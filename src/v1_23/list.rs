@@ -2,6 +2,7 @@
 
 /// List is a list of resources.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct List<T> where T: crate::ListableResource {
     /// List of objects.
     pub items: Vec<T>,
@@ -3,15 +3,16 @@
 /// Common parameters for all patch operations.
 #[cfg(feature = "api")]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct PatchOptional<'a> {
     /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
-    pub dry_run: Option<&'a str>,
+    pub dry_run: Option<&'a [crate::DryRun]>,
 
     /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint. This field is required for apply requests (application/apply-patch) but optional for non-apply patch types (JsonPatch, MergePatch, StrategicMergePatch).
     pub field_manager: Option<&'a str>,
 
     /// fieldValidation determines how the server should respond to unknown/duplicate fields in the object in the request. Introduced as alpha in 1.23, older servers or servers with the `ServerSideFieldValidation` feature disabled will discard valid values specified in  this param and not perform any server side field validation. Valid values are: - Ignore: ignores unknown/duplicate fields. - Warn: responds with a warning for each unknown/duplicate field, but successfully serves the request. - Strict: fails the request on unknown/duplicate fields.
-    pub field_validation: Option<&'a str>,
+    pub field_validation: Option<crate::FieldValidation>,
 
     /// Force is going to "force" Apply requests. It means user will re-acquire conflicting fields owned by other people. Force flag must be unset for non-apply patch requests.
     pub force: Option<bool>,
@@ -28,13 +29,15 @@ impl<'a> PatchOptional<'a> {
         __query_pairs: &mut crate::url::form_urlencoded::Serializer<'_, T>,
     ) where T: crate::url::form_urlencoded::Target {
         if let Some(value) = self.dry_run {
-            __query_pairs.append_pair("dryRun", value);
+            for value in value {
+                __query_pairs.append_pair("dryRun", &value.to_string());
+            }
         }
         if let Some(value) = self.field_manager {
             __query_pairs.append_pair("fieldManager", value);
         }
         if let Some(value) = self.field_validation {
-            __query_pairs.append_pair("fieldValidation", value);
+            __query_pairs.append_pair("fieldValidation", &value.to_string());
         }
         if let Some(value) = self.force {
             __query_pairs.append_pair("force", if value { "true" } else { "false" });
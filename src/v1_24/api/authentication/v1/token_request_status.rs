@@ -2,6 +2,7 @@
 
 /// TokenRequestStatus is the result of a token request.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct TokenRequestStatus {
     /// ExpirationTimestamp is the time of expiration of the returned token.
     pub expiration_timestamp: crate::apimachinery::pkg::apis::meta::v1::Time,
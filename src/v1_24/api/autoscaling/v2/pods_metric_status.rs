@@ -2,6 +2,7 @@
 
 /// PodsMetricStatus indicates the current value of a metric describing each pod in the current scale target (for example, transactions-processed-per-second).
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct PodsMetricStatus {
     /// current contains the current value for the given metric
     pub current: crate::api::autoscaling::v2::MetricValueStatus,
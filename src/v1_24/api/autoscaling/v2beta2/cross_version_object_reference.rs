@@ -2,6 +2,7 @@
 
 /// CrossVersionObjectReference contains enough information to let you identify the referred resource.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct CrossVersionObjectReference {
     /// API version of the referent
     pub api_version: Option<String>,
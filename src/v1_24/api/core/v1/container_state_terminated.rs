@@ -2,6 +2,7 @@
 
 /// ContainerStateTerminated is a terminated state of a container.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ContainerStateTerminated {
     /// Container's ID in the format '\<type\>://\<container_id\>'
     pub container_id: Option<String>,
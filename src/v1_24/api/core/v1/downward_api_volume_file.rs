@@ -2,6 +2,7 @@
 
 /// DownwardAPIVolumeFile represents information to create the file containing the pod field
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct DownwardAPIVolumeFile {
     /// Required: Selects a field of the pod: only annotations, labels, name and namespace are supported.
     pub field_ref: Option<crate::api::core::v1::ObjectFieldSelector>,
@@ -2,6 +2,7 @@
 
 /// EndpointAddress is a tuple that describes single IP address.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct EndpointAddress {
     /// The Hostname of this endpoint
     pub hostname: Option<String>,
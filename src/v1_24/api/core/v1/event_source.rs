@@ -2,6 +2,7 @@
 
 /// EventSource contains information for an event.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct EventSource {
     /// Component from which the event is generated.
     pub component: Option<String>,
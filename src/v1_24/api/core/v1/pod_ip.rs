@@ -4,6 +4,7 @@
 ///
 ///   IP: An IP address allocated to the pod. Routable at least within the cluster.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct PodIP {
     /// ip is an IP address (IPv4 or IPv6) assigned to the pod
     pub ip: Option<String>,
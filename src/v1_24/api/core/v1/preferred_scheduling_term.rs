@@ -2,6 +2,7 @@
 
 /// An empty preferred scheduling term matches all objects with implicit weight 0 (i.e. it's a no-op). A null preferred scheduling term matches no objects (i.e. is also a no-op).
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct PreferredSchedulingTerm {
     /// A node selector term, associated with the corresponding weight.
     pub preference: crate::api::core::v1::NodeSelectorTerm,
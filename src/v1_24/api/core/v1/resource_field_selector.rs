@@ -2,6 +2,7 @@
 
 /// ResourceFieldSelector represents container resources (cpu, memory) and their output format
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ResourceFieldSelector {
     /// Container name: required for volumes, optional for env vars
     pub container_name: Option<String>,
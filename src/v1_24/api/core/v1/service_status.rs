@@ -2,6 +2,7 @@
 
 /// ServiceStatus represents the current status of a service.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ServiceStatus {
     /// Current service state
     pub conditions: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::Condition>>,
@@ -2,6 +2,7 @@
 
 /// NetworkPolicyStatus describe the current state of the NetworkPolicy.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct NetworkPolicyStatus {
     /// Conditions holds an array of metav1.Condition that describe the state of the NetworkPolicy. Current service state
     pub conditions: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::Condition>>,
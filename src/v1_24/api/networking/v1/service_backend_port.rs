@@ -2,6 +2,7 @@
 
 /// ServiceBackendPort is the service port being referenced.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ServiceBackendPort {
     /// Name is the name of the port on the Service. This is a mutually exclusive setting with "Number".
     pub name: Option<String>,
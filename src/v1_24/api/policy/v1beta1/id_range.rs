@@ -2,6 +2,7 @@
 
 /// IDRange provides a min/max of an allowed range of IDs.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct IDRange {
     /// max is the end of the range, inclusive.
     pub max: i64,
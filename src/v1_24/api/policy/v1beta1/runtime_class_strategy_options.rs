@@ -2,6 +2,7 @@
 
 /// RuntimeClassStrategyOptions define the strategy that will dictate the allowable RuntimeClasses for a pod.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct RuntimeClassStrategyOptions {
     /// allowedRuntimeClassNames is an allowlist of RuntimeClass names that may be specified on a pod. A value of "*" means that any RuntimeClass name is allowed, and must be the only item in the list. An empty list requires the RuntimeClassName field to be unset.
     pub allowed_runtime_class_names: Vec<String>,
@@ -2,6 +2,7 @@
 
 /// APIGroup contains the name, the supported versions, and the preferred version of a group.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct APIGroup {
     /// name is the name of the group.
     pub name: String,
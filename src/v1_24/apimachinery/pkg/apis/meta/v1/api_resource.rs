@@ -2,6 +2,7 @@
 
 /// APIResource specifies the name of a resource and whether it is namespaced.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct APIResource {
     /// categories is a list of the grouped resources this resource belongs to (e.g. 'all')
     pub categories: Option<Vec<String>>,
@@ -2,6 +2,7 @@
 
 /// IntOrString is a type that can hold an int32 or a string.  When used in JSON or YAML marshalling and unmarshalling, it produces or consumes the inner type.  This allows you to have, for example, a JSON field that can accept a name or number.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Hash, Ord, PartialOrd))]
 pub enum IntOrString {
     Int(i32),
     String(String),
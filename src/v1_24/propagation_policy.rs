@@ -0,0 +1,41 @@
+// Generated from definition io.k8s.PropagationPolicy
+
+/// Whether and how garbage collection will be performed on the dependents of a deleted object.
+#[cfg(feature = "api")]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PropagationPolicy {
+    /// Orphan the dependents.
+    Orphan,
+
+    /// Allow the garbage collector to delete the dependents in the background.
+    Background,
+
+    /// A cascading policy that deletes all dependents in the foreground.
+    Foreground,
+}
+
+#[cfg(feature = "api")]
+impl PropagationPolicy {
+    /// The value of this parameter as it is sent to the API server.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PropagationPolicy::Orphan => "Orphan",
+            PropagationPolicy::Background => "Background",
+            PropagationPolicy::Foreground => "Foreground",
+        }
+    }
+}
+
+#[cfg(feature = "api")]
+impl std::fmt::Display for PropagationPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "api")]
+impl crate::serde::Serialize for PropagationPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        serializer.serialize_str(self.as_str())
+    }
+}
@@ -0,0 +1,732 @@
+// Generated from the operations of the spec
+
+/// The resource operations of this version, sorted by group, version, resource and verb.
+///
+/// Use [`crate::unserved_operations`] to find the operations that an API server does not serve.
+pub static RESOURCE_OPERATIONS: &[crate::ResourceOperation] = &[
+    crate::ResourceOperation { group: "", version: "v1", resource: "bindings", verb: "create", operation_id: "createCoreV1NamespacedBinding" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "componentstatuses", verb: "get", operation_id: "readCoreV1ComponentStatus" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "componentstatuses", verb: "list", operation_id: "listCoreV1ComponentStatus" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "componentstatuses", verb: "watch", operation_id: "watchCoreV1ComponentStatus" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "configmaps", verb: "create", operation_id: "createCoreV1NamespacedConfigMap" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "configmaps", verb: "delete", operation_id: "deleteCoreV1NamespacedConfigMap" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "configmaps", verb: "deletecollection", operation_id: "deleteCoreV1CollectionNamespacedConfigMap" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "configmaps", verb: "get", operation_id: "readCoreV1NamespacedConfigMap" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "configmaps", verb: "list", operation_id: "listCoreV1ConfigMapForAllNamespaces" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "configmaps", verb: "list", operation_id: "listCoreV1NamespacedConfigMap" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "configmaps", verb: "patch", operation_id: "patchCoreV1NamespacedConfigMap" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "configmaps", verb: "update", operation_id: "replaceCoreV1NamespacedConfigMap" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "configmaps", verb: "watch", operation_id: "watchCoreV1ConfigMapForAllNamespaces" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "configmaps", verb: "watch", operation_id: "watchCoreV1NamespacedConfigMap" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "endpoints", verb: "create", operation_id: "createCoreV1NamespacedEndpoints" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "endpoints", verb: "delete", operation_id: "deleteCoreV1NamespacedEndpoints" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "endpoints", verb: "deletecollection", operation_id: "deleteCoreV1CollectionNamespacedEndpoints" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "endpoints", verb: "get", operation_id: "readCoreV1NamespacedEndpoints" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "endpoints", verb: "list", operation_id: "listCoreV1EndpointsForAllNamespaces" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "endpoints", verb: "list", operation_id: "listCoreV1NamespacedEndpoints" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "endpoints", verb: "patch", operation_id: "patchCoreV1NamespacedEndpoints" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "endpoints", verb: "update", operation_id: "replaceCoreV1NamespacedEndpoints" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "endpoints", verb: "watch", operation_id: "watchCoreV1EndpointsForAllNamespaces" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "endpoints", verb: "watch", operation_id: "watchCoreV1NamespacedEndpoints" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "events", verb: "create", operation_id: "createCoreV1NamespacedEvent" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "events", verb: "delete", operation_id: "deleteCoreV1NamespacedEvent" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "events", verb: "deletecollection", operation_id: "deleteCoreV1CollectionNamespacedEvent" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "events", verb: "get", operation_id: "readCoreV1NamespacedEvent" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "events", verb: "list", operation_id: "listCoreV1EventForAllNamespaces" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "events", verb: "list", operation_id: "listCoreV1NamespacedEvent" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "events", verb: "patch", operation_id: "patchCoreV1NamespacedEvent" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "events", verb: "update", operation_id: "replaceCoreV1NamespacedEvent" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "events", verb: "watch", operation_id: "watchCoreV1EventForAllNamespaces" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "events", verb: "watch", operation_id: "watchCoreV1NamespacedEvent" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "limitranges", verb: "create", operation_id: "createCoreV1NamespacedLimitRange" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "limitranges", verb: "delete", operation_id: "deleteCoreV1NamespacedLimitRange" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "limitranges", verb: "deletecollection", operation_id: "deleteCoreV1CollectionNamespacedLimitRange" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "limitranges", verb: "get", operation_id: "readCoreV1NamespacedLimitRange" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "limitranges", verb: "list", operation_id: "listCoreV1LimitRangeForAllNamespaces" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "limitranges", verb: "list", operation_id: "listCoreV1NamespacedLimitRange" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "limitranges", verb: "patch", operation_id: "patchCoreV1NamespacedLimitRange" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "limitranges", verb: "update", operation_id: "replaceCoreV1NamespacedLimitRange" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "limitranges", verb: "watch", operation_id: "watchCoreV1LimitRangeForAllNamespaces" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "limitranges", verb: "watch", operation_id: "watchCoreV1NamespacedLimitRange" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "namespaces", verb: "create", operation_id: "createCoreV1Namespace" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "namespaces", verb: "delete", operation_id: "deleteCoreV1Namespace" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "namespaces", verb: "get", operation_id: "readCoreV1Namespace" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "namespaces", verb: "list", operation_id: "listCoreV1Namespace" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "namespaces", verb: "patch", operation_id: "patchCoreV1Namespace" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "namespaces", verb: "update", operation_id: "replaceCoreV1Namespace" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "namespaces", verb: "watch", operation_id: "watchCoreV1Namespace" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "namespaces/finalize", verb: "update", operation_id: "replaceCoreV1NamespaceFinalize" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "namespaces/status", verb: "get", operation_id: "readCoreV1NamespaceStatus" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "namespaces/status", verb: "patch", operation_id: "patchCoreV1NamespaceStatus" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "namespaces/status", verb: "update", operation_id: "replaceCoreV1NamespaceStatus" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "nodes", verb: "create", operation_id: "createCoreV1Node" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "nodes", verb: "delete", operation_id: "deleteCoreV1Node" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "nodes", verb: "deletecollection", operation_id: "deleteCoreV1CollectionNode" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "nodes", verb: "get", operation_id: "readCoreV1Node" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "nodes", verb: "list", operation_id: "listCoreV1Node" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "nodes", verb: "patch", operation_id: "patchCoreV1Node" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "nodes", verb: "update", operation_id: "replaceCoreV1Node" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "nodes", verb: "watch", operation_id: "watchCoreV1Node" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "nodes/proxy", verb: "create", operation_id: "connectCoreV1PostNodeProxy" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "nodes/proxy", verb: "create", operation_id: "connectCoreV1PostNodeProxyWithPath" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "nodes/proxy", verb: "delete", operation_id: "connectCoreV1DeleteNodeProxy" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "nodes/proxy", verb: "delete", operation_id: "connectCoreV1DeleteNodeProxyWithPath" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "nodes/proxy", verb: "get", operation_id: "connectCoreV1GetNodeProxy" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "nodes/proxy", verb: "get", operation_id: "connectCoreV1GetNodeProxyWithPath" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "nodes/proxy", verb: "patch", operation_id: "connectCoreV1PatchNodeProxy" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "nodes/proxy", verb: "patch", operation_id: "connectCoreV1PatchNodeProxyWithPath" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "nodes/proxy", verb: "update", operation_id: "connectCoreV1PutNodeProxy" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "nodes/proxy", verb: "update", operation_id: "connectCoreV1PutNodeProxyWithPath" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "nodes/status", verb: "get", operation_id: "readCoreV1NodeStatus" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "nodes/status", verb: "patch", operation_id: "patchCoreV1NodeStatus" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "nodes/status", verb: "update", operation_id: "replaceCoreV1NodeStatus" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "persistentvolumeclaims", verb: "create", operation_id: "createCoreV1NamespacedPersistentVolumeClaim" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "persistentvolumeclaims", verb: "delete", operation_id: "deleteCoreV1NamespacedPersistentVolumeClaim" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "persistentvolumeclaims", verb: "deletecollection", operation_id: "deleteCoreV1CollectionNamespacedPersistentVolumeClaim" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "persistentvolumeclaims", verb: "get", operation_id: "readCoreV1NamespacedPersistentVolumeClaim" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "persistentvolumeclaims", verb: "list", operation_id: "listCoreV1NamespacedPersistentVolumeClaim" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "persistentvolumeclaims", verb: "list", operation_id: "listCoreV1PersistentVolumeClaimForAllNamespaces" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "persistentvolumeclaims", verb: "patch", operation_id: "patchCoreV1NamespacedPersistentVolumeClaim" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "persistentvolumeclaims", verb: "update", operation_id: "replaceCoreV1NamespacedPersistentVolumeClaim" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "persistentvolumeclaims", verb: "watch", operation_id: "watchCoreV1NamespacedPersistentVolumeClaim" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "persistentvolumeclaims", verb: "watch", operation_id: "watchCoreV1PersistentVolumeClaimForAllNamespaces" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "persistentvolumeclaims/status", verb: "get", operation_id: "readCoreV1NamespacedPersistentVolumeClaimStatus" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "persistentvolumeclaims/status", verb: "patch", operation_id: "patchCoreV1NamespacedPersistentVolumeClaimStatus" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "persistentvolumeclaims/status", verb: "update", operation_id: "replaceCoreV1NamespacedPersistentVolumeClaimStatus" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "persistentvolumes", verb: "create", operation_id: "createCoreV1PersistentVolume" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "persistentvolumes", verb: "delete", operation_id: "deleteCoreV1PersistentVolume" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "persistentvolumes", verb: "deletecollection", operation_id: "deleteCoreV1CollectionPersistentVolume" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "persistentvolumes", verb: "get", operation_id: "readCoreV1PersistentVolume" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "persistentvolumes", verb: "list", operation_id: "listCoreV1PersistentVolume" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "persistentvolumes", verb: "patch", operation_id: "patchCoreV1PersistentVolume" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "persistentvolumes", verb: "update", operation_id: "replaceCoreV1PersistentVolume" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "persistentvolumes", verb: "watch", operation_id: "watchCoreV1PersistentVolume" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "persistentvolumes/status", verb: "get", operation_id: "readCoreV1PersistentVolumeStatus" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "persistentvolumes/status", verb: "patch", operation_id: "patchCoreV1PersistentVolumeStatus" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "persistentvolumes/status", verb: "update", operation_id: "replaceCoreV1PersistentVolumeStatus" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods", verb: "create", operation_id: "createCoreV1NamespacedPod" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods", verb: "delete", operation_id: "deleteCoreV1NamespacedPod" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods", verb: "deletecollection", operation_id: "deleteCoreV1CollectionNamespacedPod" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods", verb: "get", operation_id: "readCoreV1NamespacedPod" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods", verb: "list", operation_id: "listCoreV1NamespacedPod" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods", verb: "list", operation_id: "listCoreV1PodForAllNamespaces" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods", verb: "patch", operation_id: "patchCoreV1NamespacedPod" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods", verb: "update", operation_id: "replaceCoreV1NamespacedPod" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods", verb: "watch", operation_id: "watchCoreV1NamespacedPod" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods", verb: "watch", operation_id: "watchCoreV1PodForAllNamespaces" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods/attach", verb: "create", operation_id: "connectCoreV1PostNamespacedPodAttach" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods/attach", verb: "get", operation_id: "connectCoreV1GetNamespacedPodAttach" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods/binding", verb: "create", operation_id: "createCoreV1NamespacedPodBinding" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods/ephemeralcontainers", verb: "get", operation_id: "readCoreV1NamespacedPodEphemeralcontainers" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods/ephemeralcontainers", verb: "patch", operation_id: "patchCoreV1NamespacedPodEphemeralcontainers" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods/ephemeralcontainers", verb: "update", operation_id: "replaceCoreV1NamespacedPodEphemeralcontainers" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods/eviction", verb: "create", operation_id: "createCoreV1NamespacedPodEviction" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods/exec", verb: "create", operation_id: "connectCoreV1PostNamespacedPodExec" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods/exec", verb: "get", operation_id: "connectCoreV1GetNamespacedPodExec" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods/log", verb: "get", operation_id: "readCoreV1NamespacedPodLog" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods/portforward", verb: "create", operation_id: "connectCoreV1PostNamespacedPodPortforward" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods/portforward", verb: "get", operation_id: "connectCoreV1GetNamespacedPodPortforward" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods/proxy", verb: "create", operation_id: "connectCoreV1PostNamespacedPodProxy" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods/proxy", verb: "create", operation_id: "connectCoreV1PostNamespacedPodProxyWithPath" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods/proxy", verb: "delete", operation_id: "connectCoreV1DeleteNamespacedPodProxy" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods/proxy", verb: "delete", operation_id: "connectCoreV1DeleteNamespacedPodProxyWithPath" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods/proxy", verb: "get", operation_id: "connectCoreV1GetNamespacedPodProxy" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods/proxy", verb: "get", operation_id: "connectCoreV1GetNamespacedPodProxyWithPath" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods/proxy", verb: "patch", operation_id: "connectCoreV1PatchNamespacedPodProxy" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods/proxy", verb: "patch", operation_id: "connectCoreV1PatchNamespacedPodProxyWithPath" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods/proxy", verb: "update", operation_id: "connectCoreV1PutNamespacedPodProxy" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods/proxy", verb: "update", operation_id: "connectCoreV1PutNamespacedPodProxyWithPath" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods/status", verb: "get", operation_id: "readCoreV1NamespacedPodStatus" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods/status", verb: "patch", operation_id: "patchCoreV1NamespacedPodStatus" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "pods/status", verb: "update", operation_id: "replaceCoreV1NamespacedPodStatus" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "podtemplates", verb: "create", operation_id: "createCoreV1NamespacedPodTemplate" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "podtemplates", verb: "delete", operation_id: "deleteCoreV1NamespacedPodTemplate" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "podtemplates", verb: "deletecollection", operation_id: "deleteCoreV1CollectionNamespacedPodTemplate" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "podtemplates", verb: "get", operation_id: "readCoreV1NamespacedPodTemplate" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "podtemplates", verb: "list", operation_id: "listCoreV1NamespacedPodTemplate" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "podtemplates", verb: "list", operation_id: "listCoreV1PodTemplateForAllNamespaces" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "podtemplates", verb: "patch", operation_id: "patchCoreV1NamespacedPodTemplate" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "podtemplates", verb: "update", operation_id: "replaceCoreV1NamespacedPodTemplate" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "podtemplates", verb: "watch", operation_id: "watchCoreV1NamespacedPodTemplate" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "podtemplates", verb: "watch", operation_id: "watchCoreV1PodTemplateForAllNamespaces" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "replicationcontrollers", verb: "create", operation_id: "createCoreV1NamespacedReplicationController" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "replicationcontrollers", verb: "delete", operation_id: "deleteCoreV1NamespacedReplicationController" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "replicationcontrollers", verb: "deletecollection", operation_id: "deleteCoreV1CollectionNamespacedReplicationController" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "replicationcontrollers", verb: "get", operation_id: "readCoreV1NamespacedReplicationController" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "replicationcontrollers", verb: "list", operation_id: "listCoreV1NamespacedReplicationController" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "replicationcontrollers", verb: "list", operation_id: "listCoreV1ReplicationControllerForAllNamespaces" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "replicationcontrollers", verb: "patch", operation_id: "patchCoreV1NamespacedReplicationController" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "replicationcontrollers", verb: "update", operation_id: "replaceCoreV1NamespacedReplicationController" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "replicationcontrollers", verb: "watch", operation_id: "watchCoreV1NamespacedReplicationController" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "replicationcontrollers", verb: "watch", operation_id: "watchCoreV1ReplicationControllerForAllNamespaces" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "replicationcontrollers/scale", verb: "get", operation_id: "readCoreV1NamespacedReplicationControllerScale" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "replicationcontrollers/scale", verb: "patch", operation_id: "patchCoreV1NamespacedReplicationControllerScale" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "replicationcontrollers/scale", verb: "update", operation_id: "replaceCoreV1NamespacedReplicationControllerScale" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "replicationcontrollers/status", verb: "get", operation_id: "readCoreV1NamespacedReplicationControllerStatus" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "replicationcontrollers/status", verb: "patch", operation_id: "patchCoreV1NamespacedReplicationControllerStatus" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "replicationcontrollers/status", verb: "update", operation_id: "replaceCoreV1NamespacedReplicationControllerStatus" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "resourcequotas", verb: "create", operation_id: "createCoreV1NamespacedResourceQuota" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "resourcequotas", verb: "delete", operation_id: "deleteCoreV1NamespacedResourceQuota" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "resourcequotas", verb: "deletecollection", operation_id: "deleteCoreV1CollectionNamespacedResourceQuota" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "resourcequotas", verb: "get", operation_id: "readCoreV1NamespacedResourceQuota" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "resourcequotas", verb: "list", operation_id: "listCoreV1NamespacedResourceQuota" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "resourcequotas", verb: "list", operation_id: "listCoreV1ResourceQuotaForAllNamespaces" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "resourcequotas", verb: "patch", operation_id: "patchCoreV1NamespacedResourceQuota" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "resourcequotas", verb: "update", operation_id: "replaceCoreV1NamespacedResourceQuota" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "resourcequotas", verb: "watch", operation_id: "watchCoreV1NamespacedResourceQuota" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "resourcequotas", verb: "watch", operation_id: "watchCoreV1ResourceQuotaForAllNamespaces" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "resourcequotas/status", verb: "get", operation_id: "readCoreV1NamespacedResourceQuotaStatus" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "resourcequotas/status", verb: "patch", operation_id: "patchCoreV1NamespacedResourceQuotaStatus" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "resourcequotas/status", verb: "update", operation_id: "replaceCoreV1NamespacedResourceQuotaStatus" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "secrets", verb: "create", operation_id: "createCoreV1NamespacedSecret" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "secrets", verb: "delete", operation_id: "deleteCoreV1NamespacedSecret" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "secrets", verb: "deletecollection", operation_id: "deleteCoreV1CollectionNamespacedSecret" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "secrets", verb: "get", operation_id: "readCoreV1NamespacedSecret" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "secrets", verb: "list", operation_id: "listCoreV1NamespacedSecret" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "secrets", verb: "list", operation_id: "listCoreV1SecretForAllNamespaces" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "secrets", verb: "patch", operation_id: "patchCoreV1NamespacedSecret" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "secrets", verb: "update", operation_id: "replaceCoreV1NamespacedSecret" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "secrets", verb: "watch", operation_id: "watchCoreV1NamespacedSecret" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "secrets", verb: "watch", operation_id: "watchCoreV1SecretForAllNamespaces" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "serviceaccounts", verb: "create", operation_id: "createCoreV1NamespacedServiceAccount" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "serviceaccounts", verb: "delete", operation_id: "deleteCoreV1NamespacedServiceAccount" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "serviceaccounts", verb: "deletecollection", operation_id: "deleteCoreV1CollectionNamespacedServiceAccount" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "serviceaccounts", verb: "get", operation_id: "readCoreV1NamespacedServiceAccount" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "serviceaccounts", verb: "list", operation_id: "listCoreV1NamespacedServiceAccount" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "serviceaccounts", verb: "list", operation_id: "listCoreV1ServiceAccountForAllNamespaces" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "serviceaccounts", verb: "patch", operation_id: "patchCoreV1NamespacedServiceAccount" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "serviceaccounts", verb: "update", operation_id: "replaceCoreV1NamespacedServiceAccount" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "serviceaccounts", verb: "watch", operation_id: "watchCoreV1NamespacedServiceAccount" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "serviceaccounts", verb: "watch", operation_id: "watchCoreV1ServiceAccountForAllNamespaces" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "serviceaccounts/token", verb: "create", operation_id: "createCoreV1NamespacedServiceAccountToken" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "services", verb: "create", operation_id: "createCoreV1NamespacedService" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "services", verb: "delete", operation_id: "deleteCoreV1NamespacedService" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "services", verb: "deletecollection", operation_id: "deleteCoreV1CollectionNamespacedService" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "services", verb: "get", operation_id: "readCoreV1NamespacedService" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "services", verb: "list", operation_id: "listCoreV1NamespacedService" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "services", verb: "list", operation_id: "listCoreV1ServiceForAllNamespaces" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "services", verb: "patch", operation_id: "patchCoreV1NamespacedService" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "services", verb: "update", operation_id: "replaceCoreV1NamespacedService" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "services", verb: "watch", operation_id: "watchCoreV1NamespacedService" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "services", verb: "watch", operation_id: "watchCoreV1ServiceForAllNamespaces" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "services/proxy", verb: "create", operation_id: "connectCoreV1PostNamespacedServiceProxy" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "services/proxy", verb: "create", operation_id: "connectCoreV1PostNamespacedServiceProxyWithPath" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "services/proxy", verb: "delete", operation_id: "connectCoreV1DeleteNamespacedServiceProxy" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "services/proxy", verb: "delete", operation_id: "connectCoreV1DeleteNamespacedServiceProxyWithPath" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "services/proxy", verb: "get", operation_id: "connectCoreV1GetNamespacedServiceProxy" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "services/proxy", verb: "get", operation_id: "connectCoreV1GetNamespacedServiceProxyWithPath" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "services/proxy", verb: "patch", operation_id: "connectCoreV1PatchNamespacedServiceProxy" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "services/proxy", verb: "patch", operation_id: "connectCoreV1PatchNamespacedServiceProxyWithPath" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "services/proxy", verb: "update", operation_id: "connectCoreV1PutNamespacedServiceProxy" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "services/proxy", verb: "update", operation_id: "connectCoreV1PutNamespacedServiceProxyWithPath" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "services/status", verb: "get", operation_id: "readCoreV1NamespacedServiceStatus" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "services/status", verb: "patch", operation_id: "patchCoreV1NamespacedServiceStatus" },
+    crate::ResourceOperation { group: "", version: "v1", resource: "services/status", verb: "update", operation_id: "replaceCoreV1NamespacedServiceStatus" },
+    crate::ResourceOperation { group: "admissionregistration.k8s.io", version: "v1", resource: "mutatingwebhookconfigurations", verb: "create", operation_id: "createAdmissionregistrationV1MutatingWebhookConfiguration" },
+    crate::ResourceOperation { group: "admissionregistration.k8s.io", version: "v1", resource: "mutatingwebhookconfigurations", verb: "delete", operation_id: "deleteAdmissionregistrationV1MutatingWebhookConfiguration" },
+    crate::ResourceOperation { group: "admissionregistration.k8s.io", version: "v1", resource: "mutatingwebhookconfigurations", verb: "deletecollection", operation_id: "deleteAdmissionregistrationV1CollectionMutatingWebhookConfiguration" },
+    crate::ResourceOperation { group: "admissionregistration.k8s.io", version: "v1", resource: "mutatingwebhookconfigurations", verb: "get", operation_id: "readAdmissionregistrationV1MutatingWebhookConfiguration" },
+    crate::ResourceOperation { group: "admissionregistration.k8s.io", version: "v1", resource: "mutatingwebhookconfigurations", verb: "list", operation_id: "listAdmissionregistrationV1MutatingWebhookConfiguration" },
+    crate::ResourceOperation { group: "admissionregistration.k8s.io", version: "v1", resource: "mutatingwebhookconfigurations", verb: "patch", operation_id: "patchAdmissionregistrationV1MutatingWebhookConfiguration" },
+    crate::ResourceOperation { group: "admissionregistration.k8s.io", version: "v1", resource: "mutatingwebhookconfigurations", verb: "update", operation_id: "replaceAdmissionregistrationV1MutatingWebhookConfiguration" },
+    crate::ResourceOperation { group: "admissionregistration.k8s.io", version: "v1", resource: "mutatingwebhookconfigurations", verb: "watch", operation_id: "watchAdmissionregistrationV1MutatingWebhookConfiguration" },
+    crate::ResourceOperation { group: "admissionregistration.k8s.io", version: "v1", resource: "validatingwebhookconfigurations", verb: "create", operation_id: "createAdmissionregistrationV1ValidatingWebhookConfiguration" },
+    crate::ResourceOperation { group: "admissionregistration.k8s.io", version: "v1", resource: "validatingwebhookconfigurations", verb: "delete", operation_id: "deleteAdmissionregistrationV1ValidatingWebhookConfiguration" },
+    crate::ResourceOperation { group: "admissionregistration.k8s.io", version: "v1", resource: "validatingwebhookconfigurations", verb: "deletecollection", operation_id: "deleteAdmissionregistrationV1CollectionValidatingWebhookConfiguration" },
+    crate::ResourceOperation { group: "admissionregistration.k8s.io", version: "v1", resource: "validatingwebhookconfigurations", verb: "get", operation_id: "readAdmissionregistrationV1ValidatingWebhookConfiguration" },
+    crate::ResourceOperation { group: "admissionregistration.k8s.io", version: "v1", resource: "validatingwebhookconfigurations", verb: "list", operation_id: "listAdmissionregistrationV1ValidatingWebhookConfiguration" },
+    crate::ResourceOperation { group: "admissionregistration.k8s.io", version: "v1", resource: "validatingwebhookconfigurations", verb: "patch", operation_id: "patchAdmissionregistrationV1ValidatingWebhookConfiguration" },
+    crate::ResourceOperation { group: "admissionregistration.k8s.io", version: "v1", resource: "validatingwebhookconfigurations", verb: "update", operation_id: "replaceAdmissionregistrationV1ValidatingWebhookConfiguration" },
+    crate::ResourceOperation { group: "admissionregistration.k8s.io", version: "v1", resource: "validatingwebhookconfigurations", verb: "watch", operation_id: "watchAdmissionregistrationV1ValidatingWebhookConfiguration" },
+    crate::ResourceOperation { group: "apiextensions.k8s.io", version: "v1", resource: "customresourcedefinitions", verb: "create", operation_id: "createApiextensionsV1CustomResourceDefinition" },
+    crate::ResourceOperation { group: "apiextensions.k8s.io", version: "v1", resource: "customresourcedefinitions", verb: "delete", operation_id: "deleteApiextensionsV1CustomResourceDefinition" },
+    crate::ResourceOperation { group: "apiextensions.k8s.io", version: "v1", resource: "customresourcedefinitions", verb: "deletecollection", operation_id: "deleteApiextensionsV1CollectionCustomResourceDefinition" },
+    crate::ResourceOperation { group: "apiextensions.k8s.io", version: "v1", resource: "customresourcedefinitions", verb: "get", operation_id: "readApiextensionsV1CustomResourceDefinition" },
+    crate::ResourceOperation { group: "apiextensions.k8s.io", version: "v1", resource: "customresourcedefinitions", verb: "list", operation_id: "listApiextensionsV1CustomResourceDefinition" },
+    crate::ResourceOperation { group: "apiextensions.k8s.io", version: "v1", resource: "customresourcedefinitions", verb: "patch", operation_id: "patchApiextensionsV1CustomResourceDefinition" },
+    crate::ResourceOperation { group: "apiextensions.k8s.io", version: "v1", resource: "customresourcedefinitions", verb: "update", operation_id: "replaceApiextensionsV1CustomResourceDefinition" },
+    crate::ResourceOperation { group: "apiextensions.k8s.io", version: "v1", resource: "customresourcedefinitions", verb: "watch", operation_id: "watchApiextensionsV1CustomResourceDefinition" },
+    crate::ResourceOperation { group: "apiextensions.k8s.io", version: "v1", resource: "customresourcedefinitions/status", verb: "get", operation_id: "readApiextensionsV1CustomResourceDefinitionStatus" },
+    crate::ResourceOperation { group: "apiextensions.k8s.io", version: "v1", resource: "customresourcedefinitions/status", verb: "patch", operation_id: "patchApiextensionsV1CustomResourceDefinitionStatus" },
+    crate::ResourceOperation { group: "apiextensions.k8s.io", version: "v1", resource: "customresourcedefinitions/status", verb: "update", operation_id: "replaceApiextensionsV1CustomResourceDefinitionStatus" },
+    crate::ResourceOperation { group: "apiregistration.k8s.io", version: "v1", resource: "apiservices", verb: "create", operation_id: "createApiregistrationV1APIService" },
+    crate::ResourceOperation { group: "apiregistration.k8s.io", version: "v1", resource: "apiservices", verb: "delete", operation_id: "deleteApiregistrationV1APIService" },
+    crate::ResourceOperation { group: "apiregistration.k8s.io", version: "v1", resource: "apiservices", verb: "deletecollection", operation_id: "deleteApiregistrationV1CollectionAPIService" },
+    crate::ResourceOperation { group: "apiregistration.k8s.io", version: "v1", resource: "apiservices", verb: "get", operation_id: "readApiregistrationV1APIService" },
+    crate::ResourceOperation { group: "apiregistration.k8s.io", version: "v1", resource: "apiservices", verb: "list", operation_id: "listApiregistrationV1APIService" },
+    crate::ResourceOperation { group: "apiregistration.k8s.io", version: "v1", resource: "apiservices", verb: "patch", operation_id: "patchApiregistrationV1APIService" },
+    crate::ResourceOperation { group: "apiregistration.k8s.io", version: "v1", resource: "apiservices", verb: "update", operation_id: "replaceApiregistrationV1APIService" },
+    crate::ResourceOperation { group: "apiregistration.k8s.io", version: "v1", resource: "apiservices", verb: "watch", operation_id: "watchApiregistrationV1APIService" },
+    crate::ResourceOperation { group: "apiregistration.k8s.io", version: "v1", resource: "apiservices/status", verb: "get", operation_id: "readApiregistrationV1APIServiceStatus" },
+    crate::ResourceOperation { group: "apiregistration.k8s.io", version: "v1", resource: "apiservices/status", verb: "patch", operation_id: "patchApiregistrationV1APIServiceStatus" },
+    crate::ResourceOperation { group: "apiregistration.k8s.io", version: "v1", resource: "apiservices/status", verb: "update", operation_id: "replaceApiregistrationV1APIServiceStatus" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "controllerrevisions", verb: "create", operation_id: "createAppsV1NamespacedControllerRevision" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "controllerrevisions", verb: "delete", operation_id: "deleteAppsV1NamespacedControllerRevision" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "controllerrevisions", verb: "deletecollection", operation_id: "deleteAppsV1CollectionNamespacedControllerRevision" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "controllerrevisions", verb: "get", operation_id: "readAppsV1NamespacedControllerRevision" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "controllerrevisions", verb: "list", operation_id: "listAppsV1ControllerRevisionForAllNamespaces" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "controllerrevisions", verb: "list", operation_id: "listAppsV1NamespacedControllerRevision" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "controllerrevisions", verb: "patch", operation_id: "patchAppsV1NamespacedControllerRevision" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "controllerrevisions", verb: "update", operation_id: "replaceAppsV1NamespacedControllerRevision" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "controllerrevisions", verb: "watch", operation_id: "watchAppsV1ControllerRevisionForAllNamespaces" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "controllerrevisions", verb: "watch", operation_id: "watchAppsV1NamespacedControllerRevision" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "daemonsets", verb: "create", operation_id: "createAppsV1NamespacedDaemonSet" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "daemonsets", verb: "delete", operation_id: "deleteAppsV1NamespacedDaemonSet" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "daemonsets", verb: "deletecollection", operation_id: "deleteAppsV1CollectionNamespacedDaemonSet" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "daemonsets", verb: "get", operation_id: "readAppsV1NamespacedDaemonSet" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "daemonsets", verb: "list", operation_id: "listAppsV1DaemonSetForAllNamespaces" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "daemonsets", verb: "list", operation_id: "listAppsV1NamespacedDaemonSet" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "daemonsets", verb: "patch", operation_id: "patchAppsV1NamespacedDaemonSet" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "daemonsets", verb: "update", operation_id: "replaceAppsV1NamespacedDaemonSet" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "daemonsets", verb: "watch", operation_id: "watchAppsV1DaemonSetForAllNamespaces" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "daemonsets", verb: "watch", operation_id: "watchAppsV1NamespacedDaemonSet" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "daemonsets/status", verb: "get", operation_id: "readAppsV1NamespacedDaemonSetStatus" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "daemonsets/status", verb: "patch", operation_id: "patchAppsV1NamespacedDaemonSetStatus" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "daemonsets/status", verb: "update", operation_id: "replaceAppsV1NamespacedDaemonSetStatus" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "deployments", verb: "create", operation_id: "createAppsV1NamespacedDeployment" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "deployments", verb: "delete", operation_id: "deleteAppsV1NamespacedDeployment" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "deployments", verb: "deletecollection", operation_id: "deleteAppsV1CollectionNamespacedDeployment" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "deployments", verb: "get", operation_id: "readAppsV1NamespacedDeployment" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "deployments", verb: "list", operation_id: "listAppsV1DeploymentForAllNamespaces" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "deployments", verb: "list", operation_id: "listAppsV1NamespacedDeployment" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "deployments", verb: "patch", operation_id: "patchAppsV1NamespacedDeployment" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "deployments", verb: "update", operation_id: "replaceAppsV1NamespacedDeployment" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "deployments", verb: "watch", operation_id: "watchAppsV1DeploymentForAllNamespaces" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "deployments", verb: "watch", operation_id: "watchAppsV1NamespacedDeployment" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "deployments/scale", verb: "get", operation_id: "readAppsV1NamespacedDeploymentScale" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "deployments/scale", verb: "patch", operation_id: "patchAppsV1NamespacedDeploymentScale" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "deployments/scale", verb: "update", operation_id: "replaceAppsV1NamespacedDeploymentScale" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "deployments/status", verb: "get", operation_id: "readAppsV1NamespacedDeploymentStatus" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "deployments/status", verb: "patch", operation_id: "patchAppsV1NamespacedDeploymentStatus" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "deployments/status", verb: "update", operation_id: "replaceAppsV1NamespacedDeploymentStatus" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "replicasets", verb: "create", operation_id: "createAppsV1NamespacedReplicaSet" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "replicasets", verb: "delete", operation_id: "deleteAppsV1NamespacedReplicaSet" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "replicasets", verb: "deletecollection", operation_id: "deleteAppsV1CollectionNamespacedReplicaSet" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "replicasets", verb: "get", operation_id: "readAppsV1NamespacedReplicaSet" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "replicasets", verb: "list", operation_id: "listAppsV1NamespacedReplicaSet" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "replicasets", verb: "list", operation_id: "listAppsV1ReplicaSetForAllNamespaces" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "replicasets", verb: "patch", operation_id: "patchAppsV1NamespacedReplicaSet" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "replicasets", verb: "update", operation_id: "replaceAppsV1NamespacedReplicaSet" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "replicasets", verb: "watch", operation_id: "watchAppsV1NamespacedReplicaSet" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "replicasets", verb: "watch", operation_id: "watchAppsV1ReplicaSetForAllNamespaces" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "replicasets/scale", verb: "get", operation_id: "readAppsV1NamespacedReplicaSetScale" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "replicasets/scale", verb: "patch", operation_id: "patchAppsV1NamespacedReplicaSetScale" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "replicasets/scale", verb: "update", operation_id: "replaceAppsV1NamespacedReplicaSetScale" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "replicasets/status", verb: "get", operation_id: "readAppsV1NamespacedReplicaSetStatus" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "replicasets/status", verb: "patch", operation_id: "patchAppsV1NamespacedReplicaSetStatus" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "replicasets/status", verb: "update", operation_id: "replaceAppsV1NamespacedReplicaSetStatus" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "statefulsets", verb: "create", operation_id: "createAppsV1NamespacedStatefulSet" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "statefulsets", verb: "delete", operation_id: "deleteAppsV1NamespacedStatefulSet" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "statefulsets", verb: "deletecollection", operation_id: "deleteAppsV1CollectionNamespacedStatefulSet" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "statefulsets", verb: "get", operation_id: "readAppsV1NamespacedStatefulSet" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "statefulsets", verb: "list", operation_id: "listAppsV1NamespacedStatefulSet" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "statefulsets", verb: "list", operation_id: "listAppsV1StatefulSetForAllNamespaces" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "statefulsets", verb: "patch", operation_id: "patchAppsV1NamespacedStatefulSet" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "statefulsets", verb: "update", operation_id: "replaceAppsV1NamespacedStatefulSet" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "statefulsets", verb: "watch", operation_id: "watchAppsV1NamespacedStatefulSet" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "statefulsets", verb: "watch", operation_id: "watchAppsV1StatefulSetForAllNamespaces" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "statefulsets/scale", verb: "get", operation_id: "readAppsV1NamespacedStatefulSetScale" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "statefulsets/scale", verb: "patch", operation_id: "patchAppsV1NamespacedStatefulSetScale" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "statefulsets/scale", verb: "update", operation_id: "replaceAppsV1NamespacedStatefulSetScale" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "statefulsets/status", verb: "get", operation_id: "readAppsV1NamespacedStatefulSetStatus" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "statefulsets/status", verb: "patch", operation_id: "patchAppsV1NamespacedStatefulSetStatus" },
+    crate::ResourceOperation { group: "apps", version: "v1", resource: "statefulsets/status", verb: "update", operation_id: "replaceAppsV1NamespacedStatefulSetStatus" },
+    crate::ResourceOperation { group: "authentication.k8s.io", version: "v1", resource: "tokenreviews", verb: "create", operation_id: "createAuthenticationV1TokenReview" },
+    crate::ResourceOperation { group: "authorization.k8s.io", version: "v1", resource: "localsubjectaccessreviews", verb: "create", operation_id: "createAuthorizationV1NamespacedLocalSubjectAccessReview" },
+    crate::ResourceOperation { group: "authorization.k8s.io", version: "v1", resource: "selfsubjectaccessreviews", verb: "create", operation_id: "createAuthorizationV1SelfSubjectAccessReview" },
+    crate::ResourceOperation { group: "authorization.k8s.io", version: "v1", resource: "selfsubjectrulesreviews", verb: "create", operation_id: "createAuthorizationV1SelfSubjectRulesReview" },
+    crate::ResourceOperation { group: "authorization.k8s.io", version: "v1", resource: "subjectaccessreviews", verb: "create", operation_id: "createAuthorizationV1SubjectAccessReview" },
+    crate::ResourceOperation { group: "autoscaling", version: "v1", resource: "horizontalpodautoscalers", verb: "create", operation_id: "createAutoscalingV1NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v1", resource: "horizontalpodautoscalers", verb: "delete", operation_id: "deleteAutoscalingV1NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v1", resource: "horizontalpodautoscalers", verb: "deletecollection", operation_id: "deleteAutoscalingV1CollectionNamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v1", resource: "horizontalpodautoscalers", verb: "get", operation_id: "readAutoscalingV1NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v1", resource: "horizontalpodautoscalers", verb: "list", operation_id: "listAutoscalingV1HorizontalPodAutoscalerForAllNamespaces" },
+    crate::ResourceOperation { group: "autoscaling", version: "v1", resource: "horizontalpodautoscalers", verb: "list", operation_id: "listAutoscalingV1NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v1", resource: "horizontalpodautoscalers", verb: "patch", operation_id: "patchAutoscalingV1NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v1", resource: "horizontalpodautoscalers", verb: "update", operation_id: "replaceAutoscalingV1NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v1", resource: "horizontalpodautoscalers", verb: "watch", operation_id: "watchAutoscalingV1HorizontalPodAutoscalerForAllNamespaces" },
+    crate::ResourceOperation { group: "autoscaling", version: "v1", resource: "horizontalpodautoscalers", verb: "watch", operation_id: "watchAutoscalingV1NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v1", resource: "horizontalpodautoscalers/status", verb: "get", operation_id: "readAutoscalingV1NamespacedHorizontalPodAutoscalerStatus" },
+    crate::ResourceOperation { group: "autoscaling", version: "v1", resource: "horizontalpodautoscalers/status", verb: "patch", operation_id: "patchAutoscalingV1NamespacedHorizontalPodAutoscalerStatus" },
+    crate::ResourceOperation { group: "autoscaling", version: "v1", resource: "horizontalpodautoscalers/status", verb: "update", operation_id: "replaceAutoscalingV1NamespacedHorizontalPodAutoscalerStatus" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2", resource: "horizontalpodautoscalers", verb: "create", operation_id: "createAutoscalingV2NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2", resource: "horizontalpodautoscalers", verb: "delete", operation_id: "deleteAutoscalingV2NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2", resource: "horizontalpodautoscalers", verb: "deletecollection", operation_id: "deleteAutoscalingV2CollectionNamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2", resource: "horizontalpodautoscalers", verb: "get", operation_id: "readAutoscalingV2NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2", resource: "horizontalpodautoscalers", verb: "list", operation_id: "listAutoscalingV2HorizontalPodAutoscalerForAllNamespaces" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2", resource: "horizontalpodautoscalers", verb: "list", operation_id: "listAutoscalingV2NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2", resource: "horizontalpodautoscalers", verb: "patch", operation_id: "patchAutoscalingV2NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2", resource: "horizontalpodautoscalers", verb: "update", operation_id: "replaceAutoscalingV2NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2", resource: "horizontalpodautoscalers", verb: "watch", operation_id: "watchAutoscalingV2HorizontalPodAutoscalerForAllNamespaces" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2", resource: "horizontalpodautoscalers", verb: "watch", operation_id: "watchAutoscalingV2NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2", resource: "horizontalpodautoscalers/status", verb: "get", operation_id: "readAutoscalingV2NamespacedHorizontalPodAutoscalerStatus" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2", resource: "horizontalpodautoscalers/status", verb: "patch", operation_id: "patchAutoscalingV2NamespacedHorizontalPodAutoscalerStatus" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2", resource: "horizontalpodautoscalers/status", verb: "update", operation_id: "replaceAutoscalingV2NamespacedHorizontalPodAutoscalerStatus" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2beta1", resource: "horizontalpodautoscalers", verb: "create", operation_id: "createAutoscalingV2beta1NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2beta1", resource: "horizontalpodautoscalers", verb: "delete", operation_id: "deleteAutoscalingV2beta1NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2beta1", resource: "horizontalpodautoscalers", verb: "deletecollection", operation_id: "deleteAutoscalingV2beta1CollectionNamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2beta1", resource: "horizontalpodautoscalers", verb: "get", operation_id: "readAutoscalingV2beta1NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2beta1", resource: "horizontalpodautoscalers", verb: "list", operation_id: "listAutoscalingV2beta1HorizontalPodAutoscalerForAllNamespaces" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2beta1", resource: "horizontalpodautoscalers", verb: "list", operation_id: "listAutoscalingV2beta1NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2beta1", resource: "horizontalpodautoscalers", verb: "patch", operation_id: "patchAutoscalingV2beta1NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2beta1", resource: "horizontalpodautoscalers", verb: "update", operation_id: "replaceAutoscalingV2beta1NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2beta1", resource: "horizontalpodautoscalers", verb: "watch", operation_id: "watchAutoscalingV2beta1HorizontalPodAutoscalerForAllNamespaces" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2beta1", resource: "horizontalpodautoscalers", verb: "watch", operation_id: "watchAutoscalingV2beta1NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2beta1", resource: "horizontalpodautoscalers/status", verb: "get", operation_id: "readAutoscalingV2beta1NamespacedHorizontalPodAutoscalerStatus" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2beta1", resource: "horizontalpodautoscalers/status", verb: "patch", operation_id: "patchAutoscalingV2beta1NamespacedHorizontalPodAutoscalerStatus" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2beta1", resource: "horizontalpodautoscalers/status", verb: "update", operation_id: "replaceAutoscalingV2beta1NamespacedHorizontalPodAutoscalerStatus" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2beta2", resource: "horizontalpodautoscalers", verb: "create", operation_id: "createAutoscalingV2beta2NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2beta2", resource: "horizontalpodautoscalers", verb: "delete", operation_id: "deleteAutoscalingV2beta2NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2beta2", resource: "horizontalpodautoscalers", verb: "deletecollection", operation_id: "deleteAutoscalingV2beta2CollectionNamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2beta2", resource: "horizontalpodautoscalers", verb: "get", operation_id: "readAutoscalingV2beta2NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2beta2", resource: "horizontalpodautoscalers", verb: "list", operation_id: "listAutoscalingV2beta2HorizontalPodAutoscalerForAllNamespaces" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2beta2", resource: "horizontalpodautoscalers", verb: "list", operation_id: "listAutoscalingV2beta2NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2beta2", resource: "horizontalpodautoscalers", verb: "patch", operation_id: "patchAutoscalingV2beta2NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2beta2", resource: "horizontalpodautoscalers", verb: "update", operation_id: "replaceAutoscalingV2beta2NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2beta2", resource: "horizontalpodautoscalers", verb: "watch", operation_id: "watchAutoscalingV2beta2HorizontalPodAutoscalerForAllNamespaces" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2beta2", resource: "horizontalpodautoscalers", verb: "watch", operation_id: "watchAutoscalingV2beta2NamespacedHorizontalPodAutoscaler" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2beta2", resource: "horizontalpodautoscalers/status", verb: "get", operation_id: "readAutoscalingV2beta2NamespacedHorizontalPodAutoscalerStatus" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2beta2", resource: "horizontalpodautoscalers/status", verb: "patch", operation_id: "patchAutoscalingV2beta2NamespacedHorizontalPodAutoscalerStatus" },
+    crate::ResourceOperation { group: "autoscaling", version: "v2beta2", resource: "horizontalpodautoscalers/status", verb: "update", operation_id: "replaceAutoscalingV2beta2NamespacedHorizontalPodAutoscalerStatus" },
+    crate::ResourceOperation { group: "batch", version: "v1", resource: "cronjobs", verb: "create", operation_id: "createBatchV1NamespacedCronJob" },
+    crate::ResourceOperation { group: "batch", version: "v1", resource: "cronjobs", verb: "delete", operation_id: "deleteBatchV1NamespacedCronJob" },
+    crate::ResourceOperation { group: "batch", version: "v1", resource: "cronjobs", verb: "deletecollection", operation_id: "deleteBatchV1CollectionNamespacedCronJob" },
+    crate::ResourceOperation { group: "batch", version: "v1", resource: "cronjobs", verb: "get", operation_id: "readBatchV1NamespacedCronJob" },
+    crate::ResourceOperation { group: "batch", version: "v1", resource: "cronjobs", verb: "list", operation_id: "listBatchV1CronJobForAllNamespaces" },
+    crate::ResourceOperation { group: "batch", version: "v1", resource: "cronjobs", verb: "list", operation_id: "listBatchV1NamespacedCronJob" },
+    crate::ResourceOperation { group: "batch", version: "v1", resource: "cronjobs", verb: "patch", operation_id: "patchBatchV1NamespacedCronJob" },
+    crate::ResourceOperation { group: "batch", version: "v1", resource: "cronjobs", verb: "update", operation_id: "replaceBatchV1NamespacedCronJob" },
+    crate::ResourceOperation { group: "batch", version: "v1", resource: "cronjobs", verb: "watch", operation_id: "watchBatchV1CronJobForAllNamespaces" },
+    crate::ResourceOperation { group: "batch", version: "v1", resource: "cronjobs", verb: "watch", operation_id: "watchBatchV1NamespacedCronJob" },
+    crate::ResourceOperation { group: "batch", version: "v1", resource: "cronjobs/status", verb: "get", operation_id: "readBatchV1NamespacedCronJobStatus" },
+    crate::ResourceOperation { group: "batch", version: "v1", resource: "cronjobs/status", verb: "patch", operation_id: "patchBatchV1NamespacedCronJobStatus" },
+    crate::ResourceOperation { group: "batch", version: "v1", resource: "cronjobs/status", verb: "update", operation_id: "replaceBatchV1NamespacedCronJobStatus" },
+    crate::ResourceOperation { group: "batch", version: "v1", resource: "jobs", verb: "create", operation_id: "createBatchV1NamespacedJob" },
+    crate::ResourceOperation { group: "batch", version: "v1", resource: "jobs", verb: "delete", operation_id: "deleteBatchV1NamespacedJob" },
+    crate::ResourceOperation { group: "batch", version: "v1", resource: "jobs", verb: "deletecollection", operation_id: "deleteBatchV1CollectionNamespacedJob" },
+    crate::ResourceOperation { group: "batch", version: "v1", resource: "jobs", verb: "get", operation_id: "readBatchV1NamespacedJob" },
+    crate::ResourceOperation { group: "batch", version: "v1", resource: "jobs", verb: "list", operation_id: "listBatchV1JobForAllNamespaces" },
+    crate::ResourceOperation { group: "batch", version: "v1", resource: "jobs", verb: "list", operation_id: "listBatchV1NamespacedJob" },
+    crate::ResourceOperation { group: "batch", version: "v1", resource: "jobs", verb: "patch", operation_id: "patchBatchV1NamespacedJob" },
+    crate::ResourceOperation { group: "batch", version: "v1", resource: "jobs", verb: "update", operation_id: "replaceBatchV1NamespacedJob" },
+    crate::ResourceOperation { group: "batch", version: "v1", resource: "jobs", verb: "watch", operation_id: "watchBatchV1JobForAllNamespaces" },
+    crate::ResourceOperation { group: "batch", version: "v1", resource: "jobs", verb: "watch", operation_id: "watchBatchV1NamespacedJob" },
+    crate::ResourceOperation { group: "batch", version: "v1", resource: "jobs/status", verb: "get", operation_id: "readBatchV1NamespacedJobStatus" },
+    crate::ResourceOperation { group: "batch", version: "v1", resource: "jobs/status", verb: "patch", operation_id: "patchBatchV1NamespacedJobStatus" },
+    crate::ResourceOperation { group: "batch", version: "v1", resource: "jobs/status", verb: "update", operation_id: "replaceBatchV1NamespacedJobStatus" },
+    crate::ResourceOperation { group: "batch", version: "v1beta1", resource: "cronjobs", verb: "create", operation_id: "createBatchV1beta1NamespacedCronJob" },
+    crate::ResourceOperation { group: "batch", version: "v1beta1", resource: "cronjobs", verb: "delete", operation_id: "deleteBatchV1beta1NamespacedCronJob" },
+    crate::ResourceOperation { group: "batch", version: "v1beta1", resource: "cronjobs", verb: "deletecollection", operation_id: "deleteBatchV1beta1CollectionNamespacedCronJob" },
+    crate::ResourceOperation { group: "batch", version: "v1beta1", resource: "cronjobs", verb: "get", operation_id: "readBatchV1beta1NamespacedCronJob" },
+    crate::ResourceOperation { group: "batch", version: "v1beta1", resource: "cronjobs", verb: "list", operation_id: "listBatchV1beta1CronJobForAllNamespaces" },
+    crate::ResourceOperation { group: "batch", version: "v1beta1", resource: "cronjobs", verb: "list", operation_id: "listBatchV1beta1NamespacedCronJob" },
+    crate::ResourceOperation { group: "batch", version: "v1beta1", resource: "cronjobs", verb: "patch", operation_id: "patchBatchV1beta1NamespacedCronJob" },
+    crate::ResourceOperation { group: "batch", version: "v1beta1", resource: "cronjobs", verb: "update", operation_id: "replaceBatchV1beta1NamespacedCronJob" },
+    crate::ResourceOperation { group: "batch", version: "v1beta1", resource: "cronjobs", verb: "watch", operation_id: "watchBatchV1beta1CronJobForAllNamespaces" },
+    crate::ResourceOperation { group: "batch", version: "v1beta1", resource: "cronjobs", verb: "watch", operation_id: "watchBatchV1beta1NamespacedCronJob" },
+    crate::ResourceOperation { group: "batch", version: "v1beta1", resource: "cronjobs/status", verb: "get", operation_id: "readBatchV1beta1NamespacedCronJobStatus" },
+    crate::ResourceOperation { group: "batch", version: "v1beta1", resource: "cronjobs/status", verb: "patch", operation_id: "patchBatchV1beta1NamespacedCronJobStatus" },
+    crate::ResourceOperation { group: "batch", version: "v1beta1", resource: "cronjobs/status", verb: "update", operation_id: "replaceBatchV1beta1NamespacedCronJobStatus" },
+    crate::ResourceOperation { group: "certificates.k8s.io", version: "v1", resource: "certificatesigningrequests", verb: "create", operation_id: "createCertificatesV1CertificateSigningRequest" },
+    crate::ResourceOperation { group: "certificates.k8s.io", version: "v1", resource: "certificatesigningrequests", verb: "delete", operation_id: "deleteCertificatesV1CertificateSigningRequest" },
+    crate::ResourceOperation { group: "certificates.k8s.io", version: "v1", resource: "certificatesigningrequests", verb: "deletecollection", operation_id: "deleteCertificatesV1CollectionCertificateSigningRequest" },
+    crate::ResourceOperation { group: "certificates.k8s.io", version: "v1", resource: "certificatesigningrequests", verb: "get", operation_id: "readCertificatesV1CertificateSigningRequest" },
+    crate::ResourceOperation { group: "certificates.k8s.io", version: "v1", resource: "certificatesigningrequests", verb: "list", operation_id: "listCertificatesV1CertificateSigningRequest" },
+    crate::ResourceOperation { group: "certificates.k8s.io", version: "v1", resource: "certificatesigningrequests", verb: "patch", operation_id: "patchCertificatesV1CertificateSigningRequest" },
+    crate::ResourceOperation { group: "certificates.k8s.io", version: "v1", resource: "certificatesigningrequests", verb: "update", operation_id: "replaceCertificatesV1CertificateSigningRequest" },
+    crate::ResourceOperation { group: "certificates.k8s.io", version: "v1", resource: "certificatesigningrequests", verb: "watch", operation_id: "watchCertificatesV1CertificateSigningRequest" },
+    crate::ResourceOperation { group: "certificates.k8s.io", version: "v1", resource: "certificatesigningrequests/approval", verb: "get", operation_id: "readCertificatesV1CertificateSigningRequestApproval" },
+    crate::ResourceOperation { group: "certificates.k8s.io", version: "v1", resource: "certificatesigningrequests/approval", verb: "patch", operation_id: "patchCertificatesV1CertificateSigningRequestApproval" },
+    crate::ResourceOperation { group: "certificates.k8s.io", version: "v1", resource: "certificatesigningrequests/approval", verb: "update", operation_id: "replaceCertificatesV1CertificateSigningRequestApproval" },
+    crate::ResourceOperation { group: "certificates.k8s.io", version: "v1", resource: "certificatesigningrequests/status", verb: "get", operation_id: "readCertificatesV1CertificateSigningRequestStatus" },
+    crate::ResourceOperation { group: "certificates.k8s.io", version: "v1", resource: "certificatesigningrequests/status", verb: "patch", operation_id: "patchCertificatesV1CertificateSigningRequestStatus" },
+    crate::ResourceOperation { group: "certificates.k8s.io", version: "v1", resource: "certificatesigningrequests/status", verb: "update", operation_id: "replaceCertificatesV1CertificateSigningRequestStatus" },
+    crate::ResourceOperation { group: "coordination.k8s.io", version: "v1", resource: "leases", verb: "create", operation_id: "createCoordinationV1NamespacedLease" },
+    crate::ResourceOperation { group: "coordination.k8s.io", version: "v1", resource: "leases", verb: "delete", operation_id: "deleteCoordinationV1NamespacedLease" },
+    crate::ResourceOperation { group: "coordination.k8s.io", version: "v1", resource: "leases", verb: "deletecollection", operation_id: "deleteCoordinationV1CollectionNamespacedLease" },
+    crate::ResourceOperation { group: "coordination.k8s.io", version: "v1", resource: "leases", verb: "get", operation_id: "readCoordinationV1NamespacedLease" },
+    crate::ResourceOperation { group: "coordination.k8s.io", version: "v1", resource: "leases", verb: "list", operation_id: "listCoordinationV1LeaseForAllNamespaces" },
+    crate::ResourceOperation { group: "coordination.k8s.io", version: "v1", resource: "leases", verb: "list", operation_id: "listCoordinationV1NamespacedLease" },
+    crate::ResourceOperation { group: "coordination.k8s.io", version: "v1", resource: "leases", verb: "patch", operation_id: "patchCoordinationV1NamespacedLease" },
+    crate::ResourceOperation { group: "coordination.k8s.io", version: "v1", resource: "leases", verb: "update", operation_id: "replaceCoordinationV1NamespacedLease" },
+    crate::ResourceOperation { group: "coordination.k8s.io", version: "v1", resource: "leases", verb: "watch", operation_id: "watchCoordinationV1LeaseForAllNamespaces" },
+    crate::ResourceOperation { group: "coordination.k8s.io", version: "v1", resource: "leases", verb: "watch", operation_id: "watchCoordinationV1NamespacedLease" },
+    crate::ResourceOperation { group: "discovery.k8s.io", version: "v1", resource: "endpointslices", verb: "create", operation_id: "createDiscoveryV1NamespacedEndpointSlice" },
+    crate::ResourceOperation { group: "discovery.k8s.io", version: "v1", resource: "endpointslices", verb: "delete", operation_id: "deleteDiscoveryV1NamespacedEndpointSlice" },
+    crate::ResourceOperation { group: "discovery.k8s.io", version: "v1", resource: "endpointslices", verb: "deletecollection", operation_id: "deleteDiscoveryV1CollectionNamespacedEndpointSlice" },
+    crate::ResourceOperation { group: "discovery.k8s.io", version: "v1", resource: "endpointslices", verb: "get", operation_id: "readDiscoveryV1NamespacedEndpointSlice" },
+    crate::ResourceOperation { group: "discovery.k8s.io", version: "v1", resource: "endpointslices", verb: "list", operation_id: "listDiscoveryV1EndpointSliceForAllNamespaces" },
+    crate::ResourceOperation { group: "discovery.k8s.io", version: "v1", resource: "endpointslices", verb: "list", operation_id: "listDiscoveryV1NamespacedEndpointSlice" },
+    crate::ResourceOperation { group: "discovery.k8s.io", version: "v1", resource: "endpointslices", verb: "patch", operation_id: "patchDiscoveryV1NamespacedEndpointSlice" },
+    crate::ResourceOperation { group: "discovery.k8s.io", version: "v1", resource: "endpointslices", verb: "update", operation_id: "replaceDiscoveryV1NamespacedEndpointSlice" },
+    crate::ResourceOperation { group: "discovery.k8s.io", version: "v1", resource: "endpointslices", verb: "watch", operation_id: "watchDiscoveryV1EndpointSliceForAllNamespaces" },
+    crate::ResourceOperation { group: "discovery.k8s.io", version: "v1", resource: "endpointslices", verb: "watch", operation_id: "watchDiscoveryV1NamespacedEndpointSlice" },
+    crate::ResourceOperation { group: "discovery.k8s.io", version: "v1beta1", resource: "endpointslices", verb: "create", operation_id: "createDiscoveryV1beta1NamespacedEndpointSlice" },
+    crate::ResourceOperation { group: "discovery.k8s.io", version: "v1beta1", resource: "endpointslices", verb: "delete", operation_id: "deleteDiscoveryV1beta1NamespacedEndpointSlice" },
+    crate::ResourceOperation { group: "discovery.k8s.io", version: "v1beta1", resource: "endpointslices", verb: "deletecollection", operation_id: "deleteDiscoveryV1beta1CollectionNamespacedEndpointSlice" },
+    crate::ResourceOperation { group: "discovery.k8s.io", version: "v1beta1", resource: "endpointslices", verb: "get", operation_id: "readDiscoveryV1beta1NamespacedEndpointSlice" },
+    crate::ResourceOperation { group: "discovery.k8s.io", version: "v1beta1", resource: "endpointslices", verb: "list", operation_id: "listDiscoveryV1beta1EndpointSliceForAllNamespaces" },
+    crate::ResourceOperation { group: "discovery.k8s.io", version: "v1beta1", resource: "endpointslices", verb: "list", operation_id: "listDiscoveryV1beta1NamespacedEndpointSlice" },
+    crate::ResourceOperation { group: "discovery.k8s.io", version: "v1beta1", resource: "endpointslices", verb: "patch", operation_id: "patchDiscoveryV1beta1NamespacedEndpointSlice" },
+    crate::ResourceOperation { group: "discovery.k8s.io", version: "v1beta1", resource: "endpointslices", verb: "update", operation_id: "replaceDiscoveryV1beta1NamespacedEndpointSlice" },
+    crate::ResourceOperation { group: "discovery.k8s.io", version: "v1beta1", resource: "endpointslices", verb: "watch", operation_id: "watchDiscoveryV1beta1EndpointSliceForAllNamespaces" },
+    crate::ResourceOperation { group: "discovery.k8s.io", version: "v1beta1", resource: "endpointslices", verb: "watch", operation_id: "watchDiscoveryV1beta1NamespacedEndpointSlice" },
+    crate::ResourceOperation { group: "events.k8s.io", version: "v1", resource: "events", verb: "create", operation_id: "createEventsV1NamespacedEvent" },
+    crate::ResourceOperation { group: "events.k8s.io", version: "v1", resource: "events", verb: "delete", operation_id: "deleteEventsV1NamespacedEvent" },
+    crate::ResourceOperation { group: "events.k8s.io", version: "v1", resource: "events", verb: "deletecollection", operation_id: "deleteEventsV1CollectionNamespacedEvent" },
+    crate::ResourceOperation { group: "events.k8s.io", version: "v1", resource: "events", verb: "get", operation_id: "readEventsV1NamespacedEvent" },
+    crate::ResourceOperation { group: "events.k8s.io", version: "v1", resource: "events", verb: "list", operation_id: "listEventsV1EventForAllNamespaces" },
+    crate::ResourceOperation { group: "events.k8s.io", version: "v1", resource: "events", verb: "list", operation_id: "listEventsV1NamespacedEvent" },
+    crate::ResourceOperation { group: "events.k8s.io", version: "v1", resource: "events", verb: "patch", operation_id: "patchEventsV1NamespacedEvent" },
+    crate::ResourceOperation { group: "events.k8s.io", version: "v1", resource: "events", verb: "update", operation_id: "replaceEventsV1NamespacedEvent" },
+    crate::ResourceOperation { group: "events.k8s.io", version: "v1", resource: "events", verb: "watch", operation_id: "watchEventsV1EventForAllNamespaces" },
+    crate::ResourceOperation { group: "events.k8s.io", version: "v1", resource: "events", verb: "watch", operation_id: "watchEventsV1NamespacedEvent" },
+    crate::ResourceOperation { group: "events.k8s.io", version: "v1beta1", resource: "events", verb: "create", operation_id: "createEventsV1beta1NamespacedEvent" },
+    crate::ResourceOperation { group: "events.k8s.io", version: "v1beta1", resource: "events", verb: "delete", operation_id: "deleteEventsV1beta1NamespacedEvent" },
+    crate::ResourceOperation { group: "events.k8s.io", version: "v1beta1", resource: "events", verb: "deletecollection", operation_id: "deleteEventsV1beta1CollectionNamespacedEvent" },
+    crate::ResourceOperation { group: "events.k8s.io", version: "v1beta1", resource: "events", verb: "get", operation_id: "readEventsV1beta1NamespacedEvent" },
+    crate::ResourceOperation { group: "events.k8s.io", version: "v1beta1", resource: "events", verb: "list", operation_id: "listEventsV1beta1EventForAllNamespaces" },
+    crate::ResourceOperation { group: "events.k8s.io", version: "v1beta1", resource: "events", verb: "list", operation_id: "listEventsV1beta1NamespacedEvent" },
+    crate::ResourceOperation { group: "events.k8s.io", version: "v1beta1", resource: "events", verb: "patch", operation_id: "patchEventsV1beta1NamespacedEvent" },
+    crate::ResourceOperation { group: "events.k8s.io", version: "v1beta1", resource: "events", verb: "update", operation_id: "replaceEventsV1beta1NamespacedEvent" },
+    crate::ResourceOperation { group: "events.k8s.io", version: "v1beta1", resource: "events", verb: "watch", operation_id: "watchEventsV1beta1EventForAllNamespaces" },
+    crate::ResourceOperation { group: "events.k8s.io", version: "v1beta1", resource: "events", verb: "watch", operation_id: "watchEventsV1beta1NamespacedEvent" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta1", resource: "flowschemas", verb: "create", operation_id: "createFlowcontrolApiserverV1beta1FlowSchema" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta1", resource: "flowschemas", verb: "delete", operation_id: "deleteFlowcontrolApiserverV1beta1FlowSchema" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta1", resource: "flowschemas", verb: "deletecollection", operation_id: "deleteFlowcontrolApiserverV1beta1CollectionFlowSchema" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta1", resource: "flowschemas", verb: "get", operation_id: "readFlowcontrolApiserverV1beta1FlowSchema" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta1", resource: "flowschemas", verb: "list", operation_id: "listFlowcontrolApiserverV1beta1FlowSchema" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta1", resource: "flowschemas", verb: "patch", operation_id: "patchFlowcontrolApiserverV1beta1FlowSchema" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta1", resource: "flowschemas", verb: "update", operation_id: "replaceFlowcontrolApiserverV1beta1FlowSchema" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta1", resource: "flowschemas", verb: "watch", operation_id: "watchFlowcontrolApiserverV1beta1FlowSchema" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta1", resource: "flowschemas/status", verb: "get", operation_id: "readFlowcontrolApiserverV1beta1FlowSchemaStatus" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta1", resource: "flowschemas/status", verb: "patch", operation_id: "patchFlowcontrolApiserverV1beta1FlowSchemaStatus" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta1", resource: "flowschemas/status", verb: "update", operation_id: "replaceFlowcontrolApiserverV1beta1FlowSchemaStatus" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta1", resource: "prioritylevelconfigurations", verb: "create", operation_id: "createFlowcontrolApiserverV1beta1PriorityLevelConfiguration" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta1", resource: "prioritylevelconfigurations", verb: "delete", operation_id: "deleteFlowcontrolApiserverV1beta1PriorityLevelConfiguration" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta1", resource: "prioritylevelconfigurations", verb: "deletecollection", operation_id: "deleteFlowcontrolApiserverV1beta1CollectionPriorityLevelConfiguration" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta1", resource: "prioritylevelconfigurations", verb: "get", operation_id: "readFlowcontrolApiserverV1beta1PriorityLevelConfiguration" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta1", resource: "prioritylevelconfigurations", verb: "list", operation_id: "listFlowcontrolApiserverV1beta1PriorityLevelConfiguration" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta1", resource: "prioritylevelconfigurations", verb: "patch", operation_id: "patchFlowcontrolApiserverV1beta1PriorityLevelConfiguration" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta1", resource: "prioritylevelconfigurations", verb: "update", operation_id: "replaceFlowcontrolApiserverV1beta1PriorityLevelConfiguration" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta1", resource: "prioritylevelconfigurations", verb: "watch", operation_id: "watchFlowcontrolApiserverV1beta1PriorityLevelConfiguration" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta1", resource: "prioritylevelconfigurations/status", verb: "get", operation_id: "readFlowcontrolApiserverV1beta1PriorityLevelConfigurationStatus" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta1", resource: "prioritylevelconfigurations/status", verb: "patch", operation_id: "patchFlowcontrolApiserverV1beta1PriorityLevelConfigurationStatus" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta1", resource: "prioritylevelconfigurations/status", verb: "update", operation_id: "replaceFlowcontrolApiserverV1beta1PriorityLevelConfigurationStatus" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta2", resource: "flowschemas", verb: "create", operation_id: "createFlowcontrolApiserverV1beta2FlowSchema" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta2", resource: "flowschemas", verb: "delete", operation_id: "deleteFlowcontrolApiserverV1beta2FlowSchema" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta2", resource: "flowschemas", verb: "deletecollection", operation_id: "deleteFlowcontrolApiserverV1beta2CollectionFlowSchema" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta2", resource: "flowschemas", verb: "get", operation_id: "readFlowcontrolApiserverV1beta2FlowSchema" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta2", resource: "flowschemas", verb: "list", operation_id: "listFlowcontrolApiserverV1beta2FlowSchema" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta2", resource: "flowschemas", verb: "patch", operation_id: "patchFlowcontrolApiserverV1beta2FlowSchema" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta2", resource: "flowschemas", verb: "update", operation_id: "replaceFlowcontrolApiserverV1beta2FlowSchema" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta2", resource: "flowschemas", verb: "watch", operation_id: "watchFlowcontrolApiserverV1beta2FlowSchema" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta2", resource: "flowschemas/status", verb: "get", operation_id: "readFlowcontrolApiserverV1beta2FlowSchemaStatus" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta2", resource: "flowschemas/status", verb: "patch", operation_id: "patchFlowcontrolApiserverV1beta2FlowSchemaStatus" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta2", resource: "flowschemas/status", verb: "update", operation_id: "replaceFlowcontrolApiserverV1beta2FlowSchemaStatus" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta2", resource: "prioritylevelconfigurations", verb: "create", operation_id: "createFlowcontrolApiserverV1beta2PriorityLevelConfiguration" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta2", resource: "prioritylevelconfigurations", verb: "delete", operation_id: "deleteFlowcontrolApiserverV1beta2PriorityLevelConfiguration" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta2", resource: "prioritylevelconfigurations", verb: "deletecollection", operation_id: "deleteFlowcontrolApiserverV1beta2CollectionPriorityLevelConfiguration" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta2", resource: "prioritylevelconfigurations", verb: "get", operation_id: "readFlowcontrolApiserverV1beta2PriorityLevelConfiguration" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta2", resource: "prioritylevelconfigurations", verb: "list", operation_id: "listFlowcontrolApiserverV1beta2PriorityLevelConfiguration" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta2", resource: "prioritylevelconfigurations", verb: "patch", operation_id: "patchFlowcontrolApiserverV1beta2PriorityLevelConfiguration" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta2", resource: "prioritylevelconfigurations", verb: "update", operation_id: "replaceFlowcontrolApiserverV1beta2PriorityLevelConfiguration" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta2", resource: "prioritylevelconfigurations", verb: "watch", operation_id: "watchFlowcontrolApiserverV1beta2PriorityLevelConfiguration" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta2", resource: "prioritylevelconfigurations/status", verb: "get", operation_id: "readFlowcontrolApiserverV1beta2PriorityLevelConfigurationStatus" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta2", resource: "prioritylevelconfigurations/status", verb: "patch", operation_id: "patchFlowcontrolApiserverV1beta2PriorityLevelConfigurationStatus" },
+    crate::ResourceOperation { group: "flowcontrol.apiserver.k8s.io", version: "v1beta2", resource: "prioritylevelconfigurations/status", verb: "update", operation_id: "replaceFlowcontrolApiserverV1beta2PriorityLevelConfigurationStatus" },
+    crate::ResourceOperation { group: "internal.apiserver.k8s.io", version: "v1alpha1", resource: "storageversions", verb: "create", operation_id: "createInternalApiserverV1alpha1StorageVersion" },
+    crate::ResourceOperation { group: "internal.apiserver.k8s.io", version: "v1alpha1", resource: "storageversions", verb: "delete", operation_id: "deleteInternalApiserverV1alpha1StorageVersion" },
+    crate::ResourceOperation { group: "internal.apiserver.k8s.io", version: "v1alpha1", resource: "storageversions", verb: "deletecollection", operation_id: "deleteInternalApiserverV1alpha1CollectionStorageVersion" },
+    crate::ResourceOperation { group: "internal.apiserver.k8s.io", version: "v1alpha1", resource: "storageversions", verb: "get", operation_id: "readInternalApiserverV1alpha1StorageVersion" },
+    crate::ResourceOperation { group: "internal.apiserver.k8s.io", version: "v1alpha1", resource: "storageversions", verb: "list", operation_id: "listInternalApiserverV1alpha1StorageVersion" },
+    crate::ResourceOperation { group: "internal.apiserver.k8s.io", version: "v1alpha1", resource: "storageversions", verb: "patch", operation_id: "patchInternalApiserverV1alpha1StorageVersion" },
+    crate::ResourceOperation { group: "internal.apiserver.k8s.io", version: "v1alpha1", resource: "storageversions", verb: "update", operation_id: "replaceInternalApiserverV1alpha1StorageVersion" },
+    crate::ResourceOperation { group: "internal.apiserver.k8s.io", version: "v1alpha1", resource: "storageversions", verb: "watch", operation_id: "watchInternalApiserverV1alpha1StorageVersion" },
+    crate::ResourceOperation { group: "internal.apiserver.k8s.io", version: "v1alpha1", resource: "storageversions/status", verb: "get", operation_id: "readInternalApiserverV1alpha1StorageVersionStatus" },
+    crate::ResourceOperation { group: "internal.apiserver.k8s.io", version: "v1alpha1", resource: "storageversions/status", verb: "patch", operation_id: "patchInternalApiserverV1alpha1StorageVersionStatus" },
+    crate::ResourceOperation { group: "internal.apiserver.k8s.io", version: "v1alpha1", resource: "storageversions/status", verb: "update", operation_id: "replaceInternalApiserverV1alpha1StorageVersionStatus" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "ingressclasses", verb: "create", operation_id: "createNetworkingV1IngressClass" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "ingressclasses", verb: "delete", operation_id: "deleteNetworkingV1IngressClass" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "ingressclasses", verb: "deletecollection", operation_id: "deleteNetworkingV1CollectionIngressClass" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "ingressclasses", verb: "get", operation_id: "readNetworkingV1IngressClass" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "ingressclasses", verb: "list", operation_id: "listNetworkingV1IngressClass" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "ingressclasses", verb: "patch", operation_id: "patchNetworkingV1IngressClass" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "ingressclasses", verb: "update", operation_id: "replaceNetworkingV1IngressClass" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "ingressclasses", verb: "watch", operation_id: "watchNetworkingV1IngressClass" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "ingresses", verb: "create", operation_id: "createNetworkingV1NamespacedIngress" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "ingresses", verb: "delete", operation_id: "deleteNetworkingV1NamespacedIngress" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "ingresses", verb: "deletecollection", operation_id: "deleteNetworkingV1CollectionNamespacedIngress" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "ingresses", verb: "get", operation_id: "readNetworkingV1NamespacedIngress" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "ingresses", verb: "list", operation_id: "listNetworkingV1IngressForAllNamespaces" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "ingresses", verb: "list", operation_id: "listNetworkingV1NamespacedIngress" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "ingresses", verb: "patch", operation_id: "patchNetworkingV1NamespacedIngress" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "ingresses", verb: "update", operation_id: "replaceNetworkingV1NamespacedIngress" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "ingresses", verb: "watch", operation_id: "watchNetworkingV1IngressForAllNamespaces" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "ingresses", verb: "watch", operation_id: "watchNetworkingV1NamespacedIngress" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "ingresses/status", verb: "get", operation_id: "readNetworkingV1NamespacedIngressStatus" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "ingresses/status", verb: "patch", operation_id: "patchNetworkingV1NamespacedIngressStatus" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "ingresses/status", verb: "update", operation_id: "replaceNetworkingV1NamespacedIngressStatus" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "networkpolicies", verb: "create", operation_id: "createNetworkingV1NamespacedNetworkPolicy" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "networkpolicies", verb: "delete", operation_id: "deleteNetworkingV1NamespacedNetworkPolicy" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "networkpolicies", verb: "deletecollection", operation_id: "deleteNetworkingV1CollectionNamespacedNetworkPolicy" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "networkpolicies", verb: "get", operation_id: "readNetworkingV1NamespacedNetworkPolicy" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "networkpolicies", verb: "list", operation_id: "listNetworkingV1NamespacedNetworkPolicy" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "networkpolicies", verb: "list", operation_id: "listNetworkingV1NetworkPolicyForAllNamespaces" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "networkpolicies", verb: "patch", operation_id: "patchNetworkingV1NamespacedNetworkPolicy" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "networkpolicies", verb: "update", operation_id: "replaceNetworkingV1NamespacedNetworkPolicy" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "networkpolicies", verb: "watch", operation_id: "watchNetworkingV1NamespacedNetworkPolicy" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "networkpolicies", verb: "watch", operation_id: "watchNetworkingV1NetworkPolicyForAllNamespaces" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "networkpolicies/status", verb: "get", operation_id: "readNetworkingV1NamespacedNetworkPolicyStatus" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "networkpolicies/status", verb: "patch", operation_id: "patchNetworkingV1NamespacedNetworkPolicyStatus" },
+    crate::ResourceOperation { group: "networking.k8s.io", version: "v1", resource: "networkpolicies/status", verb: "update", operation_id: "replaceNetworkingV1NamespacedNetworkPolicyStatus" },
+    crate::ResourceOperation { group: "node.k8s.io", version: "v1", resource: "runtimeclasses", verb: "create", operation_id: "createNodeV1RuntimeClass" },
+    crate::ResourceOperation { group: "node.k8s.io", version: "v1", resource: "runtimeclasses", verb: "delete", operation_id: "deleteNodeV1RuntimeClass" },
+    crate::ResourceOperation { group: "node.k8s.io", version: "v1", resource: "runtimeclasses", verb: "deletecollection", operation_id: "deleteNodeV1CollectionRuntimeClass" },
+    crate::ResourceOperation { group: "node.k8s.io", version: "v1", resource: "runtimeclasses", verb: "get", operation_id: "readNodeV1RuntimeClass" },
+    crate::ResourceOperation { group: "node.k8s.io", version: "v1", resource: "runtimeclasses", verb: "list", operation_id: "listNodeV1RuntimeClass" },
+    crate::ResourceOperation { group: "node.k8s.io", version: "v1", resource: "runtimeclasses", verb: "patch", operation_id: "patchNodeV1RuntimeClass" },
+    crate::ResourceOperation { group: "node.k8s.io", version: "v1", resource: "runtimeclasses", verb: "update", operation_id: "replaceNodeV1RuntimeClass" },
+    crate::ResourceOperation { group: "node.k8s.io", version: "v1", resource: "runtimeclasses", verb: "watch", operation_id: "watchNodeV1RuntimeClass" },
+    crate::ResourceOperation { group: "node.k8s.io", version: "v1beta1", resource: "runtimeclasses", verb: "create", operation_id: "createNodeV1beta1RuntimeClass" },
+    crate::ResourceOperation { group: "node.k8s.io", version: "v1beta1", resource: "runtimeclasses", verb: "delete", operation_id: "deleteNodeV1beta1RuntimeClass" },
+    crate::ResourceOperation { group: "node.k8s.io", version: "v1beta1", resource: "runtimeclasses", verb: "deletecollection", operation_id: "deleteNodeV1beta1CollectionRuntimeClass" },
+    crate::ResourceOperation { group: "node.k8s.io", version: "v1beta1", resource: "runtimeclasses", verb: "get", operation_id: "readNodeV1beta1RuntimeClass" },
+    crate::ResourceOperation { group: "node.k8s.io", version: "v1beta1", resource: "runtimeclasses", verb: "list", operation_id: "listNodeV1beta1RuntimeClass" },
+    crate::ResourceOperation { group: "node.k8s.io", version: "v1beta1", resource: "runtimeclasses", verb: "patch", operation_id: "patchNodeV1beta1RuntimeClass" },
+    crate::ResourceOperation { group: "node.k8s.io", version: "v1beta1", resource: "runtimeclasses", verb: "update", operation_id: "replaceNodeV1beta1RuntimeClass" },
+    crate::ResourceOperation { group: "node.k8s.io", version: "v1beta1", resource: "runtimeclasses", verb: "watch", operation_id: "watchNodeV1beta1RuntimeClass" },
+    crate::ResourceOperation { group: "policy", version: "v1", resource: "poddisruptionbudgets", verb: "create", operation_id: "createPolicyV1NamespacedPodDisruptionBudget" },
+    crate::ResourceOperation { group: "policy", version: "v1", resource: "poddisruptionbudgets", verb: "delete", operation_id: "deletePolicyV1NamespacedPodDisruptionBudget" },
+    crate::ResourceOperation { group: "policy", version: "v1", resource: "poddisruptionbudgets", verb: "deletecollection", operation_id: "deletePolicyV1CollectionNamespacedPodDisruptionBudget" },
+    crate::ResourceOperation { group: "policy", version: "v1", resource: "poddisruptionbudgets", verb: "get", operation_id: "readPolicyV1NamespacedPodDisruptionBudget" },
+    crate::ResourceOperation { group: "policy", version: "v1", resource: "poddisruptionbudgets", verb: "list", operation_id: "listPolicyV1NamespacedPodDisruptionBudget" },
+    crate::ResourceOperation { group: "policy", version: "v1", resource: "poddisruptionbudgets", verb: "list", operation_id: "listPolicyV1PodDisruptionBudgetForAllNamespaces" },
+    crate::ResourceOperation { group: "policy", version: "v1", resource: "poddisruptionbudgets", verb: "patch", operation_id: "patchPolicyV1NamespacedPodDisruptionBudget" },
+    crate::ResourceOperation { group: "policy", version: "v1", resource: "poddisruptionbudgets", verb: "update", operation_id: "replacePolicyV1NamespacedPodDisruptionBudget" },
+    crate::ResourceOperation { group: "policy", version: "v1", resource: "poddisruptionbudgets", verb: "watch", operation_id: "watchPolicyV1NamespacedPodDisruptionBudget" },
+    crate::ResourceOperation { group: "policy", version: "v1", resource: "poddisruptionbudgets", verb: "watch", operation_id: "watchPolicyV1PodDisruptionBudgetForAllNamespaces" },
+    crate::ResourceOperation { group: "policy", version: "v1", resource: "poddisruptionbudgets/status", verb: "get", operation_id: "readPolicyV1NamespacedPodDisruptionBudgetStatus" },
+    crate::ResourceOperation { group: "policy", version: "v1", resource: "poddisruptionbudgets/status", verb: "patch", operation_id: "patchPolicyV1NamespacedPodDisruptionBudgetStatus" },
+    crate::ResourceOperation { group: "policy", version: "v1", resource: "poddisruptionbudgets/status", verb: "update", operation_id: "replacePolicyV1NamespacedPodDisruptionBudgetStatus" },
+    crate::ResourceOperation { group: "policy", version: "v1beta1", resource: "poddisruptionbudgets", verb: "create", operation_id: "createPolicyV1beta1NamespacedPodDisruptionBudget" },
+    crate::ResourceOperation { group: "policy", version: "v1beta1", resource: "poddisruptionbudgets", verb: "delete", operation_id: "deletePolicyV1beta1NamespacedPodDisruptionBudget" },
+    crate::ResourceOperation { group: "policy", version: "v1beta1", resource: "poddisruptionbudgets", verb: "deletecollection", operation_id: "deletePolicyV1beta1CollectionNamespacedPodDisruptionBudget" },
+    crate::ResourceOperation { group: "policy", version: "v1beta1", resource: "poddisruptionbudgets", verb: "get", operation_id: "readPolicyV1beta1NamespacedPodDisruptionBudget" },
+    crate::ResourceOperation { group: "policy", version: "v1beta1", resource: "poddisruptionbudgets", verb: "list", operation_id: "listPolicyV1beta1NamespacedPodDisruptionBudget" },
+    crate::ResourceOperation { group: "policy", version: "v1beta1", resource: "poddisruptionbudgets", verb: "list", operation_id: "listPolicyV1beta1PodDisruptionBudgetForAllNamespaces" },
+    crate::ResourceOperation { group: "policy", version: "v1beta1", resource: "poddisruptionbudgets", verb: "patch", operation_id: "patchPolicyV1beta1NamespacedPodDisruptionBudget" },
+    crate::ResourceOperation { group: "policy", version: "v1beta1", resource: "poddisruptionbudgets", verb: "update", operation_id: "replacePolicyV1beta1NamespacedPodDisruptionBudget" },
+    crate::ResourceOperation { group: "policy", version: "v1beta1", resource: "poddisruptionbudgets", verb: "watch", operation_id: "watchPolicyV1beta1NamespacedPodDisruptionBudget" },
+    crate::ResourceOperation { group: "policy", version: "v1beta1", resource: "poddisruptionbudgets", verb: "watch", operation_id: "watchPolicyV1beta1PodDisruptionBudgetForAllNamespaces" },
+    crate::ResourceOperation { group: "policy", version: "v1beta1", resource: "poddisruptionbudgets/status", verb: "get", operation_id: "readPolicyV1beta1NamespacedPodDisruptionBudgetStatus" },
+    crate::ResourceOperation { group: "policy", version: "v1beta1", resource: "poddisruptionbudgets/status", verb: "patch", operation_id: "patchPolicyV1beta1NamespacedPodDisruptionBudgetStatus" },
+    crate::ResourceOperation { group: "policy", version: "v1beta1", resource: "poddisruptionbudgets/status", verb: "update", operation_id: "replacePolicyV1beta1NamespacedPodDisruptionBudgetStatus" },
+    crate::ResourceOperation { group: "policy", version: "v1beta1", resource: "podsecuritypolicies", verb: "create", operation_id: "createPolicyV1beta1PodSecurityPolicy" },
+    crate::ResourceOperation { group: "policy", version: "v1beta1", resource: "podsecuritypolicies", verb: "delete", operation_id: "deletePolicyV1beta1PodSecurityPolicy" },
+    crate::ResourceOperation { group: "policy", version: "v1beta1", resource: "podsecuritypolicies", verb: "deletecollection", operation_id: "deletePolicyV1beta1CollectionPodSecurityPolicy" },
+    crate::ResourceOperation { group: "policy", version: "v1beta1", resource: "podsecuritypolicies", verb: "get", operation_id: "readPolicyV1beta1PodSecurityPolicy" },
+    crate::ResourceOperation { group: "policy", version: "v1beta1", resource: "podsecuritypolicies", verb: "list", operation_id: "listPolicyV1beta1PodSecurityPolicy" },
+    crate::ResourceOperation { group: "policy", version: "v1beta1", resource: "podsecuritypolicies", verb: "patch", operation_id: "patchPolicyV1beta1PodSecurityPolicy" },
+    crate::ResourceOperation { group: "policy", version: "v1beta1", resource: "podsecuritypolicies", verb: "update", operation_id: "replacePolicyV1beta1PodSecurityPolicy" },
+    crate::ResourceOperation { group: "policy", version: "v1beta1", resource: "podsecuritypolicies", verb: "watch", operation_id: "watchPolicyV1beta1PodSecurityPolicy" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "clusterrolebindings", verb: "create", operation_id: "createRbacAuthorizationV1ClusterRoleBinding" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "clusterrolebindings", verb: "delete", operation_id: "deleteRbacAuthorizationV1ClusterRoleBinding" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "clusterrolebindings", verb: "deletecollection", operation_id: "deleteRbacAuthorizationV1CollectionClusterRoleBinding" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "clusterrolebindings", verb: "get", operation_id: "readRbacAuthorizationV1ClusterRoleBinding" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "clusterrolebindings", verb: "list", operation_id: "listRbacAuthorizationV1ClusterRoleBinding" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "clusterrolebindings", verb: "patch", operation_id: "patchRbacAuthorizationV1ClusterRoleBinding" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "clusterrolebindings", verb: "update", operation_id: "replaceRbacAuthorizationV1ClusterRoleBinding" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "clusterrolebindings", verb: "watch", operation_id: "watchRbacAuthorizationV1ClusterRoleBinding" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "clusterroles", verb: "create", operation_id: "createRbacAuthorizationV1ClusterRole" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "clusterroles", verb: "delete", operation_id: "deleteRbacAuthorizationV1ClusterRole" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "clusterroles", verb: "deletecollection", operation_id: "deleteRbacAuthorizationV1CollectionClusterRole" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "clusterroles", verb: "get", operation_id: "readRbacAuthorizationV1ClusterRole" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "clusterroles", verb: "list", operation_id: "listRbacAuthorizationV1ClusterRole" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "clusterroles", verb: "patch", operation_id: "patchRbacAuthorizationV1ClusterRole" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "clusterroles", verb: "update", operation_id: "replaceRbacAuthorizationV1ClusterRole" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "clusterroles", verb: "watch", operation_id: "watchRbacAuthorizationV1ClusterRole" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "rolebindings", verb: "create", operation_id: "createRbacAuthorizationV1NamespacedRoleBinding" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "rolebindings", verb: "delete", operation_id: "deleteRbacAuthorizationV1NamespacedRoleBinding" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "rolebindings", verb: "deletecollection", operation_id: "deleteRbacAuthorizationV1CollectionNamespacedRoleBinding" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "rolebindings", verb: "get", operation_id: "readRbacAuthorizationV1NamespacedRoleBinding" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "rolebindings", verb: "list", operation_id: "listRbacAuthorizationV1NamespacedRoleBinding" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "rolebindings", verb: "list", operation_id: "listRbacAuthorizationV1RoleBindingForAllNamespaces" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "rolebindings", verb: "patch", operation_id: "patchRbacAuthorizationV1NamespacedRoleBinding" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "rolebindings", verb: "update", operation_id: "replaceRbacAuthorizationV1NamespacedRoleBinding" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "rolebindings", verb: "watch", operation_id: "watchRbacAuthorizationV1NamespacedRoleBinding" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "rolebindings", verb: "watch", operation_id: "watchRbacAuthorizationV1RoleBindingForAllNamespaces" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "roles", verb: "create", operation_id: "createRbacAuthorizationV1NamespacedRole" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "roles", verb: "delete", operation_id: "deleteRbacAuthorizationV1NamespacedRole" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "roles", verb: "deletecollection", operation_id: "deleteRbacAuthorizationV1CollectionNamespacedRole" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "roles", verb: "get", operation_id: "readRbacAuthorizationV1NamespacedRole" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "roles", verb: "list", operation_id: "listRbacAuthorizationV1NamespacedRole" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "roles", verb: "list", operation_id: "listRbacAuthorizationV1RoleForAllNamespaces" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "roles", verb: "patch", operation_id: "patchRbacAuthorizationV1NamespacedRole" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "roles", verb: "update", operation_id: "replaceRbacAuthorizationV1NamespacedRole" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "roles", verb: "watch", operation_id: "watchRbacAuthorizationV1NamespacedRole" },
+    crate::ResourceOperation { group: "rbac.authorization.k8s.io", version: "v1", resource: "roles", verb: "watch", operation_id: "watchRbacAuthorizationV1RoleForAllNamespaces" },
+    crate::ResourceOperation { group: "scheduling.k8s.io", version: "v1", resource: "priorityclasses", verb: "create", operation_id: "createSchedulingV1PriorityClass" },
+    crate::ResourceOperation { group: "scheduling.k8s.io", version: "v1", resource: "priorityclasses", verb: "delete", operation_id: "deleteSchedulingV1PriorityClass" },
+    crate::ResourceOperation { group: "scheduling.k8s.io", version: "v1", resource: "priorityclasses", verb: "deletecollection", operation_id: "deleteSchedulingV1CollectionPriorityClass" },
+    crate::ResourceOperation { group: "scheduling.k8s.io", version: "v1", resource: "priorityclasses", verb: "get", operation_id: "readSchedulingV1PriorityClass" },
+    crate::ResourceOperation { group: "scheduling.k8s.io", version: "v1", resource: "priorityclasses", verb: "list", operation_id: "listSchedulingV1PriorityClass" },
+    crate::ResourceOperation { group: "scheduling.k8s.io", version: "v1", resource: "priorityclasses", verb: "patch", operation_id: "patchSchedulingV1PriorityClass" },
+    crate::ResourceOperation { group: "scheduling.k8s.io", version: "v1", resource: "priorityclasses", verb: "update", operation_id: "replaceSchedulingV1PriorityClass" },
+    crate::ResourceOperation { group: "scheduling.k8s.io", version: "v1", resource: "priorityclasses", verb: "watch", operation_id: "watchSchedulingV1PriorityClass" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "csidrivers", verb: "create", operation_id: "createStorageV1CSIDriver" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "csidrivers", verb: "delete", operation_id: "deleteStorageV1CSIDriver" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "csidrivers", verb: "deletecollection", operation_id: "deleteStorageV1CollectionCSIDriver" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "csidrivers", verb: "get", operation_id: "readStorageV1CSIDriver" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "csidrivers", verb: "list", operation_id: "listStorageV1CSIDriver" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "csidrivers", verb: "patch", operation_id: "patchStorageV1CSIDriver" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "csidrivers", verb: "update", operation_id: "replaceStorageV1CSIDriver" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "csidrivers", verb: "watch", operation_id: "watchStorageV1CSIDriver" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "csinodes", verb: "create", operation_id: "createStorageV1CSINode" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "csinodes", verb: "delete", operation_id: "deleteStorageV1CSINode" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "csinodes", verb: "deletecollection", operation_id: "deleteStorageV1CollectionCSINode" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "csinodes", verb: "get", operation_id: "readStorageV1CSINode" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "csinodes", verb: "list", operation_id: "listStorageV1CSINode" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "csinodes", verb: "patch", operation_id: "patchStorageV1CSINode" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "csinodes", verb: "update", operation_id: "replaceStorageV1CSINode" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "csinodes", verb: "watch", operation_id: "watchStorageV1CSINode" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "csistoragecapacities", verb: "create", operation_id: "createStorageV1NamespacedCSIStorageCapacity" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "csistoragecapacities", verb: "delete", operation_id: "deleteStorageV1NamespacedCSIStorageCapacity" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "csistoragecapacities", verb: "deletecollection", operation_id: "deleteStorageV1CollectionNamespacedCSIStorageCapacity" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "csistoragecapacities", verb: "get", operation_id: "readStorageV1NamespacedCSIStorageCapacity" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "csistoragecapacities", verb: "list", operation_id: "listStorageV1CSIStorageCapacityForAllNamespaces" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "csistoragecapacities", verb: "list", operation_id: "listStorageV1NamespacedCSIStorageCapacity" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "csistoragecapacities", verb: "patch", operation_id: "patchStorageV1NamespacedCSIStorageCapacity" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "csistoragecapacities", verb: "update", operation_id: "replaceStorageV1NamespacedCSIStorageCapacity" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "csistoragecapacities", verb: "watch", operation_id: "watchStorageV1CSIStorageCapacityForAllNamespaces" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "csistoragecapacities", verb: "watch", operation_id: "watchStorageV1NamespacedCSIStorageCapacity" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "storageclasses", verb: "create", operation_id: "createStorageV1StorageClass" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "storageclasses", verb: "delete", operation_id: "deleteStorageV1StorageClass" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "storageclasses", verb: "deletecollection", operation_id: "deleteStorageV1CollectionStorageClass" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "storageclasses", verb: "get", operation_id: "readStorageV1StorageClass" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "storageclasses", verb: "list", operation_id: "listStorageV1StorageClass" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "storageclasses", verb: "patch", operation_id: "patchStorageV1StorageClass" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "storageclasses", verb: "update", operation_id: "replaceStorageV1StorageClass" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "storageclasses", verb: "watch", operation_id: "watchStorageV1StorageClass" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "volumeattachments", verb: "create", operation_id: "createStorageV1VolumeAttachment" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "volumeattachments", verb: "delete", operation_id: "deleteStorageV1VolumeAttachment" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "volumeattachments", verb: "deletecollection", operation_id: "deleteStorageV1CollectionVolumeAttachment" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "volumeattachments", verb: "get", operation_id: "readStorageV1VolumeAttachment" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "volumeattachments", verb: "list", operation_id: "listStorageV1VolumeAttachment" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "volumeattachments", verb: "patch", operation_id: "patchStorageV1VolumeAttachment" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "volumeattachments", verb: "update", operation_id: "replaceStorageV1VolumeAttachment" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "volumeattachments", verb: "watch", operation_id: "watchStorageV1VolumeAttachment" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "volumeattachments/status", verb: "get", operation_id: "readStorageV1VolumeAttachmentStatus" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "volumeattachments/status", verb: "patch", operation_id: "patchStorageV1VolumeAttachmentStatus" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1", resource: "volumeattachments/status", verb: "update", operation_id: "replaceStorageV1VolumeAttachmentStatus" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1beta1", resource: "csistoragecapacities", verb: "create", operation_id: "createStorageV1beta1NamespacedCSIStorageCapacity" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1beta1", resource: "csistoragecapacities", verb: "delete", operation_id: "deleteStorageV1beta1NamespacedCSIStorageCapacity" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1beta1", resource: "csistoragecapacities", verb: "deletecollection", operation_id: "deleteStorageV1beta1CollectionNamespacedCSIStorageCapacity" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1beta1", resource: "csistoragecapacities", verb: "get", operation_id: "readStorageV1beta1NamespacedCSIStorageCapacity" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1beta1", resource: "csistoragecapacities", verb: "list", operation_id: "listStorageV1beta1CSIStorageCapacityForAllNamespaces" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1beta1", resource: "csistoragecapacities", verb: "list", operation_id: "listStorageV1beta1NamespacedCSIStorageCapacity" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1beta1", resource: "csistoragecapacities", verb: "patch", operation_id: "patchStorageV1beta1NamespacedCSIStorageCapacity" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1beta1", resource: "csistoragecapacities", verb: "update", operation_id: "replaceStorageV1beta1NamespacedCSIStorageCapacity" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1beta1", resource: "csistoragecapacities", verb: "watch", operation_id: "watchStorageV1beta1CSIStorageCapacityForAllNamespaces" },
+    crate::ResourceOperation { group: "storage.k8s.io", version: "v1beta1", resource: "csistoragecapacities", verb: "watch", operation_id: "watchStorageV1beta1NamespacedCSIStorageCapacity" },
+];
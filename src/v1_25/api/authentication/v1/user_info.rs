@@ -2,6 +2,7 @@
 
 /// UserInfo holds the information about the user needed to implement the user.Info interface.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct UserInfo {
     /// Any additional information provided by the authenticator.
     pub extra: Option<std::collections::BTreeMap<String, Vec<String>>>,
@@ -2,6 +2,7 @@
 
 /// current status of a horizontal pod autoscaler
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct HorizontalPodAutoscalerStatus {
     /// current average CPU utilization over all pods, represented as a percentage of requested CPU, e.g. 70 means that an average pod is using now 70% of its requested CPU.
     pub current_cpu_utilization_percentage: Option<i32>,
@@ -2,6 +2,7 @@
 
 /// PodsMetricSource indicates how to scale on a metric describing each pod in the current scale target (for example, transactions-processed-per-second). The values will be averaged together before being compared to the target value.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct PodsMetricSource {
     /// metric identifies the target metric by name and selector
     pub metric: crate::api::autoscaling::v2::MetricIdentifier,
@@ -2,6 +2,7 @@
 
 /// UncountedTerminatedPods holds UIDs of Pods that have terminated but haven't been accounted in Job status counters.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct UncountedTerminatedPods {
     /// Failed holds UIDs of failed Pods.
     pub failed: Option<Vec<String>>,
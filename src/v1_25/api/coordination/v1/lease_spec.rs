@@ -2,6 +2,7 @@
 
 /// LeaseSpec is a specification of a Lease.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct LeaseSpec {
     /// acquireTime is a time when the current lease was acquired.
     pub acquire_time: Option<crate::apimachinery::pkg::apis::meta::v1::MicroTime>,
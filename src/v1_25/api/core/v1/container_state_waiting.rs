@@ -2,6 +2,7 @@
 
 /// ContainerStateWaiting is a waiting state of a container.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ContainerStateWaiting {
     /// Message regarding why the container is not yet running.
     pub message: Option<String>,
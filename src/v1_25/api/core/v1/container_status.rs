@@ -2,6 +2,7 @@
 
 /// ContainerStatus contains details for the current status of this container.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ContainerStatus {
     /// Container's ID in the format '\<type\>://\<container_id\>'.
     pub container_id: Option<String>,
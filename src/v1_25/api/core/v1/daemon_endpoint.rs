@@ -2,6 +2,7 @@
 
 /// DaemonEndpoint contains information about a single Daemon endpoint.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct DaemonEndpoint {
     /// Port number of the given endpoint.
     pub port: i32,
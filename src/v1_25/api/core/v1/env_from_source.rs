@@ -2,6 +2,7 @@
 
 /// EnvFromSource represents the source of a set of ConfigMaps
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct EnvFromSource {
     /// The ConfigMap to select from
     pub config_map_ref: Option<crate::api::core::v1::ConfigMapEnvSource>,
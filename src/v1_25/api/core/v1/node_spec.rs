@@ -2,6 +2,7 @@
 
 /// NodeSpec describes the attributes that a node is created with.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct NodeSpec {
     /// Deprecated: Previously used to specify the source of the node's configuration for the DynamicKubeletConfig feature. This feature is removed from Kubelets as of 1.24 and will be fully removed in 1.26.
     pub config_source: Option<crate::api::core::v1::NodeConfigSource>,
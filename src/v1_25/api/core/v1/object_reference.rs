@@ -2,6 +2,7 @@
 
 /// ObjectReference contains enough information to let you inspect or modify the referred object.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ObjectReference {
     /// API version of the referent.
     pub api_version: Option<String>,
@@ -2,6 +2,7 @@
 
 /// PersistentVolumeClaimCondition contails details about state of pvc
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct PersistentVolumeClaimCondition {
     /// lastProbeTime is the time we probed the condition.
     pub last_probe_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
@@ -2,6 +2,7 @@
 
 /// Defines a set of pods (namely those matching the labelSelector relative to the given namespace(s)) that this pod should be co-located (affinity) or not co-located (anti-affinity) with, where co-located is defined as running on a node whose value of the label with key \<topologyKey\> matches that of any node on which a pod of the set of pods is running
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct PodAffinityTerm {
     /// A label query over a set of resources, in this case pods.
     pub label_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>,
@@ -2,6 +2,7 @@
 
 /// Subject matches the originator of a request, as identified by the request authentication system. There are three ways of matching an originator; by user, group, or service account.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct Subject {
     /// `group` matches based on user group name.
     pub group: Option<crate::api::flowcontrol::v1beta2::GroupSubject>,
@@ -2,6 +2,7 @@
 
 /// IngressServiceBackend references a Kubernetes Service as a Backend.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct IngressServiceBackend {
     /// Name is the referenced service. The service must exist in the same namespace as the Ingress object.
     pub name: String,
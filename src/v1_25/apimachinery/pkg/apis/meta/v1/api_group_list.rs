@@ -2,6 +2,7 @@
 
 /// APIGroupList is a list of APIGroup, to allow clients to discover the API at /apis.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct APIGroupList {
     /// groups is a list of APIGroup.
     pub groups: Vec<crate::apimachinery::pkg::apis::meta::v1::APIGroup>,
@@ -2,6 +2,7 @@
 
 /// Preconditions must be fulfilled before an operation (update, delete, etc.) is carried out.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct Preconditions {
     /// Specifies the target ResourceVersion
     pub resource_version: Option<String>,
@@ -0,0 +1,41 @@
+// Generated from definition io.k8s.FieldValidation
+
+/// How the server handles objects in the request that contain unknown or duplicate fields.
+#[cfg(feature = "api")]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum FieldValidation {
+    /// Unknown fields are silently dropped from the object, and all but the last duplicate field are ignored.
+    Ignore,
+
+    /// A warning is sent via the standard warning response header for each unknown field that is dropped and each duplicate field that is encountered.
+    Warn,
+
+    /// The request fails with a BadRequest error if any unknown fields would be dropped from the object, or if any duplicate fields are present.
+    Strict,
+}
+
+#[cfg(feature = "api")]
+impl FieldValidation {
+    /// The value of this parameter as it is sent to the API server.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FieldValidation::Ignore => "Ignore",
+            FieldValidation::Warn => "Warn",
+            FieldValidation::Strict => "Strict",
+        }
+    }
+}
+
+#[cfg(feature = "api")]
+impl std::fmt::Display for FieldValidation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "api")]
+impl crate::serde::Serialize for FieldValidation {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        serializer.serialize_str(self.as_str())
+    }
+}
@@ -2,6 +2,7 @@
 
 /// APIServiceStatus contains derived information about an API server
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct APIServiceStatus {
     /// Current service state of apiService.
     pub conditions: Option<Vec<crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceCondition>>,
@@ -19,6 +19,16 @@ mod delete_response;
 #[cfg(feature = "api")]
 pub use self::delete_response::DeleteResponse;
 
+#[cfg(feature = "api")]
+mod dry_run;
+#[cfg(feature = "api")]
+pub use self::dry_run::DryRun;
+
+#[cfg(feature = "api")]
+mod field_validation;
+#[cfg(feature = "api")]
+pub use self::field_validation::FieldValidation;
+
 mod list;
 pub use self::list::List;
 
@@ -42,6 +52,11 @@ mod patch_response;
 #[cfg(feature = "api")]
 pub use self::patch_response::PatchResponse;
 
+#[cfg(feature = "api")]
+mod propagation_policy;
+#[cfg(feature = "api")]
+pub use self::propagation_policy::PropagationPolicy;
+
 #[cfg(feature = "api")]
 mod replace_optional;
 #[cfg(feature = "api")]
@@ -52,6 +67,11 @@ mod replace_response;
 #[cfg(feature = "api")]
 pub use self::replace_response::ReplaceResponse;
 
+#[cfg(feature = "api")]
+mod resource_version_match;
+#[cfg(feature = "api")]
+pub use self::resource_version_match::ResourceVersionMatch;
+
 #[cfg(feature = "api")]
 mod watch_optional;
 #[cfg(feature = "api")]
@@ -3044,3 +3064,8 @@ impl crate::Response for LogFileListHandlerResponse {
         }
     }
 }
+
+#[cfg(feature = "api")]
+mod resource_operations;
+#[cfg(feature = "api")]
+pub use self::resource_operations::RESOURCE_OPERATIONS;
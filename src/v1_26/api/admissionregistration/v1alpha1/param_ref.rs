@@ -2,6 +2,7 @@
 
 /// ParamRef references a parameter resource
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ParamRef {
     /// Name of the resource being referenced.
     pub name: Option<String>,
@@ -2,6 +2,7 @@
 
 /// ReplicaSetStatus represents the current status of a ReplicaSet.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ReplicaSetStatus {
     /// The number of available replicas (ready for at least minReadySeconds) for this replica set.
     pub available_replicas: Option<i32>,
@@ -2,6 +2,7 @@
 
 /// StatefulSetOrdinals describes the policy used for replica ordinal assignment in this StatefulSet.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct StatefulSetOrdinals {
     /// start is the number representing the first replica's index. It may be used to number replicas from an alternate index (eg: 1-indexed) over the default 0-indexed names, or to orchestrate progressive movement of replicas from one StatefulSet to another. If set, replica indices will be in the range:
     ///   \[.spec.ordinals.start, .spec.ordinals.start + .spec.replicas).
@@ -2,6 +2,7 @@
 
 /// SelfSubjectReviewStatus is filled by the kube-apiserver and sent back to a user.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct SelfSubjectReviewStatus {
     /// User attributes of the user making this request.
     pub user_info: Option<crate::api::authentication::v1::UserInfo>,
@@ -2,6 +2,7 @@
 
 /// HPAScalingPolicy is a single policy which must hold true for a specified past interval.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct HPAScalingPolicy {
     /// PeriodSeconds specifies the window of time for which the policy should hold true. PeriodSeconds must be greater than zero and less than or equal to 1800 (30 min).
     pub period_seconds: i32,
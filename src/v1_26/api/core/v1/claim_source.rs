@@ -4,6 +4,7 @@
 ///
 /// Exactly one of these fields should be set.  Consumers of this type must treat an empty object as if it has an unknown value.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ClaimSource {
     /// ResourceClaimName is the name of a ResourceClaim object in the same namespace as this pod.
     pub resource_claim_name: Option<String>,
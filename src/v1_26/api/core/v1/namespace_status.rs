@@ -2,6 +2,7 @@
 
 /// NamespaceStatus is information about the current status of a Namespace.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct NamespaceStatus {
     /// Represents the latest available observations of a namespace's current state.
     pub conditions: Option<Vec<crate::api::core::v1::NamespaceCondition>>,
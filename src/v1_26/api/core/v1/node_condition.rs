@@ -2,6 +2,7 @@
 
 /// NodeCondition contains condition information for a node.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct NodeCondition {
     /// Last time we got an update on a given condition.
     pub last_heartbeat_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
@@ -2,6 +2,7 @@
 
 /// SecretReference represents a Secret Reference. It has enough information to retrieve secret in any namespace
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct SecretReference {
     /// name is unique within a namespace to reference a secret resource.
     pub name: Option<String>,
@@ -2,6 +2,7 @@
 
 /// volumeDevice describes a mapping of a raw block device within a container.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct VolumeDevice {
     /// devicePath is the path inside of the container that the device will be mapped to.
     pub device_path: String,
@@ -2,6 +2,7 @@
 
 /// IngressLoadBalancerIngress represents the status of a load-balancer ingress point.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct IngressLoadBalancerIngress {
     /// Hostname is set for load-balancer ingress points that are DNS based.
     pub hostname: Option<String>,
@@ -2,6 +2,7 @@
 
 /// NetworkPolicyPeer describes a peer to allow traffic to/from. Only certain combinations of fields are allowed
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct NetworkPolicyPeer {
     /// IPBlock defines policy on a particular IPBlock. If this field is set then neither of the other fields can be.
     pub ip_block: Option<crate::api::networking::v1::IPBlock>,
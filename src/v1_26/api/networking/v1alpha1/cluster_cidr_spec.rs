@@ -2,6 +2,7 @@
 
 /// ClusterCIDRSpec defines the desired state of ClusterCIDR.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ClusterCIDRSpec {
     /// IPv4 defines an IPv4 IP block in CIDR notation(e.g. "10.0.0.0/8"). At least one of IPv4 and IPv6 must be specified. This field is immutable.
     pub ipv4: Option<String>,
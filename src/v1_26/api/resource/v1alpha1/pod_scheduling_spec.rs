@@ -2,6 +2,7 @@
 
 /// PodSchedulingSpec describes where resources for the Pod are needed.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct PodSchedulingSpec {
     /// PotentialNodes lists nodes where the Pod might be able to run.
     ///
@@ -2,6 +2,7 @@
 
 /// PodSchedulingStatus describes where resources for the Pod can be allocated.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct PodSchedulingStatus {
     /// ResourceClaims describes resource availability for each pod.spec.resourceClaim entry where the corresponding ResourceClaim uses "WaitForFirstConsumer" allocation mode.
     pub resource_claims: Option<Vec<crate::api::resource::v1alpha1::ResourceClaimSchedulingStatus>>,
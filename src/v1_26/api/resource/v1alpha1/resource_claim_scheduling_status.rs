@@ -2,6 +2,7 @@
 
 /// ResourceClaimSchedulingStatus contains information about one particular ResourceClaim with "WaitForFirstConsumer" allocation mode.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ResourceClaimSchedulingStatus {
     /// Name matches the pod.spec.resourceClaims\[*\].Name field.
     pub name: Option<String>,
@@ -2,6 +2,7 @@
 
 /// ResourceClaimSpec defines how a resource is to be allocated.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ResourceClaimSpec {
     /// Allocation can start immediately or when a Pod wants to use the resource. "WaitForFirstConsumer" is the default.
     pub allocation_mode: Option<String>,
@@ -2,6 +2,7 @@
 
 /// ResourceClaimStatus tracks whether the resource has been allocated and what the resulting attributes are.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ResourceClaimStatus {
     /// Allocation is set by the resource driver once a resource has been allocated successfully. If this is not specified, the resource is not yet allocated.
     pub allocation: Option<crate::api::resource::v1alpha1::AllocationResult>,
@@ -2,6 +2,7 @@
 
 /// ResourceClassParametersReference contains enough information to let you locate the parameters for a ResourceClass.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct ResourceClassParametersReference {
     /// APIGroup is the group for the resource being referenced. It is empty for the core API. This matches the group in the APIVersion that is used when creating the resources.
     pub api_group: Option<String>,
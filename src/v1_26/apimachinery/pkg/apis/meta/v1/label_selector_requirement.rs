@@ -2,6 +2,7 @@
 
 /// A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct LabelSelectorRequirement {
     /// key is the label key that the selector applies to.
     pub key: String,
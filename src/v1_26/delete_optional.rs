@@ -3,12 +3,13 @@
 /// Common parameters for all delete and delete-collection operations.
 #[cfg(feature = "api")]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "eq_hash_ord", derive(Eq, Hash, Ord, PartialOrd))]
 pub struct DeleteOptional<'a> {
     /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#resources
     pub api_version: Option<&'a str>,
 
     /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
-    pub dry_run: Option<&'a [String]>,
+    pub dry_run: Option<&'a [crate::DryRun]>,
 
     /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
     pub grace_period_seconds: Option<i64>,
@@ -23,7 +24,7 @@ pub struct DeleteOptional<'a> {
     pub preconditions: Option<&'a crate::apimachinery::pkg::apis::meta::v1::Preconditions>,
 
     /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
-    pub propagation_policy: Option<&'a str>,
+    pub propagation_policy: Option<crate::PropagationPolicy>,
 }
 
 impl<'a> crate::serde::Serialize for DeleteOptional<'a> {
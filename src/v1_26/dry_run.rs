@@ -0,0 +1,33 @@
+// Generated from definition io.k8s.DryRun
+
+/// A dry run directive. When present, indicates that modifications should not be persisted.
+#[cfg(feature = "api")]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DryRun {
+    /// All dry run stages will be processed.
+    All,
+}
+
+#[cfg(feature = "api")]
+impl DryRun {
+    /// The value of this parameter as it is sent to the API server.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DryRun::All => "All",
+        }
+    }
+}
+
+#[cfg(feature = "api")]
+impl std::fmt::Display for DryRun {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "api")]
+impl crate::serde::Serialize for DryRun {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        serializer.serialize_str(self.as_str())
+    }
+}
@@ -0,0 +1,112 @@
+/// A Kubernetes version, such as `1.26`.
+///
+/// Use [`KubernetesVersion::from_info`] to parse the version of an API server from the response of [`get_code_version`](crate::get_code_version),
+/// and compare it with [`KubernetesVersion::ENABLED`] to check whether the server is older or newer than the version that this crate was compiled for.
+///
+/// ```rust,ignore
+/// let (request, response_body) = k8s_openapi::get_code_version()?;
+/// let k8s_openapi::GetCodeVersionResponse::Ok(info) = client.execute(request, response_body)? else { return Err("could not get server version".into()) };
+///
+/// let server_version = k8s_openapi::KubernetesVersion::from_info(&info).ok_or("could not parse server version")?;
+/// if server_version < k8s_openapi::KubernetesVersion::ENABLED {
+///     println!("server version {server_version} is older than {}", k8s_openapi::KubernetesVersion::ENABLED);
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct KubernetesVersion {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl KubernetesVersion {
+    /// The version of Kubernetes that this crate was compiled for, ie the version of the enabled `v1_*` feature.
+    pub const ENABLED: Self = KubernetesVersion {
+        major: 1,
+        minor: include!(concat!(env!("OUT_DIR"), "/enabled_minor_version.rs")),
+    };
+
+    /// Parses the version of an API server from its [`Info`](crate::apimachinery::pkg::version::Info).
+    ///
+    /// Some distributions of Kubernetes report a `minor` version with a suffix like `26+`, or do not report `major` and `minor` at all.
+    /// In these cases the version is parsed from the `gitVersion` instead, such as `v1.26.3-gke.1000`.
+    ///
+    /// Returns `None` if the version could not be parsed.
+    pub fn from_info(info: &crate::apimachinery::pkg::version::Info) -> Option<Self> {
+        fn parse_number(s: &str) -> Option<u32> {
+            let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+            s[..end].parse().ok()
+        }
+
+        if let (Some(major), Some(minor)) = (parse_number(&info.major), parse_number(&info.minor)) {
+            return Some(KubernetesVersion { major, minor });
+        }
+
+        let mut components = info.git_version.strip_prefix('v').unwrap_or(&info.git_version).split('.');
+        let major = parse_number(components.next()?)?;
+        let minor = parse_number(components.next()?)?;
+        Some(KubernetesVersion { major, minor })
+    }
+}
+
+impl std::fmt::Display for KubernetesVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// A resource operation of this crate's bindings, identified by the resource and verb that the API server lists for it in its discovery information.
+///
+/// The table of all resource operations of the enabled version is [`RESOURCE_OPERATIONS`](crate::RESOURCE_OPERATIONS).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ResourceOperation {
+    /// The API group of the resource, such as `apps`. This is empty for the core API group.
+    pub group: &'static str,
+
+    /// The API version of the resource, such as `v1`.
+    pub version: &'static str,
+
+    /// The name of the resource as it appears in an [`APIResourceList`](crate::apimachinery::pkg::apis::meta::v1::APIResourceList), such as `deployments`.
+    /// For a subresource, this is the name of the resource and the subresource separated by a `/`, such as `pods/log`.
+    pub resource: &'static str,
+
+    /// The verb of the operation as it appears in an [`APIResource`](crate::apimachinery::pkg::apis::meta::v1::APIResource), such as `list` or `create`.
+    pub verb: &'static str,
+
+    /// The ID of the operation in the OpenAPI spec, such as `listAppsV1NamespacedDeployment`.
+    /// The documentation of the function that implements this operation starts with `Generated from operation <operation_id>`.
+    pub operation_id: &'static str,
+}
+
+/// Returns the operations in [`RESOURCE_OPERATIONS`](crate::RESOURCE_OPERATIONS) that the API server does not serve, according to its discovery information.
+///
+/// `api_resource_lists` must contain the [`APIResourceList`](crate::apimachinery::pkg::apis::meta::v1::APIResourceList) of every group version that the server serves,
+/// ie the responses of [`get_core_v1_api_resources`](crate::get_core_v1_api_resources) and of the `get_*_api_resources` functions of the other groups.
+/// All operations of a group version without an `APIResourceList` are considered to not be served.
+///
+/// This is useful to check up front whether an API server supports the resources and operations that an application uses,
+/// especially when the server is older than [`KubernetesVersion::ENABLED`].
+pub fn unserved_operations<'a>(
+    api_resource_lists: impl IntoIterator<Item = &'a crate::apimachinery::pkg::apis::meta::v1::APIResourceList>,
+) -> Vec<&'static ResourceOperation> {
+    let mut served: std::collections::BTreeSet<(&str, &str, &str)> = Default::default();
+    for api_resource_list in api_resource_lists {
+        for api_resource in &api_resource_list.resources {
+            for verb in &api_resource.verbs {
+                served.insert((&api_resource_list.group_version, &api_resource.name, verb));
+            }
+        }
+    }
+
+    crate::RESOURCE_OPERATIONS.iter()
+        .filter(|resource_operation| {
+            let group_version =
+                if resource_operation.group.is_empty() {
+                    std::borrow::Cow::Borrowed(resource_operation.version)
+                }
+                else {
+                    std::borrow::Cow::Owned(format!("{}/{}", resource_operation.group, resource_operation.version))
+                };
+            !served.contains(&(&*group_version, resource_operation.resource, resource_operation.verb))
+        })
+        .collect()
+}
@@ -0,0 +1,109 @@
+/// A warning returned by the API server in a `Warning` header of a response, such as for the use of a deprecated API
+/// or for unknown fields in the request body with `fieldValidation=Warn`.
+///
+/// Use [`ResponseBody::from_parts`](crate::ResponseBody::from_parts) to parse these from the headers of a response, or
+/// [`Warning::from_headers`] to parse them yourself.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Warning {
+    /// The warning code. The API server always uses `299`, ie "Miscellaneous persistent warning".
+    pub code: u16,
+
+    /// The host that added the warning, or `-` if it is unknown. The API server always uses `-`.
+    pub agent: String,
+
+    /// The text of the warning.
+    pub text: String,
+}
+
+impl Warning {
+    /// Parses all the warnings in all the `Warning` headers of the given header map.
+    ///
+    /// Malformed warnings are ignored.
+    pub fn from_headers(headers: &http::HeaderMap) -> Vec<Self> {
+        headers.get_all(http::header::WARNING).iter()
+            .flat_map(|value| Warning::from_header_value(&String::from_utf8_lossy(value.as_bytes())))
+            .collect()
+    }
+
+    /// Parses the value of a single `Warning` header, which can contain a comma-separated list of warnings.
+    ///
+    /// Each warning has the form `code agent "text"`, optionally followed by a quoted date, as specified by RFC 7234.
+    /// Parsing stops at the first malformed warning, and the warnings before it are returned.
+    pub fn from_header_value(value: &str) -> Vec<Self> {
+        let mut result = vec![];
+
+        let mut rest = value;
+        loop {
+            rest = rest.trim_start_matches(|c: char| c == ',' || c.is_ascii_whitespace());
+            if rest.is_empty() {
+                break;
+            }
+
+            let Some((warning, remaining)) = parse_warning(rest) else { break; };
+            result.push(warning);
+
+            rest = remaining.trim_start_matches(|c: char| c.is_ascii_whitespace());
+            if !rest.is_empty() && !rest.starts_with(',') {
+                break;
+            }
+        }
+
+        result
+    }
+}
+
+impl std::fmt::Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// Parses a single warning at the start of the given string, and returns it along with the rest of the string.
+fn parse_warning(s: &str) -> Option<(Warning, &str)> {
+    let code = s.get(..3)?;
+    if !code.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let code = code.parse().ok()?;
+
+    let s = s[3..].strip_prefix(' ')?;
+    let (agent, s) = s.split_once(' ')?;
+    if agent.is_empty() {
+        return None;
+    }
+
+    let (text, mut s) = parse_quoted_string(s)?;
+
+    // Skip the optional date.
+    if let Some(date) = s.strip_prefix(" \"") {
+        let (_, rest) = date.split_once('"')?;
+        s = rest;
+    }
+
+    Some((
+        Warning {
+            code,
+            agent: agent.to_owned(),
+            text,
+        },
+        s,
+    ))
+}
+
+/// Parses a quoted string, with backslash-escaped characters, at the start of the given string, and returns its unquoted value
+/// along with the rest of the string.
+fn parse_quoted_string(s: &str) -> Option<(String, &str)> {
+    let s = s.strip_prefix('"')?;
+
+    let mut result = String::new();
+    let mut chars = s.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((result, &s[(i + 1)..])),
+            '\\' => result.push(chars.next()?.1),
+            c => result.push(c),
+        }
+    }
+
+    None
+}